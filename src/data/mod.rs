@@ -1 +1 @@
-pub mod card_template;
+pub mod card_template;
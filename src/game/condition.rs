@@ -0,0 +1,234 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::game::card::Card;
+use crate::game::enums::Races;
+use crate::game::filter::CardFilter;
+use crate::game::history::Character;
+use crate::game::state::{GameState, PlayerId};
+
+/// Bornes incluses d'une valeur entière (attaque, PV, nombre de serviteurs…).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Bounds {
+    pub min: Option<i32>,
+    pub max: Option<i32>,
+}
+
+impl Bounds {
+    /// `{"min": 7}`, `{"max": 15}` ou un nombre exact.
+    pub fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Number(n) => n.as_i64().map(|n| Bounds { min: Some(n as i32), max: Some(n as i32) }),
+            Value::Object(map) => {
+                let bound = |k: &str| map.get(k).and_then(|b| b.as_i64()).map(|b| b as i32);
+                Some(Bounds { min: bound("min"), max: bound("max") })
+            }
+            _ => None,
+        }
+    }
+
+    pub fn contains(&self, value: i32) -> bool {
+        self.min.is_none_or(|m| value >= m) && self.max.is_none_or(|m| value <= m)
+    }
+}
+
+/// Propriétés exigées de la cible d'un effet (Big Game Hunter, The Black Knight…).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TargetCondition {
+    pub filter: CardFilter,
+    pub attack: Option<Bounds>,
+    pub health: Option<Bounds>,
+    pub damaged: Option<bool>,
+}
+
+impl TargetCondition {
+    pub fn matches(&self, card: &Card) -> bool {
+        self.filter.matches(card)
+            && self.attack.is_none_or(|b| b.contains(card.effective_attack()))
+            && self.health.is_none_or(|b| b.contains(card.effective_health()))
+            && self.damaged.is_none_or(|d| is_damaged(card) == d)
+    }
+}
+
+fn is_damaged(card: &Card) -> bool {
+    let max = card.max_health.or(card.health).unwrap_or(0);
+    card.status.current_health.is_some_and(|h| h < max)
+}
+
+/// Condition « Si… » d'un effet, lue depuis le champ `condition` du JSON.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum EffectCondition {
+    /// « Si vous avez un Dragon en main »
+    Holding(CardFilter),
+    /// « Si vous contrôlez un autre serviteur… » (la source est exclue)
+    ControlsOtherMinion(CardFilter),
+    /// « Si vous avez lancé un sort au tour précédent »
+    CastSpellLastTurn,
+    /// Nombre de serviteurs alliés (ou ennemis) sur le board
+    BoardCount { friendly: bool, count: Bounds },
+    /// PV du héros allié
+    HeroHealth(Bounds),
+    /// Au moins N Cadavres disponibles
+    CorpsesAvailable(u32),
+    /// « Si vous avez une arme équipée »
+    WeaponEquipped,
+    /// « Après avoir joué un Élémentaire » : la dernière carte jouée correspond au filtre
+    PlayedCard(CardFilter),
+    /// « Quand ce serviteur subit des dégâts » : la source en a subi ce tour-ci
+    DamageTaken,
+    /// « Si un Mort-vivant allié est mort depuis votre dernier tour »
+    FriendlyDiedSinceLastTurn(Races),
+    /// Vérifiée sur chaque cible plutôt qu'au lancement
+    Target(TargetCondition),
+    /// Toutes les conditions de la liste
+    All(Vec<EffectCondition>),
+    /// Condition pas encore modélisée : considérée remplie
+    Unknown(Value),
+}
+
+impl EffectCondition {
+    /// Lit une condition JSON. Un objet sans `type` est une conjonction de critères.
+    pub fn from_value(value: &Value) -> Self {
+        let Value::Object(map) = value else {
+            return EffectCondition::Unknown(value.clone());
+        };
+        match map.get("type").and_then(|t| t.as_str()) {
+            Some("holding") => {
+                let mut filter = map.clone();
+                filter.remove("type");
+                filter.remove("zone");
+                return EffectCondition::Holding(CardFilter::from_value(&Value::Object(filter)));
+            }
+            Some("cast_spell_last_turn") => return EffectCondition::CastSpellLastTurn,
+            Some(_) => return EffectCondition::Unknown(value.clone()),
+            None => {}
+        }
+
+        let mut target = TargetCondition::default();
+        let mut filter_keys = serde_json::Map::new();
+        let mut is_target = false;
+        let mut all = Vec::new();
+        for (key, v) in map {
+            match key.as_str() {
+                "control_other_friendly_minion" => all.push(EffectCondition::ControlsOtherMinion(CardFilter::from_value(v))),
+                "cast_spell_last_turn" if v.as_bool() == Some(true) => all.push(EffectCondition::CastSpellLastTurn),
+                "weapon_equipped" if v.as_bool() == Some(true) => all.push(EffectCondition::WeaponEquipped),
+                "damage_taken" if v.as_bool() == Some(true) => all.push(EffectCondition::DamageTaken),
+                "friendly_undead_died_since_opponent_turn" if v.as_bool() == Some(true) => {
+                    all.push(EffectCondition::FriendlyDiedSinceLastTurn(Races::Undead))
+                }
+                "card_race" => all.push(EffectCondition::PlayedCard(CardFilter::from_value(&serde_json::json!({ "races": v })))),
+                "friendly_minions" | "board_count" => {
+                    if let Some(count) = Bounds::from_value(v) {
+                        all.push(EffectCondition::BoardCount { friendly: true, count });
+                    }
+                }
+                "enemy_minions" => {
+                    if let Some(count) = Bounds::from_value(v) {
+                        all.push(EffectCondition::BoardCount { friendly: false, count });
+                    }
+                }
+                "hero_health" => {
+                    if let Some(b) = Bounds::from_value(v) {
+                        all.push(EffectCondition::HeroHealth(b));
+                    }
+                }
+                "corpses" => {
+                    let min = Bounds::from_value(v).and_then(|b| b.min).unwrap_or(1);
+                    all.push(EffectCondition::CorpsesAvailable(min.max(0) as u32));
+                }
+                "attack" => {
+                    target.attack = Bounds::from_value(v);
+                    is_target = true;
+                }
+                "health" => {
+                    target.health = Bounds::from_value(v);
+                    is_target = true;
+                }
+                "damaged" => {
+                    target.damaged = v.as_bool();
+                    is_target = true;
+                }
+                "has_taunt" | "races" | "race" | "card_type" | "rarity" | "cost" | "keywords" | "mechanic" => {
+                    filter_keys.insert(key.clone(), v.clone());
+                    is_target = true;
+                }
+                _ => all.push(EffectCondition::Unknown(serde_json::json!({ key.clone(): v.clone() }))),
+            }
+        }
+        if is_target {
+            target.filter = CardFilter::from_value(&Value::Object(filter_keys));
+            all.push(EffectCondition::Target(target));
+        }
+        match all.len() {
+            1 => all.pop().unwrap(),
+            _ => EffectCondition::All(all),
+        }
+    }
+
+    /// La condition est-elle remplie pour `player_id` au moment où l'effet se résout ?
+    /// Les critères de cible sont vérifiés cible par cible (`allows_target`).
+    pub fn is_met(&self, state: &GameState, player_id: &PlayerId) -> bool {
+        let player = &state.players[player_id];
+        match self {
+            EffectCondition::Holding(filter) => {
+                let filter = filter.clone().for_player(state, player_id);
+                player.zones.hand.iter().any(|c| filter.matches(c))
+            }
+            EffectCondition::ControlsOtherMinion(filter) => {
+                let filter = filter.clone().for_player(state, player_id);
+                let source = player.source_minion_index();
+                player
+                    .zones
+                    .board
+                    .iter()
+                    .enumerate()
+                    .any(|(i, c)| Some(i) != source && !c.is_location() && filter.matches(c))
+            }
+            EffectCondition::CastSpellLastTurn => state.history.cast_spell_last_turn(player_id),
+            EffectCondition::BoardCount { friendly, count } => {
+                let owner = if *friendly { player } else { &state.players[&player_id.opponent()] };
+                count.contains(owner.zones.board.iter().filter(|c| !c.is_location()).count() as i32)
+            }
+            EffectCondition::HeroHealth(bounds) => bounds.contains(player.stats.health),
+            EffectCondition::CorpsesAvailable(n) => player.corpses >= *n,
+            EffectCondition::WeaponEquipped => player.stats.weapon.is_some(),
+            EffectCondition::PlayedCard(filter) => {
+                let filter = filter.clone().for_player(state, player_id);
+                state
+                    .history
+                    .cards_played_by(player_id, None)
+                    .last()
+                    .and_then(|r| state.card_templates.get(&r.card_id))
+                    .is_some_and(|t| filter.matches(t))
+            }
+            EffectCondition::DamageTaken => player.source_minion_index().is_some_and(|i| {
+                let source = Character::Minion { owner: *player_id, card_id: player.zones.board[i].card_id.clone() };
+                state.history.damage_taken_by(&source, Some(state.history.current_turn())) > 0
+            }),
+            EffectCondition::FriendlyDiedSinceLastTurn(race) => {
+                let since = state.history.last_turn_of(player_id).map_or(1, |t| t + 1);
+                state
+                    .history
+                    .minions_died_of(player_id, Some(since))
+                    .iter()
+                    .any(|r| r.races.iter().any(|x| x == race || *x == Races::All))
+            }
+            EffectCondition::Target(_) => true,
+            EffectCondition::All(conditions) => conditions.iter().all(|c| c.is_met(state, player_id)),
+            EffectCondition::Unknown(raw) => {
+                println!("(condition non évaluée : {})", raw);
+                true
+            }
+        }
+    }
+
+    /// `card` est-elle une cible valide au regard des critères de cible ?
+    pub fn allows_target(&self, card: &Card) -> bool {
+        match self {
+            EffectCondition::Target(t) => t.matches(card),
+            EffectCondition::All(conditions) => conditions.iter().all(|c| c.allows_target(card)),
+            _ => true,
+        }
+    }
+}
@@ -1,838 +1,1674 @@
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
-use std::collections::HashMap;
-use crate::game::triggers::Trigger;
-use crate::game::engine::utils::{ChooseRandomMut, IdString};
-use crate::data::card_template::{EffectTemplate,CardTemplate};
-use crate::game::state::{GameState, PlayerId};
-use crate::game::targets::Target;
-use crate::game::engine::draw::{draw_n, draw_n_with_filter};
-use crate::game::enums::{CardType, Rarity, Races};
-use crate::game::card::Card;
-
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(tag = "type", rename_all = "snake_case")]
-pub enum Effect {
-
-    Spellpower { amount: Option<i32> },
-
-    Damage {
-        amount: Option<i32>,
-        repeat: Option<i32>,
-        target: Option<Target>,
-        random: Option<bool>,
-        trigger: Option<String>,
-        condition: Option<EffectCondition>,
-    },
-    Heal {
-        amount: Option<i32>,
-        target: Option<Target>,
-        trigger: Option<String>,
-    },
-    Draw {
-        amount: Option<i32>,
-        target: Option<Target>,
-        filter: Option<HashMap<String, serde_json::Value>>,
-        trigger: Option<String>,
-    },
-    Buff {
-        attack: Option<i32>,
-        health: Option<i32>,
-        amount: Option<i32>,
-        random: Option<bool>,
-        duration: Option<String>,
-        filter: Option<Value>,
-        target: Option<Target>,
-        trigger: Option<Trigger>,
-    },
-    Summon {
-        amount: Option<i32>,
-        card_id: Option<String>,
-        filter: Option<std::collections::HashMap<String, serde_json::Value>>,
-        source: Option<String>,
-        destination: Option<String>,
-        random: Option<bool>,
-        trigger: Option<String>,
-    },
-    AddCardToHand {
-        amount: Option<i32>,
-        card_id: Option<String>,
-        card: Option<String>,
-        random: Option<bool>,
-        source_pool: Option<serde_json::Value>,
-        zone: Option<String>,
-        trigger: Option<String>,
-    },
-    Discover {
-        amount: Option<i32>,
-        source_pool: Option<serde_json::Value>,
-        condition: Option<HashMap<String, serde_json::Value>>,
-        trigger: Option<String>,
-    },
-    Aura {
-        effect: Box<serde_json::Value>,
-        target: Option<String>,
-    },
-    GrantDeathrattle {
-        effect: Box<serde_json::Value>,
-        target: Option<String>,
-        random: Option<bool>,
-        trigger: Option<String>,
-    },
-    GrantMechanic {
-        mechanic: String,
-        target: Option<String>,
-        trigger: Option<String>,
-    },
-    Destroy {
-        target: Option<String>,
-        condition: Option<HashMap<String, serde_json::Value>>,
-        trigger: Option<String>,
-    },
-    SetHealth {
-        value: Option<i32>,
-        target: Option<String>,
-        trigger: Option<String>,
-    },
-    Overload { amount: Option<i32> },
-    GainArmor {
-        amount: Option<i32>,
-        trigger: Option<String>,
-    },
-    EquipWeapon {
-        card_id: Option<String>,
-        trigger: Option<String>,
-    },
-    Tradeable,
-    CantAttack,
-    SwapStats {
-        target: Option<String>,
-        trigger: Option<String>,
-    },
-    ReturnToHand {
-        target: Option<String>,
-        zone: Option<String>,
-        trigger: Option<String>,
-    },
-    CopyCardToHand {
-        from: Option<serde_json::Value>,
-        to: Option<serde_json::Value>,
-        card_type: Option<String>,
-        amount: Option<i32>,
-        trigger: Option<String>,
-    },
-    Discard {
-        amount: Option<i32>,
-        random: Option<bool>,
-        trigger: Option<String>,
-    },
-    ModifyCost {
-        mode: Option<String>,
-        amount: Option<i32>,
-        target: Option<String>,
-        filter: Option<HashMap<String, serde_json::Value>>,
-        value: Option<i32>,
-        duration: Option<String>,
-        condition: Option<HashMap<String, serde_json::Value>>,
-        trigger: Option<String>,
-    },
-    Elusive,
-    #[serde(other)]
-    Unknown,
-}
-
-
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct EffectCondition {
-    #[serde(flatten)]
-    pub data: HashMap<String, serde_json::Value>,
-}
-
-impl Effect {
-    pub fn from_template(template: &EffectTemplate) -> Self {
-        match template.effect_type.to_ascii_lowercase().as_str() {           
-            "taunt" | "charge" | "rush" | "divine_shield" | "lifesteal"
-            | "poisonous" | "reborn" | "stealth" | "windfury" | "megawindfury" => {
-                return Effect::Unknown
-            }
-            "add_card_to_hand" => Effect::AddCardToHand {
-                amount: None,
-                card_id: None,
-                card: None,
-                random: None,
-                source_pool: None,
-                zone: None,
-                trigger: None,
-            },
-            "banish_temporarily" => Effect::Unknown,
-            "buff" => {
-    let attack   = template.extra.get("attack").and_then(|v| v.as_i64()).map(|x| x as i32);
-    let health   = template.extra.get("health").and_then(|v| v.as_i64()).map(|x| x as i32);
-    let amount   = template.extra.get("amount").and_then(|v| v.as_i64()).map(|x| x as i32);
-    let random   = template.extra.get("random").and_then(|v| v.as_bool());
-    let duration = template.extra.get("duration").and_then(|v| v.as_str()).map(|s| s.to_string());
-    let filter   = template.extra.get("filter").cloned();
-    let target   = template
-        .extra
-        .get("target")
-        .and_then(|v| v.as_str())
-        .and_then(|s| crate::game::targets::Target::from_str(s));
-
-    Effect::Buff {
-        attack,
-        health,
-        amount,
-        random,
-        duration,
-        filter,
-        target,
-        trigger: template.trigger.clone(),
-    }
-}
-            "choose" => Effect::Unknown,
-            "copy_card_to_hand" => Effect::CopyCardToHand {
-                from: None,
-                to: None,
-                card_type: None,
-                amount: None,
-                trigger: None,
-            },
-            "damage" => {
-                let target_str = template.extra.get("target").and_then(|v| v.as_str());
-                let parsed_target = target_str.and_then(Target::from_str);
-                Effect::Damage {
-                    amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
-                    repeat: template.extra.get("repeat").and_then(|v| v.as_i64().map(|x| x as i32)),
-                    target: parsed_target,
-                    random: template.extra.get("random").and_then(|v| v.as_bool()),
-                    trigger: template.extra.get("trigger").and_then(|v| v.as_str().map(|s| s.to_string())),
-                    condition: None,
-                }
-            }
-            "destroy" => Effect::Destroy {
-                target: None,
-                condition: None,
-                trigger: None,
-            },
-            "discard" => Effect::Discard {
-                amount: None,
-                random: None,
-                trigger: None,
-            },
-            "discover" => Effect::Discover {
-                amount: None,
-                source_pool: None,
-                condition: None,
-                trigger: None,
-            },
-            "discover_mystery" => Effect::Unknown,
-            "draw" => {
-                let target_str = template.extra.get("target").and_then(|v| v.as_str());
-                let parsed_target = target_str.and_then(Target::from_str);
-
-                Effect::Draw {
-                    amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
-                    target: parsed_target,
-                    filter: template.extra.get("filter").and_then(|v| v.as_object().map(|m| m.clone().into_iter().collect())),
-                    trigger: template.extra.get("trigger").and_then(|v| v.as_str().map(|s| s.to_string())),
-                }
-            },
-
-            "freeze" => Effect::Unknown,
-            "gain_corpse" => Effect::Unknown,
-            "gain_health" => Effect::Unknown,
-            "grant_mechanic" => Effect::GrantMechanic {
-                mechanic: "".to_string(),
-                target: None,
-                trigger: None,
-            },
-            "heal" => {
-                let target_str = template.extra.get("target").and_then(|v| v.as_str());
-                let parsed_target = target_str.and_then(Target::from_str);
-                Effect::Heal {
-                    amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
-                    target: parsed_target,
-                    trigger: template.extra.get("trigger").and_then(|v| v.as_str().map(|s| s.to_string())),
-                }
-            },
-            "modify_cost" => Effect::ModifyCost {
-                mode: None,
-                amount: None,
-                target: None,
-                filter: None,
-                value: None,
-                duration: None,
-                condition: None,
-                trigger: None,
-            },
-            "replay_cards" => Effect::Unknown,
-            "return_to_hand" => Effect::ReturnToHand {
-                target: None,
-                zone: None,
-                trigger: None,
-            },
-            "set_health" => Effect::SetHealth {
-                value: None,
-                target: None,
-                trigger: None,
-            },
-            "silence" => Effect::Unknown,
-            "spend_corpse" => Effect::Unknown,
-            "summon" => {
-                Effect::Summon {
-                    amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
-                    card_id: template.extra.get("card_id").and_then(|v| v.as_str().map(|s| s.to_string())),
-                    filter: template.extra.get("filter").and_then(|v| v.as_object().map(|m| m.clone().into_iter().collect())),
-                    source: template.extra.get("source").and_then(|v| v.as_str().map(|s| s.to_string())),
-                    destination: template.extra.get("destination")
-                        .or_else(|| template.extra.get("zone"))
-                        .or_else(|| template.extra.get("target"))
-                        .and_then(|v| v.as_str().map(|s| s.to_string())),
-                    random: template.extra.get("random").and_then(|v| v.as_bool()),
-                    trigger: template.extra.get("trigger").and_then(|v| v.as_str().map(|s| s.to_string())),
-                }
-
-            },
-            "swap_stats" => Effect::SwapStats {
-                target: None,
-                trigger: None,
-            },
-            "trigger_deathrattle" => Effect::Unknown,
-            _ => Effect::Unknown,
-        }
-    }
-}
-/// Enlève tous les serviteurs morts sur le board de chaque joueur.
-pub fn remove_dead_minions(state: &mut GameState) {
-    for player in state.players.values_mut() {
-        let before = player.zones.board.len();
-        player.zones.board.retain(|m| m.status.current_health.unwrap_or(1) > 0);
-        let after = player.zones.board.len();
-        if before != after {
-            println!(
-                "[CLEANUP] {}/{} minions retirés du board de {:?} (morts)",
-                before - after, before, player.id
-            );
-        }
-    }
-}
-
-fn card_has_any_race(card: &crate::game::card::Card, races: &[Races]) -> bool {
-    match &card.races {
-        Some(card_races) => card_races.iter().any(|r| races.contains(r)),
-        None => false,
-    }
-}
-
-fn apply_buff_values(
-    card: &mut crate::game::card::Card,
-    add_atk: i32,
-    add_hp: i32,
-) {
-    if add_atk != 0 {
-        card.status.attack_modifiers += add_atk;
-    }
-    if add_hp != 0 {
-        // max_health et current_health sont Option<i32>; on les traite prudemment
-        let cur = card.status.current_health.unwrap_or(0);
-        let maxh = card.max_health.unwrap_or(card.health.unwrap_or(0));
-        card.max_health = Some(maxh + add_hp);
-        card.status.current_health = Some(cur + add_hp);
-    }
-}
-
-pub fn apply_effect(
-    state: &mut GameState,
-    player_id: &PlayerId,
-    effect: &Effect,
-    chooser: &dyn crate::game::engine::choose::Chooser,
-    card_templates: &HashMap<String, CardTemplate>,
-) {
-    let owner_id = player_id;  // ou player_id, selon le vrai nom
-    match effect {
-        // ---- DAMAGE ----
-        Effect::Damage { amount: Some(dmg), repeat, target: Some(target), .. } => {
-            let times = repeat.unwrap_or(1);
-            for _ in 0..times {
-                match target {
-                    Target::AnyCharacter => {
-                        let mut valid = Vec::new();
-                        for (pid, player) in state.players.iter() {
-                            valid.push((pid.clone(), "hero".to_string(), None));
-                            for (i, _) in player.zones.board.iter().enumerate() {
-                                valid.push((pid.clone(), "minion".to_string(), Some(i)));
-                            }
-                        }
-                        let choice = chooser.choose(
-                            state,
-                            crate::game::engine::choose::Choice::Target {
-                                valid_targets: (0..valid.len()).collect(),
-                            }
-                        );
-                        if let crate::game::engine::choose::Choice::Target { valid_targets } = choice {
-                            let (tgt_pid, tgt_type, opt_idx) = &valid[valid_targets[0]];
-                            let tgt_player = state.players.get_mut(tgt_pid).unwrap();
-                            if tgt_type == "hero" {
-                                tgt_player.stats.health -= *dmg;
-                                println!("[DAMAGE] Damage {} to hero {:?}", dmg, tgt_pid);
-                            } else if let Some(idx) = opt_idx {
-                                if let Some(minion) = tgt_player.zones.board.get_mut(*idx) {
-                                    minion.status.current_health = Some(minion.status.current_health.unwrap_or(0) - *dmg);
-                                    println!("[DAMAGE] Damage {} to minion {}", dmg, minion.name);
-                                }
-                            }
-                        }
-                    }
-
-                    Target::AnyMinion => {
-                        let mut valid = Vec::new();
-                        for (pid, player) in state.players.iter() {
-                            for (i, _) in player.zones.board.iter().enumerate() {
-                                valid.push((pid.clone(), i));
-                            }
-                        }
-                        let choice = chooser.choose(
-                            state,
-                            crate::game::engine::choose::Choice::Target {
-                                valid_targets: (0..valid.len()).collect(),
-                            }
-                        );
-                        if let crate::game::engine::choose::Choice::Target { valid_targets } = choice {
-                            let (tgt_pid, idx) = &valid[valid_targets[0]];
-                            let tgt_player = state.players.get_mut(tgt_pid).unwrap();
-                            if let Some(minion) = tgt_player.zones.board.get_mut(*idx) {
-                                minion.status.current_health = Some(minion.status.current_health.unwrap_or(0) - *dmg);
-                                println!("[DAMAGE] Damage {} to minion {}", dmg, minion.name);
-                            }
-                        }
-                    }
-
-                    Target::EnemyCharacter => {
-                        let opponent_id = player_id.opponent();
-                        let opponent = state.players.get(&opponent_id).unwrap();
-                        let mut valid = Vec::new();
-                        valid.push(("hero", None));
-                        for (i, _) in opponent.zones.board.iter().enumerate() {
-                            valid.push(("minion", Some(i)));
-                        }
-                        let choice = chooser.choose(
-                            state,
-                            crate::game::engine::choose::Choice::Target {
-                                valid_targets: (0..valid.len()).collect(),
-                            }
-                        );
-                        if let crate::game::engine::choose::Choice::Target { valid_targets } = choice {
-                            let (kind, opt_idx) = &valid[valid_targets[0]];
-                            let opponent = state.players.get_mut(&opponent_id).unwrap();
-                            if *kind == "hero" {
-                                opponent.stats.health -= *dmg;
-                                println!("[DAMAGE] Damage {} to ENEMY hero", dmg);
-                            } else if let Some(idx) = opt_idx {
-                                if let Some(minion) = opponent.zones.board.get_mut(*idx) {
-                                    minion.status.current_health = Some(minion.status.current_health.unwrap_or(0) - *dmg);
-                                    println!("[DAMAGE] Damage {} to ENEMY minion {}", dmg, minion.name);
-                                }
-                            }
-                        }
-                    }
-
-                    Target::FriendlyCharacter => {
-                        let player = state.players.get(player_id).unwrap();
-                        let mut valid = Vec::new();
-                        valid.push(("hero", None));
-                        for (i, _) in player.zones.board.iter().enumerate() {
-                            valid.push(("minion", Some(i)));
-                        }
-                        let choice = chooser.choose(
-                            state,
-                            crate::game::engine::choose::Choice::Target {
-                                valid_targets: (0..valid.len()).collect(),
-                            }
-                        );
-                        if let crate::game::engine::choose::Choice::Target { valid_targets } = choice {
-                            let (kind, opt_idx) = &valid[valid_targets[0]];
-                            let player = state.players.get_mut(player_id).unwrap();
-                            if *kind == "hero" {
-                                player.stats.health -= *dmg;
-                                println!("[DAMAGE] Damage {} to FRIENDLY hero", dmg);
-                            } else if let Some(idx) = opt_idx {
-                                if let Some(minion) = player.zones.board.get_mut(*idx) {
-                                    minion.status.current_health = Some(minion.status.current_health.unwrap_or(0) - *dmg);
-                                    println!("[DAMAGE] Damage {} to FRIENDLY minion {}", dmg, minion.name);
-                                }
-                            }
-                        }
-                    }
-
-                    Target::AllEnemyCharacter => {
-                        let opponent_id = player_id.opponent();
-                        if let Some(opponent) = state.players.get_mut(&opponent_id) {
-                            // Minions adverses
-                            for minion in opponent.zones.board.iter_mut() {
-                                minion.status.current_health = Some(minion.status.current_health.unwrap_or(0) - *dmg);
-                                println!("[DAMAGE] Damage {} to ENEMY minion {}", dmg, minion.name);
-                            }
-                            // Héros adverse
-                            opponent.stats.health -= *dmg;
-                            println!("[DAMAGE] Damage {} to ENEMY hero", dmg);
-                        }
-                    }
-
-                    Target::FriendlyMinion => {
-                        let player = state.players.get(player_id).unwrap();
-                        let valid: Vec<_> = player.zones.board.iter().enumerate().map(|(i, _)| i).collect();
-                        let choice = chooser.choose(
-                            state,
-                            crate::game::engine::choose::Choice::Target {
-                                valid_targets: valid.clone(),
-                            }
-                        );
-                        if let crate::game::engine::choose::Choice::Target { valid_targets } = choice {
-                            let idx = valid_targets[0];
-                            let player = state.players.get_mut(player_id).unwrap();
-                            if let Some(minion) = player.zones.board.get_mut(idx) {
-                                minion.status.current_health = Some(minion.status.current_health.unwrap_or(0) - *dmg);
-                                println!("[DAMAGE] Damage {} to FRIENDLY minion {}", dmg, minion.name);
-                            }
-                        }
-                    }
-
-                    Target::EnemyMinion => {
-                        let opponent_id = player_id.opponent();
-                        let opponent = state.players.get(&opponent_id).unwrap();
-                        let valid: Vec<_> = opponent.zones.board.iter().enumerate().map(|(i, _)| i).collect();
-
-                        if valid.is_empty() {
-                            println!("[DAMAGE] Pas de minion ennemi valide pour cibler");
-                            return;
-                        }
-
-                        let choice = chooser.choose(
-                            state,
-                            crate::game::engine::choose::Choice::Target {
-                                valid_targets: valid.clone(),
-                            }
-                        );
-
-                        if let crate::game::engine::choose::Choice::Target { valid_targets } = choice {
-                            if valid_targets.is_empty() {
-                                println!("[DAMAGE] Aucun target valide choisi");
-                                return;
-                            }
-                            let idx = valid_targets[0];
-                            let opponent = state.players.get_mut(&opponent_id).unwrap();
-                            if let Some(minion) = opponent.zones.board.get_mut(idx) {
-                                minion.status.current_health = Some(minion.status.current_health.unwrap_or(0) - *dmg);
-                                println!("[DAMAGE] Damage {} to ENEMY minion {}", dmg, minion.name);
-                            }
-                        }
-                    }
-
-
-                    _ => {
-                        // Garde ta logique précédente pour les autres cibles (EnemyHero, AllEnemyMinion, etc)
-                    }
-                }
-                // === SUPPRESSION GÉNÉRIQUE DES MORTS APRÈS CHAQUE DÉGÂT ===
-                remove_dead_minions(state);
-            }
-        }
-
-        // ---- HEAL ----
-        Effect::Heal { amount: Some(heal), target: Some(target), .. } => {
-            match target {
-                Target::FriendlyHero => {
-                    if let Some(player) = state.players.get_mut(player_id) {
-                        player.heal(*heal);
-                        println!("[HEAL] {} soigne son héros de {} PV (nouveaux PV : {})", player_id.id_string(), heal, player.stats.health);
-                    }
-                }
-                Target::FriendlyCharacter => {
-                    let player = state.players.get(player_id).unwrap();
-                    let mut valid = Vec::new();
-                    valid.push(("hero", None));
-                    for (i, _) in player.zones.board.iter().enumerate() {
-                        valid.push(("minion", Some(i)));
-                    }
-                    let choice = chooser.choose(
-                        state,
-                        crate::game::engine::choose::Choice::Target { valid_targets: (0..valid.len()).collect() }
-                    );
-                    if let crate::game::engine::choose::Choice::Target { valid_targets } = choice {
-                        let (kind, opt_idx) = &valid[valid_targets[0]];
-                        let player = state.players.get_mut(player_id).unwrap();
-                        if *kind == "hero" {
-                            player.heal(*heal);
-                            println!("[HEAL] {} soigne son héros de {} PV (nouveaux PV : {})", player_id.id_string(), heal, player.stats.health);
-                        } else if let Some(idx) = opt_idx {
-                            if let Some(minion) = player.zones.board.get_mut(*idx) {
-                                minion.status.current_health = Some(minion.status.current_health.unwrap_or(0) + *heal);
-                                println!("[HEAL] {} soigne {} de {} PV (nouveaux PV : {})", player_id.id_string(), minion.name, heal, minion.status.current_health.unwrap_or(0));
-                            }
-                        }
-                    }
-                }
-                Target::AnyCharacter => {
-                    let mut valid = Vec::new();
-                    for (pid, player) in state.players.iter() {
-                        valid.push((pid.clone(), "hero".to_string(), None));
-                        for (i, _) in player.zones.board.iter().enumerate() {
-                            valid.push((pid.clone(), "minion".to_string(), Some(i)));
-                        }
-                    }
-                    let choice = chooser.choose(
-                        state,
-                        crate::game::engine::choose::Choice::Target { valid_targets: (0..valid.len()).collect() }
-                    );
-                    if let crate::game::engine::choose::Choice::Target { valid_targets } = choice {
-                        let (tgt_pid, tgt_type, opt_idx) = &valid[valid_targets[0]];
-                        let tgt_player = state.players.get_mut(tgt_pid).unwrap();
-                        if tgt_type == "hero" {
-                            tgt_player.heal(*heal);
-                            println!("[HEAL] Heal {} to hero {:?}", heal, tgt_pid);
-                        } else if let Some(idx) = opt_idx {
-                            if let Some(minion) = tgt_player.zones.board.get_mut(*idx) {
-                                minion.status.current_health = Some(minion.status.current_health.unwrap_or(0) + *heal);
-                                println!("[HEAL] Heal {} to minion {}", heal, minion.name);
-                            }
-                        }
-                    }
-                }
-                _ => println!("[HEAL] Target '{:?}' non encore géré pour Heal", target),
-            }
-        }
-
-        // ---- DRAW ----
-        Effect::Draw { amount, filter, target, .. } => {
-            let amt = amount.unwrap_or(1);
-            if let Some(f) = filter {
-                if let Some(player) = state.players.get_mut(player_id) {
-                    println!("[DRAW] {} va piocher {} carte(s) filtrée(s) ({:?})", player_id.id_string(), amt, f);
-                    draw_n_with_filter(player, amt, f.clone());
-                }
-            } else if let Some(tgt) = target {
-                match tgt {
-                    Target::FriendlyHero => {
-                        if let Some(player) = state.players.get_mut(player_id) {
-                            println!("[DRAW] {} va piocher {} carte(s)", player_id.id_string(), amt);
-                            draw_n(player, amt);
-                        }
-                    }
-                    Target::EnemyHero => {
-                        let opp_id = player_id.opponent();
-                        if let Some(opponent) = state.players.get_mut(&opp_id) {
-                            println!("[DRAW] {} va piocher {} carte(s)", opp_id.id_string(), amt);
-                            draw_n(opponent, amt);
-                        }
-                    }
-                    _ => println!("[DRAW] Target '{:?}' non géré dans Draw", tgt),
-                }
-            } else {
-                if let Some(player) = state.players.get_mut(player_id) {
-                    println!("[DRAW] {} va piocher {} carte(s)", player_id.id_string(), amt);
-                    draw_n(player, amt);
-                }
-            }
-        }
-
-        // ---- BUFF ----
-        Effect::Buff {
-    attack,
-    health,
-    amount,
-    random: _,
-    duration: _,
-    filter: _,
-    target,
-    trigger: _,
-} => {
-    // ΔATK et ΔPV (fallback simple : "amount" = bonus d'ATK si "attack" absent)
-    let add_atk = (*attack).or(*amount).unwrap_or(0);
-    let add_hp  = (*health).unwrap_or(0);
-
-    println!(
-        "[BUFF] owner={:?} target={:?} atk:+{} hp:+{} amount={:?}",
-        owner_id, target, add_atk, add_hp, amount
-    );
-
-    // petit helper local pour appliquer le buff à un serviteur
-    fn apply_buff_to(minion: &mut Card, add_atk: i32, add_hp: i32) {
-        if add_atk != 0 {
-            minion.status.attack_modifiers += add_atk;
-            let before = crate::game::engine::utils::minion_stats_string(minion);
-
-            // ATTAQUE
-            minion.status.attack_modifiers += add_atk;
-
-            // VIE (courante + max si présent)
-            if add_hp != 0 {
-                if let Some(h) = minion.status.current_health {
-                    minion.status.current_health = Some(h + add_hp);
-                }
-                if let Some(mh) = minion.max_health {
-                    minion.max_health = Some(mh + add_hp);
-                }
-            }
-
-            let after = crate::game::engine::utils::minion_stats_string(minion);
-            println!(
-                "[BUFF→APPLIED] {}  ==>  {}   ({}; +{}/+{})",
-                before, after, minion.name, add_atk, add_hp
-            );
-
-        }
-        if add_hp != 0 {
-            // met à jour max, base et PV courants de façon cohérente
-            let prev_max = minion.max_health.unwrap_or(minion.health.unwrap_or(0));
-            minion.max_health = Some(prev_max + add_hp);
-
-            let prev_base = minion.health.unwrap_or(prev_max);
-            minion.health = Some(prev_base + add_hp);
-
-            let prev_cur = minion.status.current_health.unwrap_or(prev_base);
-            minion.status.current_health = Some(prev_cur + add_hp);
-        }
-    }
-
-    match target {
-        // Tous les serviteurs alliés
-        Some(Target::AllFriendlyMinion) => {
-            let p = state.players.get_mut(owner_id).unwrap();
-            for m in &mut p.zones.board {
-                apply_buff_to(m, add_atk, add_hp);
-            }
-        }
-        // Tous les serviteurs (alliés + ennemis)
-        Some(Target::AllMinion) => {
-            for pid in [*owner_id, owner_id.opponent()] {
-                let p = state.players.get_mut(&pid).unwrap();
-                for m in &mut p.zones.board {
-                    apply_buff_to(m, add_atk, add_hp);
-                }
-            }
-        }
-        // Un serviteur allié (simplifié : on prend le premier)
-        Some(Target::FriendlyMinion) | Some(Target::SelfTarget) => {
-            let p = state.players.get_mut(owner_id).unwrap();
-            if let Some(m) = p.zones.board.first_mut() {
-                apply_buff_to(m, add_atk, add_hp);
-            }
-        }
-        // Par défaut : pas encore géré
-        other => {
-            println!("[BUFF] cible {:?} non gérée pour l’instant (ΔATK={}, ΔPV={})", other, add_atk, add_hp);
-        }
-    }
-}
-
-        // ---- SUMMON ----
-
-        Effect::Summon {
-    amount,
-    card_id,
-    filter,
-    source,
-    destination,
-    random,
-    trigger: _,
-} => {
-    let how_many = amount.unwrap_or(1);
-    // Par défaut, on cible le board du joueur courant, sauf si destination explicite
-    let dest_player_id = match destination.as_deref() {
-        Some("opponent_board") => player_id.opponent(),
-        // Ajoute d'autres cas spécifiques si besoin
-        _ => *player_id,
-    };
-
-    // Cas 1 : Summon d'un ou plusieurs minions par card_id (token classique, Bear, Nerubian, etc)
-    if let Some(cid) = card_id {
-        for _ in 0..how_many {
-            // À adapter selon ta fonction de création de carte/token !
-            let template = card_templates.get(cid.as_str()).unwrap();
-
-            let mut new_minion = template.to_card();
-
-            // Tu veux probablement push dans le board du dest_player_id
-            let dest_player = state.players.get_mut(&dest_player_id).unwrap();
-            dest_player.zones.board.push(new_minion);
-            println!("[SUMMON] {} invoque {}", dest_player_id.id_string(), cid);
-        }
-        return;
-    }
-
-    // Cas 2 : Summon depuis une main/zone (Dirty Rat…)
-    if let (Some(src), Some(dest), Some(is_random)) = (source.as_ref(), destination.as_ref(), random) {
-        if src == "hand" && dest == "opponent_board" && *is_random {
-            let opponent_id = player_id.opponent();
-            let opponent = state.players.get_mut(&opponent_id).unwrap();
-            if !opponent.zones.hand.is_empty() {
-                use rand::seq::SliceRandom;
-                let mut rng = rand::thread_rng();
-                let idx = (0..opponent.zones.hand.len()).collect::<Vec<_>>()
-                    .choose(&mut rng).copied().unwrap_or(0);
-                let mut minion = opponent.zones.hand.remove(idx);
-                println!("[SUMMON] Dirty Rat sort {} de la main adverse et le pose sur le board !", minion.name);
-                opponent.zones.board.push(minion);
-            }
-            return;
-        }
-        // Ajoute d'autres cas si besoin (main du joueur courant, etc)
-    }
-
-    // Cas 3 : Summon via filter (Sneed’s, Maze Guide…)
-    if let Some(filt) = filter {
-        // Ex: filtre par type minion et rareté légendaire (à adapter selon filt)
-        let pool: Vec<_> = card_templates.values()
-            .filter(|tpl| {
-                tpl.card_type == CardType::Minion
-                    && tpl.rarity == Some(Rarity::Legendary)
-            })
-            .collect();
-
-        use rand::seq::SliceRandom;
-        let mut rng = rand::thread_rng();
-        if let Some(template) = pool.choose(&mut rng) {
-            let mut new_minion = template.to_card();
-            println!(
-                "[SUMMON] {} invoque {} ({})",
-                dest_player_id.id_string(),
-                template.card_name,          // ou template.card_name selon ton struct
-                template.card_id
-            );
-
-            let dest_player = state.players.get_mut(&dest_player_id).unwrap();
-            dest_player.zones.board.push(new_minion);
-        }
-     return;
-}
-
-
-    // Cas 4 : Summon une copie de soi-même (self_copy)
-    if let Some(target) = destination.as_ref().or(card_id.as_ref()) {
-        if target == "self_copy" {
-            // Cherche le minion sur le board du joueur courant
-            if let Some(player) = state.players.get_mut(player_id) {
-                if let Some(myself) = player.zones.board.last().cloned() {
-                    for _ in 0..how_many {
-                        let mut copy = myself.clone();
-                        // Reset les status nécessaires
-                        copy.status.current_health = copy.max_health;
-                        player.zones.board.push(copy);
-                    }
-                    println!("[SUMMON] {} se clone ({})", myself.name, how_many);
-                }
-            }
-            return;
-        }
-    }
-
-    // TODO: gérer d’autres variantes exotiques si besoin.
-}
-
-
-                // ---- AUTRES ----
-                _ => {
-                    println!("[APPLY] Effet non encore géré : {:?}", effect);
-                }
-            }
-        }
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use crate::game::triggers::{Trigger, TriggerDef};
+use crate::game::engine::utils::{ChooseRandomMut, IdString};
+use crate::data::card_template::{EffectTemplate,CardTemplate};
+use crate::game::state::{GameState, PlayerId};
+use crate::game::targets::Target;
+use crate::game::engine::draw::{draw_n, draw_n_with_filter};
+use crate::game::engine::cost::apply_modify_cost;
+use crate::game::engine::discover::discover;
+use crate::game::engine::choose_one::choose_one;
+use crate::game::engine::choose::Choice;
+use crate::game::engine::attack::{enemy_defender, forced_attack, redirect_attack};
+use crate::game::engine::deck::{destroy_not_starting_in_deck, reveal_top, shuffle_into_deck, summon_from_deck, DeckPosition};
+use crate::game::engine::enchant::{enchant_targets, silence_targets, source_card_id};
+use crate::game::engine::generate::{copies_from_zone, give_cards, random_cards, CardModification};
+use crate::game::engine::graveyard::{fresh_copy, graveyard_query, resurrect, DEATH_ORDER_TAG};
+use crate::game::engine::location::location_targets;
+use crate::game::engine::replay::{replay_cards, trigger_deathrattles};
+use crate::game::engine::targeting::{can_be_targeted, pick_character, pick_target, resolve_minion_targets, CharacterRef};
+use crate::game::engine::weapon::destroy_weapon;
+use crate::game::engine::zones::{add_to_hand, bounce, discard, move_card, summon};
+use crate::game::enums::{CardType, Races, Zone};
+use crate::game::card::{Card, Enchantment};
+use crate::game::event::GameEvent;
+use crate::game::condition::EffectCondition;
+use crate::game::filter::CardFilter;
+use crate::game::history::{Character, GameHistory};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Effect {
+
+    Spellpower { amount: Option<i32> },
+
+    Damage {
+        amount: Option<i32>,
+        repeat: Option<i32>,
+        target: Option<Target>,
+        random: Option<bool>,
+        trigger: Option<String>,
+        condition: Option<EffectCondition>,
+    },
+    Heal {
+        amount: Option<i32>,
+        target: Option<Target>,
+        trigger: Option<String>,
+    },
+    Draw {
+        amount: Option<i32>,
+        target: Option<Target>,
+        filter: Option<CardFilter>,
+        condition: Option<EffectCondition>,
+        trigger: Option<String>,
+    },
+    Buff {
+        attack: Option<i32>,
+        health: Option<i32>,
+        amount: Option<i32>,
+        random: Option<bool>,
+        duration: Option<String>,
+        filter: Option<CardFilter>,
+        target: Option<Target>,
+        condition: Option<EffectCondition>,
+        trigger: Option<Trigger>,
+    },
+    Summon {
+        amount: Option<i32>,
+        card_id: Option<String>,
+        filter: Option<CardFilter>,
+        source: Option<String>,
+        destination: Option<String>,
+        random: Option<bool>,
+        trigger: Option<String>,
+    },
+    /// Génère des cartes en main : `card_id` précis, tirage dans le pool de `filter`,
+    /// ou copie d'une carte de `source_zone` (Psychic Conjurer)
+    AddCardToHand {
+        amount: Option<i32>,
+        card_id: Option<String>,
+        random: Option<bool>,
+        filter: Option<CardFilter>,
+        source_zone: Option<String>,
+        /// Main qui reçoit les cartes : « opponent_hand » pour l'adversaire
+        zone: Option<String>,
+        modify: Option<CardModification>,
+        trigger: Option<String>,
+    },
+    Discover {
+        amount: Option<i32>,
+        source_pool: Option<serde_json::Value>,
+        condition: Option<EffectCondition>,
+        destination: Option<String>,   // "hand" (défaut), "cast", "summon"
+        mystery: Option<bool>,         // discover_mystery : 4e option aléatoire
+        trigger: Option<String>,
+    },
+    Aura {
+        effect: Box<serde_json::Value>,
+        target: Option<String>,
+    },
+    /// Donne « Râle d'agonie : `effect` » aux cartes visées (enchantement)
+    GrantDeathrattle {
+        effect: Box<serde_json::Value>,
+        target: Option<Target>,
+        random: Option<bool>,
+        trigger: Option<String>,
+    },
+    /// Donne un mot-clé aux cartes visées (enchantement) ; sans cible, au serviteur source
+    GrantMechanic {
+        mechanic: String,
+        target: Option<Target>,
+        random: Option<bool>,
+        trigger: Option<String>,
+    },
+    Silence { target: Option<Target> },
+    Destroy {
+        target: Option<Target>,
+        condition: Option<EffectCondition>,
+        random: Option<bool>,
+        trigger: Option<String>,
+    },
+    SetHealth {
+        value: Option<i32>,
+        target: Option<Target>,
+        trigger: Option<String>,
+    },
+    Overload { amount: Option<i32> },
+    UnlockOverload,
+    GainMana {
+        amount: Option<i32>,
+        temporary: Option<bool>,
+    },
+    GainManaCrystal {
+        amount: Option<i32>,
+        empty: Option<bool>,
+        target: Option<Target>,
+    },
+    DestroyManaCrystal {
+        amount: Option<i32>,
+        target: Option<Target>,
+    },
+    EmptyManaCrystal {
+        amount: Option<i32>,
+        target: Option<Target>,
+    },
+    GainArmor {
+        amount: Option<i32>,
+        /// Montant dynamique (`{"value": "spell_cost"}`) : remplace `amount`
+        amount_from: Option<String>,
+        target: Option<Target>,
+        trigger: Option<String>,
+    },
+    EquipWeapon {
+        card_id: Option<String>,
+        trigger: Option<String>,
+    },
+    Tradeable,
+    CantAttack,
+    /// L'attaque touche aussi les serviteurs voisins du défenseur
+    Cleave,
+    /// Le serviteur source attaque le défenseur de la dernière attaque (Illidari Inquisitor)
+    FollowUpAttack { trigger: Option<String> },
+    /// Le serviteur source attaque un ennemi : `lowest_health_enemy`, sinon au hasard
+    AttackEnemy {
+        target: Option<Target>,
+        trigger: Option<String>,
+    },
+    /// Pendant la déclaration d'une attaque : elle vise un autre personnage au hasard
+    RedirectAttack { trigger: Option<String> },
+    /// Déclenche le(s) Râle(s) d'agonie des serviteurs visés sans les tuer
+    TriggerDeathrattle {
+        target: Option<Target>,
+        random: Option<bool>,
+        trigger: Option<String>,
+    },
+    /// Rejoue les cartes jouées pendant la partie (cibles d'origine, ou au hasard)
+    ReplayCards {
+        filter: Option<CardFilter>,
+        random_targets: Option<bool>,
+        trigger: Option<String>,
+    },
+    /// Répète `effect` : `times` fois, un nombre lu dans l'état (`times_from` :
+    /// « corpses_spent »), ou jusqu'à ce que `until` soit rempli
+    Repeat {
+        times: Option<i32>,
+        times_from: Option<String>,
+        until: Option<EffectCondition>,
+        effect: Box<Effect>,
+    },
+    /// Donne l'Attaque du serviteur source (`self_attack`), même mort, aux serviteurs visés
+    TransferAttack {
+        target: Option<Target>,
+        random: Option<bool>,
+        trigger: Option<String>,
+    },
+    SwapStats {
+        target: Option<Target>,
+        trigger: Option<String>,
+    },
+    /// Renvoie en main une carte du board (remise à son état imprimé) ou, avec `zone`
+    /// « graveyard », les cartes du cimetière satisfaisant `filter`
+    ReturnToHand {
+        target: Option<Target>,
+        zone: Option<String>,
+        filter: Option<CardFilter>,
+        /// Seulement les cartes jouées par le lanceur à son tour précédent (Krag'wa)
+        last_turn: Option<bool>,
+        trigger: Option<String>,
+    },
+    /// Copie en main des cartes d'une zone (positions `leftmost`/`rightmost`) ou,
+    /// sans zone, le dernier sort lancé (Lorewalker Cho)
+    CopyCardToHand {
+        from_zone: Option<String>,
+        positions: Vec<String>,
+        /// Main qui reçoit les copies : « opponent_hand » pour l'adversaire
+        to: Option<String>,
+        card_type: Option<CardType>,
+        target: Option<Target>,
+        amount: Option<i32>,
+        modify: Option<CardModification>,
+        trigger: Option<String>,
+    },
+    Discard {
+        amount: Option<i32>,
+        random: Option<bool>,
+        trigger: Option<String>,
+    },
+    ModifyCost {
+        mode: Option<String>,
+        amount: Option<i32>,
+        target: Option<Vec<String>>,
+        filter: Option<CardFilter>,
+        value: Option<serde_json::Value>,
+        duration: Option<String>,
+        condition: Option<EffectCondition>,
+        trigger: Option<Trigger>,
+    },
+    Elusive,
+    /// « Dépensez N Cadavres (pour…) » : `then` n'est résolu que si le coût est payé.
+    SpendCorpse {
+        amount: Option<i32>,
+        up_to: Option<bool>,
+        condition: Option<EffectCondition>,
+        #[serde(default)]
+        then: Vec<Effect>,
+    },
+    GainCorpse { amount: Option<i32> },
+    /// Choix des Mystiques : `amount` branche(s) parmi `options`
+    ChooseOne {
+        options: Vec<Effect>,
+        amount: Option<i32>,
+    },
+    CombineChooseOne,
+    NoCorpse,
+    /// Invoque des serviteurs alliés morts pendant la partie (au hasard, ou le plus cher)
+    Resurrect {
+        amount: Option<i32>,
+        filter: Option<CardFilter>,
+        highest_cost: Option<bool>,
+        trigger: Option<String>,
+    },
+    ModifyCorpseGain { multiplier: Option<i32> },
+    /// Mélange `amount` exemplaires de `card_id` dans un deck (`position` : random, top, bottom)
+    ShuffleIntoDeck {
+        amount: Option<i32>,
+        card_id: Option<String>,
+        position: Option<String>,
+        target: Option<Target>,
+        trigger: Option<String>,
+    },
+    /// Révèle la carte du dessus d'un deck sans la piocher
+    RevealTop { target: Option<Target> },
+    /// Invoque des serviteurs du deck satisfaisant le filtre (Finja)
+    SummonFromDeck {
+        amount: Option<i32>,
+        filter: Option<CardFilter>,
+        condition: Option<EffectCondition>,
+        trigger: Option<String>,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+
+impl Effect {
+    /// Condition « Si… » portée par l'effet, vérifiée avant sa résolution.
+    pub fn condition(&self) -> Option<&EffectCondition> {
+        match self {
+            Effect::Damage { condition, .. }
+            | Effect::Discover { condition, .. }
+            | Effect::Destroy { condition, .. }
+            | Effect::ModifyCost { condition, .. }
+            | Effect::Buff { condition, .. }
+            | Effect::Draw { condition, .. }
+            | Effect::SpendCorpse { condition, .. }
+            | Effect::SummonFromDeck { condition, .. } => condition.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Parse un effet imbriqué (aura, grant_deathrattle…) depuis son JSON brut.
+    pub fn from_value(value: &Value) -> Self {
+        serde_json::from_value::<EffectTemplate>(value.clone())
+            .map(|tpl| Effect::from_template(&tpl))
+            .unwrap_or(Effect::Unknown)
+    }
+
+    pub fn from_template(template: &EffectTemplate) -> Self {
+        match template.effect_type.to_ascii_lowercase().as_str() {           
+            // Mot-clé seul (option de Choix des Mystiques…) : le porteur le gagne
+            "taunt" | "charge" | "rush" | "divine_shield" | "lifesteal"
+            | "poisonous" | "reborn" | "stealth" | "windfury" | "megawindfury" => Effect::GrantMechanic {
+                mechanic: mechanic_name(&template.effect_type),
+                target: Some(Target::SelfTarget),
+                random: None,
+                trigger: None,
+            },
+            "choose" => Effect::ChooseOne {
+                options: template
+                    .extra
+                    .get("options")
+                    .and_then(|v| v.as_array())
+                    .map(|opts| opts.iter().map(Effect::from_value).collect())
+                    .unwrap_or_default(),
+                amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
+            },
+            "combine_choose_one" => Effect::CombineChooseOne,
+            "add_card_to_hand" => {
+                let filter = template.extra.get("filter");
+                Effect::AddCardToHand {
+                    amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
+                    card_id: template.extra.get("card_id").and_then(|v| v.as_str().map(|s| s.to_string())),
+                    random: template.extra.get("random").and_then(|v| v.as_bool()),
+                    filter: filter.map(CardFilter::from_value),
+                    // « mode: copy » : la zone d'origine est donnée dans le filtre
+                    source_zone: filter
+                        .and_then(|f| f.get("zone"))
+                        .and_then(|v| v.as_str().map(|s| s.to_string())),
+                    zone: template.extra.get("zone").and_then(|v| v.as_str().map(|s| s.to_string())),
+                    modify: template.extra.get("modify").and_then(|v| serde_json::from_value(v.clone()).ok()),
+                    trigger: None,
+                }
+            }
+            "aura" => Effect::Aura {
+                effect: Box::new(template.extra.get("effect").cloned().unwrap_or(Value::Null)),
+                target: template.extra.get("target").and_then(|v| v.as_str().map(|s| s.to_string())),
+            },
+            "banish_temporarily" => Effect::Unknown,
+            "buff" => {
+    let attack   = template.extra.get("attack").and_then(|v| v.as_i64()).map(|x| x as i32);
+    let health   = template.extra.get("health").and_then(|v| v.as_i64()).map(|x| x as i32);
+    let amount   = template.extra.get("amount").and_then(|v| v.as_i64()).map(|x| x as i32);
+    let random   = template.extra.get("random").and_then(|v| v.as_bool());
+    let duration = template.extra.get("duration").and_then(|v| v.as_str()).map(|s| s.to_string());
+    let filter   = template.extra.get("filter").map(CardFilter::from_value);
+    let target   = template
+        .extra
+        .get("target")
+        .and_then(|v| v.as_str())
+        .and_then(|s| crate::game::targets::Target::from_str(s));
+    let condition = template.extra.get("condition").map(EffectCondition::from_value);
+
+    Effect::Buff {
+        attack,
+        health,
+        amount,
+        random,
+        duration,
+        filter,
+        target,
+        condition,
+        trigger: template.trigger.clone(),
+    }
+}
+            "copy_card_to_hand" => {
+                let from = template.extra.get("from");
+                Effect::CopyCardToHand {
+                    from_zone: from.and_then(|f| f.get("zone")).and_then(|v| v.as_str().map(|s| s.to_string())),
+                    positions: match from.and_then(|f| f.get("position")) {
+                        Some(Value::Array(ps)) => ps.iter().filter_map(|p| p.as_str().map(|s| s.to_string())).collect(),
+                        Some(Value::String(p)) => vec![p.clone()],
+                        _ => Vec::new(),
+                    },
+                    to: template
+                        .extra
+                        .get("to")
+                        .and_then(|t| t.get("zone"))
+                        .and_then(|v| v.as_str().map(|s| s.to_string())),
+                    card_type: template.extra.get("card_type").and_then(|v| v.as_str()).map(CardType::from_str),
+                    target: template.extra.get("target").and_then(|v| v.as_str()).and_then(Target::from_str),
+                    amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
+                    modify: template.extra.get("modify").and_then(|v| serde_json::from_value(v.clone()).ok()),
+                    trigger: None,
+                }
+            }
+            "damage" => {
+                let target_str = template.extra.get("target").and_then(|v| v.as_str());
+                let parsed_target = target_str.and_then(Target::from_str);
+                Effect::Damage {
+                    amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
+                    repeat: template.extra.get("repeat").and_then(|v| v.as_i64().map(|x| x as i32)),
+                    target: parsed_target,
+                    random: template.extra.get("random").and_then(|v| v.as_bool()),
+                    trigger: template.extra.get("trigger").and_then(|v| v.as_str().map(|s| s.to_string())),
+                    condition: template.extra.get("condition").map(EffectCondition::from_value),
+                }
+            }
+            "destroy" => Effect::Destroy {
+                target: template.extra.get("target").and_then(|v| v.as_str()).and_then(Target::from_str),
+                condition: template.extra.get("condition").map(EffectCondition::from_value),
+                random: template.extra.get("random").and_then(|v| v.as_bool()),
+                trigger: None,
+            },
+            "destroy_mana_crystal" => Effect::DestroyManaCrystal {
+                amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
+                target: template.extra.get("target").and_then(|v| v.as_str()).and_then(Target::from_str),
+            },
+            "discard" => Effect::Discard {
+                amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
+                random: template.extra.get("random").and_then(|v| v.as_bool()),
+                trigger: None,
+            },
+            "elusive" => Effect::Elusive,
+            "cant_attack" => Effect::CantAttack,
+            "cleave" => Effect::Cleave,
+            "follow_up_attack" => Effect::FollowUpAttack { trigger: None },
+            "attack_enemy" => Effect::AttackEnemy {
+                target: template.extra.get("target").and_then(|v| v.as_str()).and_then(Target::from_str),
+                trigger: None,
+            },
+            "redirect_attack" => Effect::RedirectAttack { trigger: None },
+            "transfer_attack" => Effect::TransferAttack {
+                target: template.extra.get("target").and_then(|v| v.as_str()).and_then(Target::from_str),
+                random: template.extra.get("random").and_then(|v| v.as_bool()),
+                trigger: None,
+            },
+            "discover" | "discover_mystery" => Effect::Discover {
+                amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
+                // le pool est décrit par « source_pool » ou, le plus souvent, « filter »
+                source_pool: template
+                    .extra
+                    .get("source_pool")
+                    .or_else(|| template.extra.get("filter"))
+                    .cloned(),
+                condition: template.extra.get("condition").map(EffectCondition::from_value),
+                destination: template.extra.get("destination").and_then(|v| v.as_str().map(|s| s.to_string())),
+                mystery: Some(template.effect_type.eq_ignore_ascii_case("discover_mystery")),
+                trigger: None,
+            },
+            "draw" => {
+                let target_str = template.extra.get("target").and_then(|v| v.as_str());
+                let parsed_target = target_str.and_then(Target::from_str);
+
+                Effect::Draw {
+                    amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
+                    target: parsed_target,
+                    filter: template.extra.get("filter").map(CardFilter::from_value),
+                    condition: template.extra.get("condition").map(EffectCondition::from_value),
+                    trigger: template.extra.get("trigger").and_then(|v| v.as_str().map(|s| s.to_string())),
+                }
+            },
+
+            "empty_mana_crystal" => Effect::EmptyManaCrystal {
+                amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
+                target: template.extra.get("target").and_then(|v| v.as_str()).and_then(Target::from_str),
+            },
+            "equip_weapon" => Effect::EquipWeapon {
+                card_id: template.extra.get("card_id").and_then(|v| v.as_str().map(|s| s.to_string())),
+                trigger: None,
+            },
+            "freeze" => Effect::Unknown,
+            "gain_corpse" => Effect::GainCorpse {
+                amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
+            },
+            "gain_mana" => Effect::GainMana {
+                amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
+                temporary: template.extra.get("temporary").and_then(|v| v.as_bool()),
+            },
+            "gain_mana_crystal" => Effect::GainManaCrystal {
+                amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
+                empty: template.extra.get("empty").and_then(|v| v.as_bool()),
+                target: template.extra.get("target").and_then(|v| v.as_str()).and_then(Target::from_str),
+            },
+            "gain_armor" => Effect::GainArmor {
+                amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
+                amount_from: template
+                    .extra
+                    .get("amount")
+                    .and_then(|v| v.get("value"))
+                    .and_then(|v| v.as_str().map(|s| s.to_string())),
+                target: template.extra.get("target").and_then(|v| v.as_str()).and_then(Target::from_str),
+                trigger: None,
+            },
+            "gain_health" => Effect::Unknown,
+            "grant_mechanic" => Effect::GrantMechanic {
+                mechanic: template
+                    .extra
+                    .get("mechanic")
+                    .and_then(|v| v.as_str())
+                    .map(|m| mechanic_name(&m.to_ascii_lowercase()))
+                    .unwrap_or_default(),
+                target: template.extra.get("target").and_then(|v| v.as_str()).and_then(Target::from_str),
+                random: template.extra.get("random").and_then(|v| v.as_bool()),
+                trigger: None,
+            },
+            "grant_deathrattle" => Effect::GrantDeathrattle {
+                effect: Box::new(template.extra.get("effect").cloned().unwrap_or(Value::Null)),
+                target: template.extra.get("target").and_then(|v| v.as_str()).and_then(Target::from_str),
+                random: template.extra.get("random").and_then(|v| v.as_bool()),
+                trigger: None,
+            },
+            "heal" => {
+                let target_str = template.extra.get("target").and_then(|v| v.as_str());
+                let parsed_target = target_str.and_then(Target::from_str);
+                Effect::Heal {
+                    amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
+                    target: parsed_target,
+                    trigger: template.extra.get("trigger").and_then(|v| v.as_str().map(|s| s.to_string())),
+                }
+            },
+            "modify_cost" => Effect::ModifyCost {
+                mode: template.extra.get("mode").and_then(|v| v.as_str().map(|s| s.to_string())),
+                amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
+                // "target" est soit une chaîne, soit une liste (Wayward Sage)
+                target: template.extra.get("target").map(|v| match v {
+                    Value::Array(arr) => arr.iter().filter_map(|t| t.as_str().map(|s| s.to_string())).collect(),
+                    other => other.as_str().map(|s| vec![s.to_string()]).unwrap_or_default(),
+                }),
+                filter: template.extra.get("filter").map(CardFilter::from_value),
+                value: template.extra.get("value").cloned(),
+                duration: template.extra.get("duration").and_then(|v| v.as_str().map(|s| s.to_string())),
+                condition: template.extra.get("condition").map(EffectCondition::from_value),
+                trigger: template.trigger.clone(),
+            },
+            "overload" => Effect::Overload {
+                amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
+            },
+            "repeat" => {
+                let times = template.extra.get("times");
+                Effect::Repeat {
+                    times: times.and_then(|v| v.as_i64()).map(|x| x as i32),
+                    times_from: times.and_then(|v| v.as_str()).map(|s| s.to_string()),
+                    until: template.extra.get("until").map(EffectCondition::from_value),
+                    effect: Box::new(template.extra.get("effect").map(Effect::from_value).unwrap_or(Effect::Unknown)),
+                }
+            }
+            "replay_cards" => Effect::ReplayCards {
+                filter: template.extra.get("filter").map(CardFilter::from_value),
+                random_targets: template.extra.get("random_targets").and_then(|v| v.as_bool()),
+                trigger: None,
+            },
+            "resurrect" => Effect::Resurrect {
+                amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
+                filter: template.extra.get("filter").map(CardFilter::from_value),
+                highest_cost: template.extra.get("mode").and_then(|v| v.as_str()).map(|m| m == "highest_cost"),
+                trigger: template.extra.get("trigger").and_then(|v| v.as_str().map(|s| s.to_string())),
+            },
+            "return_to_hand" => {
+                let filter = template.extra.get("filter");
+                Effect::ReturnToHand {
+                    target: template.extra.get("target").and_then(|v| v.as_str()).and_then(Target::from_str),
+                    // la zone d'origine peut aussi être précisée dans le filtre
+                    zone: template
+                        .extra
+                        .get("zone")
+                        .or_else(|| filter.and_then(|f| f.get("zone")))
+                        .and_then(|v| v.as_str().map(|s| s.to_string())),
+                    filter: filter.map(CardFilter::from_value),
+                    last_turn: filter
+                        .and_then(|f| f.get("last_turn_by"))
+                        .and_then(|v| v.as_str())
+                        .map(|by| by == "self"),
+                    trigger: None,
+                }
+            }
+            "set_health" => Effect::SetHealth {
+                value: template.extra.get("value").and_then(|v| v.as_i64().map(|x| x as i32)),
+                target: template.extra.get("target").and_then(|v| v.as_str()).and_then(Target::from_str),
+                trigger: None,
+            },
+            "silence" => Effect::Silence {
+                target: template.extra.get("target").and_then(|v| v.as_str()).and_then(Target::from_str),
+            },
+            "spend_corpse" => {
+                let amount = template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32));
+                Effect::SpendCorpse {
+                    amount,
+                    up_to: template.extra.get("mode").and_then(|v| v.as_str()).map(|m| m == "up_to"),
+                    // « required » : le coût complet doit être payable
+                    condition: template.extra.get("condition").map(|c| match c.get("required").and_then(|v| v.as_bool()) {
+                        Some(true) => EffectCondition::CorpsesAvailable(amount.unwrap_or(1).max(0) as u32),
+                        _ => EffectCondition::from_value(c),
+                    }),
+                    then: Vec::new(),
+                }
+            }
+            "no_corpse" => Effect::NoCorpse,
+            "modify_corpse_gain" => Effect::ModifyCorpseGain {
+                multiplier: template.extra.get("multiplier").and_then(|v| v.as_i64().map(|x| x as i32)),
+            },
+            "shuffle_into_deck" => Effect::ShuffleIntoDeck {
+                amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
+                card_id: template.extra.get("card_id").and_then(|v| v.as_str().map(|s| s.to_string())),
+                position: template.extra.get("position").and_then(|v| v.as_str().map(|s| s.to_string())),
+                target: template.extra.get("target").and_then(|v| v.as_str()).and_then(Target::from_str),
+                trigger: None,
+            },
+            "reveal_top" => Effect::RevealTop {
+                target: template.extra.get("target").and_then(|v| v.as_str()).and_then(Target::from_str),
+            },
+            "summon_from_deck" => Effect::SummonFromDeck {
+                amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
+                filter: template.extra.get("filter").map(CardFilter::from_value),
+                condition: template.extra.get("condition").map(EffectCondition::from_value),
+                trigger: None,
+            },
+            "summon" => {
+                Effect::Summon {
+                    amount: template.extra.get("amount").and_then(|v| v.as_i64().map(|x| x as i32)),
+                    card_id: template.extra.get("card_id").and_then(|v| v.as_str().map(|s| s.to_string())),
+                    filter: template.extra.get("filter").map(CardFilter::from_value),
+                    source: template.extra.get("source").and_then(|v| v.as_str().map(|s| s.to_string())),
+                    destination: template.extra.get("destination")
+                        .or_else(|| template.extra.get("zone"))
+                        .or_else(|| template.extra.get("target"))
+                        .and_then(|v| v.as_str().map(|s| s.to_string())),
+                    random: template.extra.get("random").and_then(|v| v.as_bool()),
+                    trigger: template.extra.get("trigger").and_then(|v| v.as_str().map(|s| s.to_string())),
+                }
+
+            },
+            "swap_stats" => Effect::SwapStats {
+                target: template.extra.get("target").and_then(|v| v.as_str()).and_then(Target::from_str),
+                trigger: None,
+            },
+            "tradeable" => Effect::Tradeable,
+            "trigger_deathrattle" => Effect::TriggerDeathrattle {
+                target: template.extra.get("target").and_then(|v| v.as_str()).and_then(Target::from_str),
+                random: template.extra.get("random").and_then(|v| v.as_bool()),
+                trigger: None,
+            },
+            "unlock_overload" => Effect::UnlockOverload,
+            _ => Effect::Unknown,
+        }
+    }
+}
+/// Enlève tous les serviteurs morts sur le board de chaque joueur.
+pub fn remove_dead_minions(state: &mut GameState) {
+    for pid in [PlayerId::Player1, PlayerId::Player2] {
+        let before = state.players[&pid].zones.board.len();
+        while let Some(pos) = state.players[&pid]
+            .zones
+            .board
+            .iter()
+            .position(|m| m.status.current_health.unwrap_or(1) <= 0)
+        {
+            bury_minion(state, &pid, pos);
+        }
+        let player = &state.players[&pid];
+        let after = player.zones.board.len();
+        if before != after {
+            println!(
+                "[CLEANUP] {}/{} minions retirés du board de {:?} (morts)",
+                before - after, before, player.id
+            );
+        }
+    }
+}
+
+/// Retire du board le serviteur mort en `position` : Cadavre, cimetière, puis
+/// `MinionDied` (son Râle d'agonie se résout depuis le cimetière, à sa place).
+pub fn bury_minion(state: &mut GameState, pid: &PlayerId, position: usize) {
+    let player = state.players.get_mut(pid).unwrap();
+    let mut dead = player.remove_from_board(position);
+    player.on_friendly_minion_death(&dead);
+    let card_id = dead.card_id.clone();
+    let is_minion = !dead.is_location();
+    if is_minion {
+        state.history.record_minion_died(pid, &dead);
+        dead.tags.insert(DEATH_ORDER_TAG.to_string(), state.history.minions_died.len() as i32);
+    }
+    player.zones.graveyard.push(dead);
+    if is_minion {
+        state.event_queue.push_back(GameEvent::MinionDied { card_id, owner: *pid, position });
+    }
+}
+
+/// Trace un serviteur touché : dégâts subis et, pour un effet ciblé, cible du sort en cours.
+fn log_minion_hit(history: &mut GameHistory, dealer: PlayerId, owner: PlayerId, minion: &Card, dmg: i32, targeted: bool) {
+    let hit = Character::Minion { owner, card_id: minion.card_id.clone() };
+    if targeted {
+        history.note_target(hit.clone());
+    }
+    history.record_damage(hit, dealer, dmg);
+}
+
+/// Nom de mécanique (champ « mechanics » du JSON) pour un type d'effet mot-clé.
+pub(crate) fn mechanic_name(effect_type: &str) -> String {
+    match effect_type {
+        "divine_shield" => "Divine Shield".to_string(),
+        "megawindfury" => "Mega-Windfury".to_string(),
+        other => {
+            let mut chars = other.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        }
+    }
+}
+
+/// Joueur visé par un effet de mana : le lanceur par défaut, l'adversaire si la cible le désigne.
+fn mana_target(player_id: &PlayerId, target: Option<&Target>) -> PlayerId {
+    match target {
+        Some(Target::EnemyHero) | Some(Target::OtherPlayer) => player_id.opponent(),
+        _ => *player_id,
+    }
+}
+
+fn card_has_any_race(card: &crate::game::card::Card, races: &[Races]) -> bool {
+    match &card.races {
+        Some(card_races) => card_races.iter().any(|r| races.contains(r)),
+        None => false,
+    }
+}
+
+fn apply_buff_values(
+    card: &mut crate::game::card::Card,
+    add_atk: i32,
+    add_hp: i32,
+) {
+    if add_atk != 0 {
+        card.status.attack_modifiers += add_atk;
+    }
+    if add_hp != 0 {
+        // max_health et current_health sont Option<i32>; on les traite prudemment
+        let cur = card.status.current_health.unwrap_or(0);
+        let maxh = card.max_health.unwrap_or(card.health.unwrap_or(0));
+        card.max_health = Some(maxh + add_hp);
+        card.status.current_health = Some(cur + add_hp);
+    }
+}
+
+/// Plafond des répétitions d'un effet `repeat` (et nombre par défaut avec `until`).
+const MAX_REPEAT: i32 = 30;
+
+pub fn apply_effect(
+    state: &mut GameState,
+    player_id: &PlayerId,
+    effect: &Effect,
+    chooser: &dyn crate::game::engine::choose::Chooser,
+    card_templates: &HashMap<String, CardTemplate>,
+) {
+    let owner_id = player_id;  // ou player_id, selon le vrai nom
+    if let Some(cond) = effect.condition() {
+        if !cond.is_met(state, player_id) {
+            println!("[CONDITION] non remplie ({:?}) : effet ignoré", cond);
+            return;
+        }
+    }
+    match effect {
+        // ---- DAMAGE ----
+        Effect::Damage { amount: Some(dmg), repeat, target: Some(target), .. } => {
+            let times = repeat.unwrap_or(1);
+            for _ in 0..times {
+                match target {
+                    Target::AnyCharacter => {
+                        let mut valid = Vec::new();
+                        for (pid, player) in state.players.iter() {
+                            valid.push((pid.clone(), "hero".to_string(), None));
+                            for (i, _) in player.zones.board.iter().enumerate().filter(|(_, m)| !m.is_location() && can_be_targeted(&state.history, m)) {
+                                valid.push((pid.clone(), "minion".to_string(), Some(i)));
+                            }
+                        }
+                        let candidates: Vec<CharacterRef> = valid.iter().map(|(pid, _, i)| (*pid, *i)).collect();
+                        if let Some(k) = pick_character(state, &candidates, chooser) {
+                            let (tgt_pid, tgt_type, opt_idx) = &valid[k];
+                            let tgt_player = state.players.get_mut(tgt_pid).unwrap();
+                            if tgt_type == "hero" {
+                                state.history.note_target(Character::Hero(*tgt_pid));
+                                tgt_player.take_damage(*dmg);
+                                println!("[DAMAGE] Damage {} to hero {:?}", dmg, tgt_pid);
+                                state.event_queue.push_back(GameEvent::HeroDamaged { player: *tgt_pid, amount: *dmg, dealer: *player_id });
+                            } else if let Some(idx) = opt_idx {
+                                if let Some(minion) = tgt_player.zones.board.get_mut(*idx) {
+                                    minion.status.current_health = Some(minion.status.current_health.unwrap_or(0) - *dmg);
+                                    log_minion_hit(&mut state.history, *player_id, *tgt_pid, minion, *dmg, true);
+                                    println!("[DAMAGE] Damage {} to minion {}", dmg, minion.name);
+                                }
+                            }
+                        }
+                    }
+
+                    Target::AnyMinion => {
+                        let mut valid = Vec::new();
+                        for (pid, player) in state.players.iter() {
+                            for (i, _) in player.zones.board.iter().enumerate().filter(|(_, m)| !m.is_location() && can_be_targeted(&state.history, m)) {
+                                valid.push((pid.clone(), i));
+                            }
+                        }
+                        let candidates: Vec<CharacterRef> = valid.iter().map(|(pid, i)| (*pid, Some(*i))).collect();
+                        if let Some(k) = pick_character(state, &candidates, chooser) {
+                            let (tgt_pid, idx) = &valid[k];
+                            let tgt_player = state.players.get_mut(tgt_pid).unwrap();
+                            if let Some(minion) = tgt_player.zones.board.get_mut(*idx) {
+                                minion.status.current_health = Some(minion.status.current_health.unwrap_or(0) - *dmg);
+                                log_minion_hit(&mut state.history, *player_id, *tgt_pid, minion, *dmg, true);
+                                println!("[DAMAGE] Damage {} to minion {}", dmg, minion.name);
+                            }
+                        }
+                    }
+
+                    Target::EnemyCharacter => {
+                        let opponent_id = player_id.opponent();
+                        let opponent = state.players.get(&opponent_id).unwrap();
+                        let mut valid = Vec::new();
+                        valid.push(("hero", None));
+                        for (i, _) in opponent.zones.board.iter().enumerate().filter(|(_, m)| !m.is_location() && can_be_targeted(&state.history, m)) {
+                            valid.push(("minion", Some(i)));
+                        }
+                        let candidates: Vec<CharacterRef> = valid.iter().map(|(_, i)| (opponent_id, *i)).collect();
+                        if let Some(k) = pick_character(state, &candidates, chooser) {
+                            let (kind, opt_idx) = &valid[k];
+                            let opponent = state.players.get_mut(&opponent_id).unwrap();
+                            if *kind == "hero" {
+                                state.history.note_target(Character::Hero(opponent_id));
+                                opponent.take_damage(*dmg);
+                                println!("[DAMAGE] Damage {} to ENEMY hero", dmg);
+                                state.event_queue.push_back(GameEvent::HeroDamaged { player: opponent_id, amount: *dmg, dealer: *player_id });
+                            } else if let Some(idx) = opt_idx {
+                                if let Some(minion) = opponent.zones.board.get_mut(*idx) {
+                                    minion.status.current_health = Some(minion.status.current_health.unwrap_or(0) - *dmg);
+                                    log_minion_hit(&mut state.history, *player_id, opponent_id, minion, *dmg, true);
+                                    println!("[DAMAGE] Damage {} to ENEMY minion {}", dmg, minion.name);
+                                }
+                            }
+                        }
+                    }
+
+                    Target::FriendlyCharacter => {
+                        let player = state.players.get(player_id).unwrap();
+                        let mut valid = Vec::new();
+                        valid.push(("hero", None));
+                        for (i, _) in player.zones.board.iter().enumerate().filter(|(_, m)| !m.is_location() && can_be_targeted(&state.history, m)) {
+                            valid.push(("minion", Some(i)));
+                        }
+                        let candidates: Vec<CharacterRef> = valid.iter().map(|(_, i)| (*player_id, *i)).collect();
+                        if let Some(k) = pick_character(state, &candidates, chooser) {
+                            let (kind, opt_idx) = &valid[k];
+                            let player = state.players.get_mut(player_id).unwrap();
+                            if *kind == "hero" {
+                                state.history.note_target(Character::Hero(*player_id));
+                                player.take_damage(*dmg);
+                                println!("[DAMAGE] Damage {} to FRIENDLY hero", dmg);
+                                state.event_queue.push_back(GameEvent::HeroDamaged { player: *player_id, amount: *dmg, dealer: *player_id });
+                            } else if let Some(idx) = opt_idx {
+                                if let Some(minion) = player.zones.board.get_mut(*idx) {
+                                    minion.status.current_health = Some(minion.status.current_health.unwrap_or(0) - *dmg);
+                                    log_minion_hit(&mut state.history, *player_id, *player_id, minion, *dmg, true);
+                                    println!("[DAMAGE] Damage {} to FRIENDLY minion {}", dmg, minion.name);
+                                }
+                            }
+                        }
+                    }
+
+                    Target::AllEnemyCharacter => {
+                        let opponent_id = player_id.opponent();
+                        if let Some(opponent) = state.players.get_mut(&opponent_id) {
+                            // Minions adverses
+                            for minion in opponent.zones.board.iter_mut().filter(|m| !m.is_location()) {
+                                minion.status.current_health = Some(minion.status.current_health.unwrap_or(0) - *dmg);
+                                log_minion_hit(&mut state.history, *player_id, opponent_id, minion, *dmg, false);
+                                println!("[DAMAGE] Damage {} to ENEMY minion {}", dmg, minion.name);
+                            }
+                            // Héros adverse
+                            opponent.take_damage(*dmg);
+                            println!("[DAMAGE] Damage {} to ENEMY hero", dmg);
+                        }
+                        state.event_queue.push_back(GameEvent::HeroDamaged { player: opponent_id, amount: *dmg, dealer: *player_id });
+                    }
+
+                    Target::FriendlyMinion => {
+                        let player = state.players.get(player_id).unwrap();
+                        let valid: Vec<_> = player.zones.board.iter().enumerate().filter(|(_, m)| !m.is_location() && can_be_targeted(&state.history, m)).map(|(i, _)| i).collect();
+                        let candidates: Vec<CharacterRef> = valid.iter().map(|i| (*player_id, Some(*i))).collect();
+                        if let Some(k) = pick_character(state, &candidates, chooser) {
+                            let idx = valid[k];
+                            let player = state.players.get_mut(player_id).unwrap();
+                            if let Some(minion) = player.zones.board.get_mut(idx) {
+                                minion.status.current_health = Some(minion.status.current_health.unwrap_or(0) - *dmg);
+                                log_minion_hit(&mut state.history, *player_id, *player_id, minion, *dmg, true);
+                                println!("[DAMAGE] Damage {} to FRIENDLY minion {}", dmg, minion.name);
+                            }
+                        }
+                    }
+
+                    Target::EnemyMinion => {
+                        let opponent_id = player_id.opponent();
+                        let opponent = state.players.get(&opponent_id).unwrap();
+                        let valid: Vec<_> = opponent.zones.board.iter().enumerate().filter(|(_, m)| !m.is_location() && can_be_targeted(&state.history, m)).map(|(i, _)| i).collect();
+
+                        if valid.is_empty() {
+                            println!("[DAMAGE] Pas de minion ennemi valide pour cibler");
+                            return;
+                        }
+
+                        let candidates: Vec<CharacterRef> = valid.iter().map(|i| (opponent_id, Some(*i))).collect();
+
+                        if let Some(k) = pick_character(state, &candidates, chooser) {
+                            let idx = valid[k];
+                            let opponent = state.players.get_mut(&opponent_id).unwrap();
+                            if let Some(minion) = opponent.zones.board.get_mut(idx) {
+                                minion.status.current_health = Some(minion.status.current_health.unwrap_or(0) - *dmg);
+                                log_minion_hit(&mut state.history, *player_id, opponent_id, minion, *dmg, true);
+                                println!("[DAMAGE] Damage {} to ENEMY minion {}", dmg, minion.name);
+                            }
+                        }
+                    }
+
+
+                    Target::EnemyHero | Target::FriendlyHero => {
+                        let hero_id = if *target == Target::EnemyHero { player_id.opponent() } else { *player_id };
+                        state.players.get_mut(&hero_id).unwrap().take_damage(*dmg);
+                        println!("[DAMAGE] Damage {} to hero {:?}", dmg, hero_id);
+                        state.event_queue.push_back(GameEvent::HeroDamaged { player: hero_id, amount: *dmg, dealer: *player_id });
+                    }
+
+                    _ => {
+                        // Garde ta logique précédente pour les autres cibles (EnemyHero, AllEnemyMinion, etc)
+                    }
+                }
+                // === SUPPRESSION GÉNÉRIQUE DES MORTS APRÈS CHAQUE DÉGÂT ===
+                remove_dead_minions(state);
+            }
+        }
+
+        // ---- HEAL ----
+        Effect::Heal { amount: Some(heal), target: Some(target), .. } => {
+            match target {
+                Target::FriendlyHero => {
+                    if let Some(player) = state.players.get_mut(player_id) {
+                        player.heal(*heal);
+                        println!("[HEAL] {} soigne son héros de {} PV (nouveaux PV : {})", player_id.id_string(), heal, player.stats.health);
+                    }
+                }
+                Target::FriendlyCharacter => {
+                    let player = state.players.get(player_id).unwrap();
+                    let mut valid = Vec::new();
+                    valid.push(("hero", None));
+                    for (i, _) in player.zones.board.iter().enumerate().filter(|(_, m)| !m.is_location() && can_be_targeted(&state.history, m)) {
+                        valid.push(("minion", Some(i)));
+                    }
+                    let candidates: Vec<CharacterRef> = valid.iter().map(|(_, i)| (*player_id, *i)).collect();
+                    if let Some(k) = pick_character(state, &candidates, chooser) {
+                        let (kind, opt_idx) = &valid[k];
+                        let player = state.players.get_mut(player_id).unwrap();
+                        if *kind == "hero" {
+                            player.heal(*heal);
+                            println!("[HEAL] {} soigne son héros de {} PV (nouveaux PV : {})", player_id.id_string(), heal, player.stats.health);
+                        } else if let Some(idx) = opt_idx {
+                            if let Some(minion) = player.zones.board.get_mut(*idx) {
+                                minion.status.current_health = Some(minion.status.current_health.unwrap_or(0) + *heal);
+                                println!("[HEAL] {} soigne {} de {} PV (nouveaux PV : {})", player_id.id_string(), minion.name, heal, minion.status.current_health.unwrap_or(0));
+                            }
+                        }
+                    }
+                }
+                Target::AnyCharacter => {
+                    let mut valid = Vec::new();
+                    for (pid, player) in state.players.iter() {
+                        valid.push((pid.clone(), "hero".to_string(), None));
+                        for (i, _) in player.zones.board.iter().enumerate().filter(|(_, m)| !m.is_location() && can_be_targeted(&state.history, m)) {
+                            valid.push((pid.clone(), "minion".to_string(), Some(i)));
+                        }
+                    }
+                    let candidates: Vec<CharacterRef> = valid.iter().map(|(pid, _, i)| (*pid, *i)).collect();
+                    if let Some(k) = pick_character(state, &candidates, chooser) {
+                        let (tgt_pid, tgt_type, opt_idx) = &valid[k];
+                        let tgt_player = state.players.get_mut(tgt_pid).unwrap();
+                        if tgt_type == "hero" {
+                            tgt_player.heal(*heal);
+                            println!("[HEAL] Heal {} to hero {:?}", heal, tgt_pid);
+                        } else if let Some(idx) = opt_idx {
+                            if let Some(minion) = tgt_player.zones.board.get_mut(*idx) {
+                                minion.status.current_health = Some(minion.status.current_health.unwrap_or(0) + *heal);
+                                println!("[HEAL] Heal {} to minion {}", heal, minion.name);
+                            }
+                        }
+                    }
+                }
+                _ => println!("[HEAL] Target '{:?}' non encore géré pour Heal", target),
+            }
+        }
+
+        // ---- DRAW ----
+        Effect::Draw { amount, filter, target, .. } => {
+            let amt = amount.unwrap_or(1);
+            if let Some(f) = filter {
+                println!("[DRAW] {} va piocher {} carte(s) filtrée(s) ({:?})", player_id.id_string(), amt, f);
+                draw_n_with_filter(state, player_id, amt, &f.clone().for_player(state, player_id));
+            } else if let Some(tgt) = target {
+                match tgt {
+                    Target::FriendlyHero => {
+                        println!("[DRAW] {} va piocher {} carte(s)", player_id.id_string(), amt);
+                        draw_n(state, player_id, amt);
+                    }
+                    Target::EnemyHero => {
+                        let opp_id = player_id.opponent();
+                        println!("[DRAW] {} va piocher {} carte(s)", opp_id.id_string(), amt);
+                        draw_n(state, &opp_id, amt);
+                    }
+                    _ => println!("[DRAW] Target '{:?}' non géré dans Draw", tgt),
+                }
+            } else {
+                println!("[DRAW] {} va piocher {} carte(s)", player_id.id_string(), amt);
+                draw_n(state, player_id, amt);
+            }
+        }
+
+        // ---- BUFF ----
+        Effect::Buff {
+    attack,
+    health,
+    amount,
+    random: _,
+    duration,
+    filter,
+    target,
+    condition,
+    trigger: _,
+} => {
+    let filter = filter.clone().map(|f| f.for_player(state, owner_id));
+    // Critères de cible de la condition (Grommash : « s'il est blessé »…)
+    let allowed = |m: &Card| condition.as_ref().is_none_or(|c| c.allows_target(m));
+    let selected = |m: &Card| !m.is_location() && filter.as_ref().is_none_or(|f| f.matches(m)) && allowed(m);
+    // ΔATK et ΔPV (fallback simple : "amount" = bonus d'ATK si "attack" absent)
+    let add_atk = (*attack).or(*amount).unwrap_or(0);
+    let add_hp  = (*health).unwrap_or(0);
+
+    println!(
+        "[BUFF] owner={:?} target={:?} atk:+{} hp:+{} amount={:?}",
+        owner_id, target, add_atk, add_hp, amount
+    );
+
+    // petit helper local pour appliquer le buff à un serviteur
+    fn apply_buff_to(minion: &mut Card, add_atk: i32, add_hp: i32) {
+        if add_atk != 0 {
+            minion.status.attack_modifiers += add_atk;
+            let before = crate::game::engine::utils::minion_stats_string(minion);
+
+            // ATTAQUE
+            minion.status.attack_modifiers += add_atk;
+
+            // VIE (courante + max si présent)
+            if add_hp != 0 {
+                if let Some(h) = minion.status.current_health {
+                    minion.status.current_health = Some(h + add_hp);
+                }
+                if let Some(mh) = minion.max_health {
+                    minion.max_health = Some(mh + add_hp);
+                }
+            }
+
+            let after = crate::game::engine::utils::minion_stats_string(minion);
+            println!(
+                "[BUFF→APPLIED] {}  ==>  {}   ({}; +{}/+{})",
+                before, after, minion.name, add_atk, add_hp
+            );
+
+        }
+        if add_hp != 0 {
+            // met à jour max, base et PV courants de façon cohérente
+            let prev_max = minion.max_health.unwrap_or(minion.health.unwrap_or(0));
+            minion.max_health = Some(prev_max + add_hp);
+
+            let prev_base = minion.health.unwrap_or(prev_max);
+            minion.health = Some(prev_base + add_hp);
+
+            let prev_cur = minion.status.current_health.unwrap_or(prev_base);
+            minion.status.current_health = Some(prev_cur + add_hp);
+        }
+    }
+
+    match target {
+        // Tous les serviteurs alliés
+        Some(Target::AllFriendlyMinion) => {
+            let p = state.players.get_mut(owner_id).unwrap();
+            for m in p.zones.board.iter_mut().filter(|m| selected(m)) {
+                apply_buff_to(m, add_atk, add_hp);
+            }
+        }
+        // Tous les serviteurs (alliés + ennemis)
+        Some(Target::AllMinion) => {
+            for pid in [*owner_id, owner_id.opponent()] {
+                let p = state.players.get_mut(&pid).unwrap();
+                for m in p.zones.board.iter_mut().filter(|m| selected(m)) {
+                    apply_buff_to(m, add_atk, add_hp);
+                }
+            }
+        }
+        // Héros allié : « this_turn » est remis à 0 en fin de tour, sinon l'attaque reste
+        Some(Target::FriendlyHero) => {
+            let p = state.players.get_mut(owner_id).unwrap();
+            if duration.as_deref() == Some("this_turn") {
+                p.stats.hero_attack += add_atk;
+                println!("[BUFF→HERO] {} gagne +{} ATK ce tour (total {})", p.id_string(), add_atk, p.stats.hero_attack);
+            } else {
+                p.stats.hero_base_attack += add_atk;
+                println!("[BUFF→HERO] {} gagne +{} ATK (total {})", p.id_string(), add_atk, p.stats.hero_base_attack);
+            }
+        }
+        // Le serviteur source (« self »)
+        Some(Target::SelfTarget) => {
+            let p = state.players.get_mut(owner_id).unwrap();
+            if let Some(i) = p.source_minion_index().filter(|&i| allowed(&p.zones.board[i])) {
+                apply_buff_to(&mut p.zones.board[i], add_atk, add_hp);
+            }
+        }
+        // Les voisins du serviteur source
+        Some(Target::AdjacentFriendlyMinion) => {
+            let p = state.players.get_mut(owner_id).unwrap();
+            let neighbours = p.source_minion_index().map(|i| p.adjacent_minions(i)).unwrap_or_default();
+            for i in neighbours {
+                if allowed(&p.zones.board[i]) {
+                    apply_buff_to(&mut p.zones.board[i], add_atk, add_hp);
+                }
+            }
+        }
+        // Un serviteur allié (simplifié : on prend le premier)
+        Some(Target::FriendlyMinion) => {
+            let p = state.players.get_mut(owner_id).unwrap();
+            if let Some(m) = p.zones.board.iter_mut().find(|m| !m.is_location() && allowed(m)) {
+                apply_buff_to(m, add_atk, add_hp);
+            }
+        }
+        // Par défaut : pas encore géré
+        other => {
+            println!("[BUFF] cible {:?} non gérée pour l’instant (ΔATK={}, ΔPV={})", other, add_atk, add_hp);
+        }
+    }
+}
+
+        // ---- SUMMON ----
+
+        Effect::Summon {
+    amount,
+    card_id,
+    filter,
+    source,
+    destination,
+    random,
+    trigger: _,
+} => {
+    let how_many = amount.unwrap_or(1);
+    // Par défaut, on cible le board du joueur courant, sauf si destination explicite
+    let dest_player_id = match destination.as_deref() {
+        Some("opponent_board") => player_id.opponent(),
+        // Ajoute d'autres cas spécifiques si besoin
+        _ => *player_id,
+    };
+
+    // Cas 1 : Summon d'un ou plusieurs minions par card_id (token classique, Bear, Nerubian, etc)
+    if let Some(cid) = card_id {
+        for _ in 0..how_many {
+            // À adapter selon ta fonction de création de carte/token !
+            let template = card_templates.get(cid.as_str()).unwrap();
+
+            let mut new_minion = template.to_card();
+
+            // À droite de la source (ou à la place du serviteur mort pour un Râle d'agonie)
+            match summon(state, &dest_player_id, new_minion) {
+                Some(pos) => println!("[SUMMON] {} invoque {} en position {}", dest_player_id.id_string(), cid, pos),
+                None => break, // board plein : les suivantes échoueraient aussi
+            }
+        }
+        return;
+    }
+
+    // Cas 2 : Summon depuis une main/zone (Dirty Rat…)
+    if let (Some(src), Some(dest), Some(is_random)) = (source.as_ref(), destination.as_ref(), random) {
+        if src == "hand" && dest == "opponent_board" && *is_random {
+            let opponent_id = player_id.opponent();
+            let opponent = &state.players[&opponent_id];
+            if !opponent.zones.hand.is_empty() {
+                use rand::seq::SliceRandom;
+                let mut rng = rand::thread_rng();
+                let idx = (0..opponent.zones.hand.len()).collect::<Vec<_>>()
+                    .choose(&mut rng).copied().unwrap_or(0);
+                let name = opponent.zones.hand[idx].name.clone();
+                // board adverse plein : la carte reste en main
+                if move_card(state, &opponent_id, Zone::Hand, idx, Zone::Board, None).is_ok() {
+                    println!("[SUMMON] Dirty Rat sort {} de la main adverse et le pose sur le board !", name);
+                }
+            }
+            return;
+        }
+        // Ajoute d'autres cas si besoin (main du joueur courant, etc)
+    }
+
+    // Cas 3 : Summon via filter (Sneed’s, Maze Guide…) : serviteur aléatoire satisfaisant le filtre
+    if let Some(filt) = filter {
+        let mut filt = filt.clone().for_player(state, player_id);
+        filt.card_type.get_or_insert(CardType::Minion);
+        let mut pool: Vec<_> = card_templates.values().filter(|tpl| filt.matches(*tpl)).collect();
+        // ordre stable : le hasard ne vient que du tirage
+        pool.sort_by(|a, b| a.card_id.cmp(&b.card_id));
+
+        use rand::seq::SliceRandom;
+        let mut rng = rand::thread_rng();
+        for _ in 0..how_many {
+            let Some(template) = pool.choose(&mut rng) else {
+                println!("[SUMMON] Aucun serviteur ne correspond au filtre {:?}", filt);
+                break;
+            };
+            println!(
+                "[SUMMON] {} invoque {} ({})",
+                dest_player_id.id_string(),
+                template.card_name,
+                template.card_id
+            );
+            if summon(state, &dest_player_id, template.to_card()).is_none() {
+                break;
+            }
+        }
+        return;
+    }
+
+    // Cas 4 : Summon une copie de soi-même (self_copy)
+    if let Some(target) = destination.as_ref().or(card_id.as_ref()) {
+        if target == "self_copy" {
+            // Cherche le minion sur le board du joueur courant
+            let player = &state.players[player_id];
+            if let Some(myself) = player.source_minion_index().map(|i| player.zones.board[i].clone()) {
+                for _ in 0..how_many {
+                    let mut copy = myself.clone();
+                    // Reset les status nécessaires
+                    copy.status.current_health = copy.max_health;
+                    if summon(state, player_id, copy).is_none() {
+                        break;
+                    }
+                }
+                println!("[SUMMON] {} se clone ({})", myself.name, how_many);
+            }
+            return;
+        }
+    }
+
+    // TODO: gérer d’autres variantes exotiques si besoin.
+}
+
+
+        // ---- ÉQUIPER UNE ARME ----
+        Effect::EquipWeapon { card_id: Some(id), .. } => match card_templates.get(id) {
+            Some(tpl) => {
+                let weapon = tpl.to_card();
+                crate::game::engine::weapon::equip_weapon(state, player_id, weapon, chooser, card_templates);
+            }
+            None => println!("⚠️ Arme {} introuvable dans les templates", id),
+        },
+
+        // ---- DÉCOUVERTE ----
+        Effect::Discover { amount, source_pool, destination, mystery, .. } => {
+            for _ in 0..amount.unwrap_or(1).max(1) {
+                discover(
+                    state,
+                    player_id,
+                    source_pool.as_ref(),
+                    destination.as_deref(),
+                    mystery.unwrap_or(false),
+                    chooser,
+                    card_templates,
+                );
+            }
+        }
+
+        // ---- CHOIX DES MYSTIQUES ----
+        Effect::ChooseOne { options, amount } => {
+            let amount = amount.unwrap_or(1).max(1) as usize;
+            choose_one(state, player_id, options, amount, chooser, card_templates);
+        }
+
+        // ---- ENCHANTEMENTS ----
+        // « self » = serviteur dont les effets se résolvent (à défaut, le dernier posé)
+        // Mot-clé du héros (Windfury, Immune pendant ses attaques) : jusqu'à la fin du tour
+        Effect::GrantMechanic { mechanic, target: Some(Target::FriendlyHero), .. } => {
+            let p = state.players.get_mut(player_id).unwrap();
+            p.stats.hero_keywords |= crate::game::keywords::Keywords::from_mechanics(std::slice::from_ref(mechanic));
+            println!("{} gagne {} ce tour", p.id_string(), mechanic);
+        }
+        Effect::GrantMechanic { mechanic, target, random, .. } => {
+            let enchantment = Enchantment {
+                source: source_card_id(state, player_id),
+                keywords: crate::game::keywords::Keywords::from_mechanics(std::slice::from_ref(mechanic)),
+                triggers: Vec::new(),
+            };
+            let target = target.clone().unwrap_or(Target::SelfTarget);
+            enchant_targets(state, player_id, &target, random.unwrap_or(false), enchantment, chooser);
+        }
+        Effect::GrantDeathrattle { effect: granted, target, random, .. } => {
+            let enchantment = Enchantment {
+                source: source_card_id(state, player_id),
+                keywords: crate::game::keywords::Keywords::empty(),
+                triggers: vec![TriggerDef { when: Trigger::Deathrattle, effect: Effect::from_value(granted) }],
+            };
+            let target = target.clone().unwrap_or(Target::SelfTarget);
+            enchant_targets(state, player_id, &target, random.unwrap_or(false), enchantment, chooser);
+        }
+        Effect::Silence { target } => {
+            silence_targets(state, player_id, target.as_ref().unwrap_or(&Target::AnyMinion), chooser);
+        }
+
+        // ---- CADAVRES ----
+        Effect::GainCorpse { amount } => {
+            state.players.get_mut(player_id).unwrap().gain_corpses(amount.unwrap_or(1).max(0) as u32);
+        }
+        Effect::SpendCorpse { amount, up_to, then, .. } => {
+            let amount = amount.unwrap_or(1).max(0) as u32;
+            let player = state.players.get_mut(player_id).unwrap();
+            let spendable = if up_to.unwrap_or(false) { amount.min(player.corpses) } else { amount };
+            // « si vous pouvez » : sans assez de Cadavres, rien ne se passe
+            if player.corpses < spendable {
+                println!("{} n'a pas assez de Cadavres ({}/{})", player.id_string(), player.corpses, amount);
+                return;
+            }
+            player.spend_corpses(spendable);
+            for eff in then {
+                apply_effect(state, player_id, eff, chooser, card_templates);
+            }
+        }
+
+        // ---- OVERLOAD ----
+        Effect::Overload { amount } => {
+            let player = state.players.get_mut(player_id).unwrap();
+            player.add_overload(amount.unwrap_or(0).max(0) as u8);
+        }
+        Effect::UnlockOverload => {
+            state.players.get_mut(player_id).unwrap().unlock_overload();
+        }
+
+        // ---- MANA ----
+        Effect::GainMana { amount, temporary } => {
+            let amt = amount.unwrap_or(1).max(0) as u8;
+            let player = state.players.get_mut(player_id).unwrap();
+            if temporary.unwrap_or(false) {
+                let gained = player.stats.mana.gain_temporary(amt);
+                println!("[MANA] {} gagne {} mana ce tour-ci ({}/{})", player_id.id_string(), gained, player.stats.mana.current, player.stats.mana.max);
+            } else {
+                let gained = player.stats.mana.gain_crystals(amt, false);
+                println!("[MANA] {} gagne {} cristal(aux) plein(s) ({}/{})", player_id.id_string(), gained, player.stats.mana.current, player.stats.mana.max);
+            }
+        }
+        Effect::GainManaCrystal { amount, empty, target } => {
+            let pid = mana_target(player_id, target.as_ref());
+            let player = state.players.get_mut(&pid).unwrap();
+            let gained = player.stats.mana.gain_crystals(amount.unwrap_or(1).max(0) as u8, empty.unwrap_or(false));
+            println!("[MANA] {} gagne {} cristal(aux) ({}/{})", pid.id_string(), gained, player.stats.mana.current, player.stats.mana.max);
+        }
+        Effect::DestroyManaCrystal { amount, target } => {
+            let pid = mana_target(player_id, target.as_ref());
+            let player = state.players.get_mut(&pid).unwrap();
+            let destroyed = player.stats.mana.destroy_crystals(amount.unwrap_or(1).max(0) as u8);
+            println!("[MANA] {} perd {} cristal(aux) ({}/{})", pid.id_string(), destroyed, player.stats.mana.current, player.stats.mana.max);
+        }
+        Effect::EmptyManaCrystal { amount, target } => {
+            let pid = mana_target(player_id, target.as_ref());
+            let player = state.players.get_mut(&pid).unwrap();
+            let emptied = player.stats.mana.empty_crystals(amount.unwrap_or(1).max(0) as u8);
+            println!("[MANA] {} voit {} cristal(aux) vidé(s) ({}/{})", pid.id_string(), emptied, player.stats.mana.current, player.stats.mana.max);
+        }
+
+        // ---- MODIFY COST ----
+        Effect::ModifyCost { .. } => {
+            let source = source_card_id(state, player_id).unwrap_or_default();
+            apply_modify_cost(state, player_id, effect, &source);
+        }
+
+        // ---- RÉSURRECTION ----
+        Effect::Resurrect { amount, filter, highest_cost, .. } => {
+            let amount = amount.unwrap_or(1).max(0) as usize;
+            resurrect(state, player_id, amount, filter.as_ref(), highest_cost.unwrap_or(false), card_templates);
+        }
+
+        // ---- DESTRUCTION ----
+        Effect::Destroy { target: Some(target), condition, random, .. } => match target {
+            Target::OpponentWeapon => {
+                destroy_weapon(state, &player_id.opponent(), chooser, card_templates);
+            }
+            Target::EnemyLocation | Target::FriendlyLocation | Target::AnyLocation => {
+                let locations = location_targets(state, player_id, target);
+                if let Some((pid, i)) = pick_target(state, &locations, random.unwrap_or(false), chooser) {
+                    let location = &mut state.players.get_mut(&pid).unwrap().zones.board[i];
+                    println!("[DESTROY] Le lieu {} est détruit", location.name);
+                    location.status.current_health = Some(0);
+                    remove_dead_minions(state);
+                }
+            }
+            // Steamcleaner : les deux decks
+            Target::DeckCardsNotStartingInDeck => {
+                for pid in [*player_id, player_id.opponent()] {
+                    destroy_not_starting_in_deck(state, &pid);
+                }
+            }
+            _ => {
+                let hits = resolve_minion_targets(state, player_id, target, condition.as_ref(), random.unwrap_or(false), chooser);
+                if hits.is_empty() {
+                    println!("[DESTROY] Aucune cible valide ({:?})", target);
+                }
+                for (pid, i) in hits {
+                    let minion = &mut state.players.get_mut(&pid).unwrap().zones.board[i];
+                    println!("[DESTROY] {} est détruit", minion.name);
+                    minion.status.current_health = Some(0);
+                }
+                remove_dead_minions(state);
+            }
+        },
+
+        // ---- PV FIXÉS ----
+        Effect::SetHealth { value: Some(value), target: Some(target), .. } => {
+            for (pid, i) in resolve_minion_targets(state, player_id, target, None, false, chooser) {
+                let minion = &mut state.players.get_mut(&pid).unwrap().zones.board[i];
+                minion.health = Some(*value);
+                minion.max_health = Some(*value);
+                minion.status.current_health = Some(*value);
+                println!("[SET_HEALTH] {} passe à {} PV", minion.name, value);
+            }
+            remove_dead_minions(state);
+        }
+
+        // ---- ÉCHANGE ATTAQUE / PV ----
+        Effect::SwapStats { target: Some(target), .. } => {
+            for (pid, i) in resolve_minion_targets(state, player_id, target, None, false, chooser) {
+                let minion = &mut state.players.get_mut(&pid).unwrap().zones.board[i];
+                let (atk, hp) = (minion.effective_attack(), minion.effective_health());
+                // l'aura d'attaque continue de s'ajouter : la base compense
+                minion.attack = Some(hp - minion.status.aura_attack);
+                minion.status.attack_modifiers = 0;
+                minion.health = Some(atk);
+                minion.max_health = Some(atk);
+                minion.status.current_health = Some(atk);
+                println!("[SWAP_STATS] {} passe de {}/{} à {}/{}", minion.name, atk, hp, hp, atk);
+            }
+            remove_dead_minions(state);
+        }
+
+        // ---- RETOUR EN MAIN ----
+        // Depuis le cimetière (Krag'wa) : cartes du lanceur satisfaisant le filtre
+        Effect::ReturnToHand { zone: Some(zone), filter, last_turn, .. } if zone == "graveyard" => {
+            let turn = match last_turn {
+                Some(true) => match state.history.last_turn_of(player_id) {
+                    Some(t) => Some(t),
+                    None => {
+                        println!("[RETURN] {} n'a pas encore joué de tour précédent", player_id.id_string());
+                        return;
+                    }
+                },
+                _ => None,
+            };
+            let filter = filter.clone().unwrap_or_default();
+            let returned: Vec<Card> = match turn {
+                // chaque carte jouée ce tour-là, retrouvée au cimetière (un exemplaire par partie jouée)
+                Some(t) => {
+                    let filter = filter.for_player(state, player_id);
+                    let graveyard = &state.players[player_id].zones.graveyard;
+                    let mut used = Vec::new();
+                    for record in state.history.cards_played_by(player_id, Some(t)) {
+                        if let Some(i) = (0..graveyard.len()).rev().find(|i| !used.contains(i) && graveyard[*i].card_id == record.card_id) {
+                            used.push(i);
+                        }
+                    }
+                    used.sort_unstable();
+                    used.into_iter()
+                        .filter(|&i| filter.matches(&graveyard[i]))
+                        .map(|i| fresh_copy(&graveyard[i], card_templates))
+                        .collect()
+                }
+                None => graveyard_query(state, player_id, &filter)
+                    .into_iter()
+                    .map(|c| fresh_copy(c, card_templates))
+                    .collect(),
+            };
+            for card in returned {
+                println!("[RETURN] {} reprend {} en main", player_id.id_string(), card.name);
+                add_to_hand(state, player_id, card);
+            }
+        }
+        Effect::ReturnToHand { target: Some(target), .. } => {
+            let dead = state.players[player_id].deathrattle_source;
+            match (target, dead) {
+                // Râle d'agonie « self » : la carte morte revient depuis le cimetière
+                (Target::SelfTarget, Some(i)) => {
+                    let card = fresh_copy(&state.players[player_id].zones.graveyard[i], card_templates);
+                    println!("[RETURN] {} revient dans la main de {}", card.name, player_id.id_string());
+                    add_to_hand(state, player_id, card);
+                }
+                _ => {
+                    let mut hits = resolve_minion_targets(state, player_id, target, None, false, chooser);
+                    // de droite à gauche : les index restants restent valides
+                    hits.sort_by_key(|&(_, i)| std::cmp::Reverse(i));
+                    for (pid, i) in hits {
+                        bounce(state, &pid, i, card_templates);
+                    }
+                }
+            }
+        }
+
+        // ---- DÉFAUSSE ----
+        Effect::Discard { amount, random, .. } => {
+            for _ in 0..amount.unwrap_or(1).max(0) {
+                let hand_len = state.players[player_id].zones.hand.len();
+                if hand_len == 0 {
+                    break;
+                }
+                let index = if random.unwrap_or(false) {
+                    rand::Rng::gen_range(&mut rand::thread_rng(), 0..hand_len)
+                } else {
+                    match chooser.choose(state, Choice::Discard { hand_indices: (0..hand_len).collect() }) {
+                        Choice::DiscardIndex(i) if i < hand_len => i,
+                        _ => break,
+                    }
+                };
+                discard(state, player_id, index);
+            }
+        }
+
+        // ---- ARMURE ----
+        Effect::GainArmor { amount, amount_from, target, .. } => {
+            let armor = match amount_from.as_deref() {
+                // mana payé pour le dernier sort lancé par le joueur (Artisan des arcanes)
+                Some("spell_cost") => state.history.spells_cast_by(player_id, None).last().map_or(0, |r| r.cost as i32),
+                Some(other) => {
+                    println!("[ARMOR] Montant « {} » non géré", other);
+                    return;
+                }
+                None => amount.unwrap_or(0),
+            };
+            let pid = if *target == Some(Target::EnemyHero) { player_id.opponent() } else { *player_id };
+            state.players.get_mut(&pid).unwrap().gain_armor(armor.max(0) as u32);
+        }
+
+        // ---- DECK ----
+        Effect::ShuffleIntoDeck { amount, card_id: Some(id), position, target, .. } => {
+            let Some(tpl) = card_templates.get(id) else {
+                println!("⚠️ Carte {} introuvable dans les templates", id);
+                return;
+            };
+            let pid = match target {
+                Some(Target::EnemyHero) | Some(Target::OtherPlayer) => player_id.opponent(),
+                _ => *player_id,
+            };
+            let position = position.as_deref().map(DeckPosition::parse).unwrap_or_default();
+            for _ in 0..amount.unwrap_or(1).max(0) {
+                shuffle_into_deck(state, &pid, tpl.to_card(), position);
+            }
+        }
+        Effect::RevealTop { target } => {
+            let pid = match target {
+                Some(Target::EnemyHero) | Some(Target::OtherPlayer) => player_id.opponent(),
+                _ => *player_id,
+            };
+            reveal_top(state, &pid);
+        }
+        Effect::SummonFromDeck { amount, filter, .. } => {
+            let amount = amount.unwrap_or(1).max(0) as usize;
+            summon_from_deck(state, player_id, &filter.clone().unwrap_or_default(), amount);
+        }
+
+        // ---- GÉNÉRATION DE CARTES ----
+        Effect::AddCardToHand { amount, card_id, filter, source_zone, zone, modify, .. } => {
+            let amount = amount.unwrap_or(1).max(0) as usize;
+            let receiver = if zone.as_deref() == Some("opponent_hand") { player_id.opponent() } else { *player_id };
+            let cards = match (card_id, source_zone) {
+                (Some(id), _) => match card_templates.get(id) {
+                    Some(tpl) => (0..amount).map(|_| tpl.to_card()).collect(),
+                    None => {
+                        println!("⚠️ Carte {} introuvable dans les templates", id);
+                        Vec::new()
+                    }
+                },
+                (None, Some(src)) => {
+                    copies_from_zone(state, player_id, src, &[], amount, filter.as_ref().unwrap_or(&CardFilter::default()))
+                }
+                (None, None) => random_cards(state, player_id, &filter.clone().unwrap_or_default(), amount, card_templates),
+            };
+            give_cards(state, &receiver, cards, modify.as_ref());
+        }
+        Effect::CopyCardToHand { from_zone, positions, to, card_type, target, amount, modify, .. } => {
+            let amount = amount.unwrap_or(1).max(0) as usize;
+            let (cards, caster) = match from_zone {
+                Some(zone) => {
+                    let filter = CardFilter { card_type: card_type.clone(), ..CardFilter::default() };
+                    (copies_from_zone(state, player_id, zone, positions, amount, &filter), *player_id)
+                }
+                // le sort qui vient d'être lancé, quel que soit son lanceur
+                None => match state.history.spells_cast.last() {
+                    Some(spell) => {
+                        let copy = card_templates.get(&spell.card_id).map(|t| t.to_card());
+                        (copy.into_iter().collect(), spell.player)
+                    }
+                    None => (Vec::new(), *player_id),
+                },
+            };
+            let receiver = if to.as_deref() == Some("opponent_hand") || *target == Some(Target::OtherPlayer) {
+                caster.opponent()
+            } else {
+                *player_id
+            };
+            give_cards(state, &receiver, cards, modify.as_ref());
+        }
+
+        // Capacités statiques : lues par l'attaque (CantAttack, Cleave) et le ciblage (Elusive)
+        Effect::CantAttack | Effect::Cleave | Effect::Elusive => {}
+
+        // ---- ATTAQUES DÉCLENCHÉES ----
+        Effect::FollowUpAttack { .. } => {
+            let source = state.players[player_id].source_minion_index();
+            if let (Some(index), Some(defender)) = (source, state.last_defender.clone()) {
+                forced_attack(state, player_id, index, &defender, chooser);
+            }
+        }
+        Effect::AttackEnemy { target, .. } => {
+            if let Some(index) = state.players[player_id].source_minion_index() {
+                let defender = enemy_defender(state, player_id, *target == Some(Target::LowestHealthEnemy));
+                forced_attack(state, player_id, index, &defender, chooser);
+            }
+        }
+        Effect::RedirectAttack { .. } => redirect_attack(state),
+
+        // ---- REJOUER / RÉPÉTER ----
+        Effect::TriggerDeathrattle { target, random, .. } => {
+            let target = target.clone().unwrap_or(Target::FriendlyMinion);
+            trigger_deathrattles(state, player_id, &target, random.unwrap_or(false), chooser, card_templates);
+        }
+        Effect::ReplayCards { filter, random_targets, .. } => {
+            let filter = filter.clone().unwrap_or_default();
+            replay_cards(state, player_id, &filter, random_targets.unwrap_or(false), chooser, card_templates);
+        }
+        Effect::Repeat { times, times_from, until, effect: repeated } => {
+            let times = match times_from.as_deref() {
+                Some("corpses_spent") => state.players[player_id].corpses_spent_last as i32,
+                Some(other) => {
+                    println!("[REPEAT] Nombre « {} » non géré", other);
+                    return;
+                }
+                None => times.unwrap_or(if until.is_some() { MAX_REPEAT } else { 1 }),
+            };
+            for _ in 0..times.clamp(0, MAX_REPEAT) {
+                if until.as_ref().is_some_and(|c| c.is_met(state, player_id)) {
+                    break;
+                }
+                apply_effect(state, player_id, repeated, chooser, card_templates);
+            }
+        }
+        Effect::TransferAttack { target, random, .. } => {
+            let player = &state.players[player_id];
+            let source = match player.deathrattle_source {
+                Some(i) => player.zones.graveyard.get(i),
+                None => player.source_minion_index().map(|i| &player.zones.board[i]),
+            };
+            let attack = source.map_or(0, |c| c.effective_attack());
+            let target = target.clone().unwrap_or(Target::FriendlyMinion);
+            for (pid, i) in resolve_minion_targets(state, player_id, &target, None, random.unwrap_or(false), chooser) {
+                let minion = &mut state.players.get_mut(&pid).unwrap().zones.board[i];
+                minion.status.attack_modifiers += attack;
+                println!("🗡️ {} reçoit +{} Attaque", minion.name, attack);
+            }
+        }
+
+                // ---- AUTRES ----
+                _ => {
+                    println!("[APPLY] Effet non encore géré : {:?}", effect);
+                }
+            }
+        }
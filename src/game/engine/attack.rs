@@ -1,424 +1,714 @@
-use std::collections::HashMap;
-use crate::game::player::Player;
-
-use crate::{
-    data::card_template::CardTemplate,
-    game::{
-        card::Card,
-        engine::{
-            choose::Chooser,
-            events::dispatch_events,
-            utils::{minion_stats_string, IdString},
-        },
-        event::GameEvent,
-        keywords::Keywords,
-        state::{GameState, PlayerId},
-    },
-};
-
-// ===========================================================================
-// API publique
-// ===========================================================================
-pub fn perform_attack_phase(
-    state: &mut GameState,
-    current: &PlayerId,
-    opponent: &PlayerId,
-    _chooser: &dyn Chooser,
-    _templates: &HashMap<String, CardTemplate>,
-) {
-    // ─────────────────────────────── attaques des serviteurs
-    let mut guard = 0;
-    loop {
-        guard += 1;
-        if guard > 5000 {
-            println!("⚠️ guard ATTACK_PHASE stop après 50 itérations");
-            break;
-        }
-
-        let attacker_id = match find_next_attacker(state, current) {
-            Some(id) => id,
-            None => break,
-        };
-
-        let defender_id = choose_defender(state, opponent);
-
-        // Skip Rush sans cible
-        if rush_cannot_attack(state, current, &attacker_id, defender_id.as_deref()) {
-            continue;
-        }
-
-        match defender_id {
-            Some(def_id) => fight_minion(state, current, opponent, &attacker_id, &def_id),
-            None => attack_hero(state, current, opponent, &attacker_id),
-        }
-    }
-
-    // ─────────────────────────────── arme du héros
-    hero_weapon_attack(state, current, opponent);
-}
-
-// ===========================================================================
-// Sélection attaquant / défenseur
-// ===========================================================================
-fn find_next_attacker(state: &GameState, pid: &PlayerId) -> Option<String> {
-    state.players[pid]
-        .zones
-        .board
-        .iter()
-        .find(|m| {
-            if m.status.attacks_this_turn >= m.max_attacks_per_turn() {
-                false
-            } else if m.status.just_played {
-                m.has_kw(Keywords::CHARGE) || m.has_kw(Keywords::RUSH)
-            } else {
-                true
-            }
-        })
-        .map(|m| m.card_id.clone())
-}
-
-fn choose_defender(state: &GameState, opp_id: &PlayerId) -> Option<String> {
-    let opp_board = &state.players[opp_id].zones.board;
-
-    if let Some(t) = opp_board
-        .iter()
-        .find(|m| m.has_kw(Keywords::TAUNT) && !m.has_kw(Keywords::STEALTH))
-    {
-        return Some(t.card_id.clone());
-    }
-
-    opp_board
-        .iter()
-        .find(|m| !m.has_kw(Keywords::STEALTH))
-        .map(|m| m.card_id.clone())
-}
-
-fn rush_cannot_attack(
-    state: &mut GameState,
-    pid: &PlayerId,
-    attacker_id: &str,
-    defender_opt: Option<&str>,
-) -> bool {
-    let attacker = state.players[pid]
-        .zones
-        .board
-        .iter()
-        .find(|m| m.card_id == attacker_id)
-        .unwrap();
-
-    if attacker.has_kw(Keywords::RUSH)
-        && !attacker.has_kw(Keywords::CHARGE)
-        && attacker.status.just_played
-        && defender_opt.is_none()
-    {
-        state.players.get_mut(pid).unwrap().zones.board
-            .iter_mut()
-            .find(|m| m.card_id == attacker_id)
-            .unwrap()
-            .status
-            .attacks_this_turn += 1;
-        true
-    } else {
-        false
-    }
-}
-
-// ===========================================================================
-// Combat serviteur ↔ serviteur
-// ===========================================================================
-/// combat entre deux serviteurs déjà identifiés
-fn fight_minion(
-    state: &mut GameState,
-    current: &PlayerId,
-    opponent: &PlayerId,
-    attacker_id: &str,
-    defender_id: &str,
-) {
-    // ── indices sur les boards (pas d’emprunt mutable ici)
-    let att_idx = index_of(state, current, attacker_id);
-    let def_idx = index_of(state, opponent, defender_id);
-
-    // ── log
-    println!(
-        "{} attaque {}",
-        minion_stats_string(&state.players[current].zones.board[att_idx]),
-        minion_stats_string(&state.players[opponent].zones.board[def_idx])
-    );
-
-    // ── bloc mutable simultané (unsafe contrôlé)
-    let (att_dead, def_dead, dmg_att, dmg_def, att_ls, def_ls) = {
-        let current_ptr  = state.players.get_mut(current).unwrap()  as *mut Player;
-        let opponent_ptr = state.players.get_mut(opponent).unwrap() as *mut Player;
-        let (cur_mut, opp_mut) = unsafe { (&mut *current_ptr, &mut *opponent_ptr) };
-
-        let attacker = &mut cur_mut.zones.board[att_idx];
-        let defender = &mut opp_mut.zones.board[def_idx];
-
-        // Capture des flags Lifesteal AVANT le combat
-        let att_ls_local = attacker.has_kw(Keywords::LIFESTEAL);
-        let def_ls_local = defender.has_kw(Keywords::LIFESTEAL);
-
-        // Combat
-        let (a_dead, d_dead, dmg_to_def, dmg_to_att) = perform_attack(attacker, defender);
-
-        // Compter l'attaque tant qu'on a encore &mut attacker
-        attacker.status.attacks_this_turn = attacker.status.attacks_this_turn.saturating_add(1);
-        println!("[ATK] {} -> attacks_this_turn={}", attacker.name, attacker.status.attacks_this_turn);
-        (a_dead, d_dead, dmg_to_def, dmg_to_att, att_ls_local, def_ls_local)
-    };
-
-    // Lifesteal (héros) uniquement si présent et si dégâts > 0
-    if att_ls && dmg_att > 0 {
-        state.players.get_mut(current).unwrap().heal(dmg_att);
-    }
-    if def_ls && dmg_def > 0 {
-        state.players.get_mut(opponent).unwrap().heal(dmg_def);
-    }
-
-    // Morts / Reborn / Deathrattles via file d’événements
-    handle_dead(
-        state,
-        current,
-        opponent,
-        att_dead.then_some(attacker_id.to_string()),
-        def_dead.then_some(defender_id.to_string()),
-    );
-
-}
-
-// ===========================================================================
-// Combat serviteur ↦ héros
-// ===========================================================================
-fn attack_hero(
-    state: &mut GameState,
-    current: &PlayerId,
-    opponent: &PlayerId,
-    attacker_id: &str,
-) {
-    let att_idx = index_of(state, current, attacker_id);
-
-    // Modifications sur l'attaquant + dégâts au héros adverse
-    let (dmg, attacker_dead, att_ls) = {
-        use crate::game::player::Player;
-        let current_ptr  = state.players.get_mut(current).unwrap()  as *mut Player;
-        let opponent_ptr = state.players.get_mut(opponent).unwrap() as *mut Player;
-        let (cur_mut, opp_mut) = unsafe { (&mut *current_ptr, &mut *opponent_ptr) };
-
-        let attacker = &mut cur_mut.zones.board[att_idx];
-
-        // Rush sans Charge : pas d'attaque héros le tour où il arrive
-        if attacker.status.just_played
-            && attacker.has_kw(Keywords::RUSH)
-            && !attacker.has_kw(Keywords::CHARGE)
-        {
-            attacker.status.attacks_this_turn =
-                attacker.status.attacks_this_turn.saturating_add(1);
-            return;
-        }
-
-        let att_ls = attacker.has_kw(Keywords::LIFESTEAL);
-        let dmg = attacker.effective_attack();
-
-        opp_mut.take_damage(dmg as i32);
-
-        // Marquer l'attaque et maj de l'état de l'attaquant
-        attacker.status.has_attacked = true;
-        attacker.status.attacks_this_turn =
-            attacker.status.attacks_this_turn.saturating_add(1);
-
-        // (pas de riposte quand on tape le héros)
-        attacker.status.current_health = Some(attacker.effective_health());
-
-        (dmg, attacker.status.current_health.unwrap_or(0) <= 0, att_ls)
-    };
-
-    println!(
-        "{} [{}|{}] attaque le héros adverse ({:?}) pour {}",
-        state.players[current].zones.board[att_idx].name,
-        state.players[current].zones.board[att_idx].effective_attack(),
-        state.players[current].zones.board[att_idx].effective_health(),
-        opponent,
-        dmg
-    );
-
-    // Lifesteal (soigne le héros du contrôleur de l’attaquant)
-    if att_ls && dmg > 0 {
-        state.players.get_mut(current).unwrap().heal(dmg);
-    }
-
-    // Nettoyage / deathrattles via file d’événements
-    handle_dead(
-        state,
-        current,
-        opponent,
-        attacker_dead.then_some(attacker_id.to_string()),
-        None,
-    );
-}
-
-
-// ===========================================================================
-// Héros + arme
-// ===========================================================================
-fn hero_weapon_attack(state: &mut GameState, current: &PlayerId, opponent: &PlayerId) {
-    // infos de base (aucun emprunt mutable)
-    let (can_attack, w_atk, w_dur, target_is_hero) = {
-        let p = &state.players[current];
-        let can = matches!(&p.stats.weapon, Some(w) if !p.hero_has_attacked);
-        let atk = p.stats.weapon.as_ref().map(|w| w.attack.unwrap_or(0)).unwrap_or(0);
-        let dur = p.stats.weapon.as_ref().map(|w| w.health.unwrap_or(0)).unwrap_or(0);
-        let hero = state.players[opponent].zones.board.is_empty();
-        (can, atk, dur, hero)
-    };
-    if !can_attack || w_atk == 0 || w_dur == 0 {
-        return;
-    }
-
-    // ── bloc 1 : dégâts infligés ---------------------------------------
-let retaliation = {
-    let current_ptr = state.players.get_mut(current).unwrap() as *mut Player;
-    let opponent_ptr = state.players.get_mut(opponent).unwrap() as *mut Player;
-    let (cur_mut, opp_mut) = unsafe { (&mut *current_ptr, &mut *opponent_ptr) };
-
-    if target_is_hero {
-        opp_mut.take_damage(w_atk as i32);
-        0
-    } else {
-        // appliquer les dégâts de l'arme au serviteur
-        let def = &mut opp_mut.zones.board[0];
-        def.status.current_health = Some(def.effective_health() - w_atk);
-
-        // riposte calculée AVANT suppression
-        let retaliation = def.effective_attack();
-
-        // retirer si mort
-        if def.status.current_health.unwrap_or(0) <= 0 {
-            opp_mut.zones.board.remove(0);
-        }
-
-        retaliation
-    }
-};
-
-    // ── bloc 2 : riposte + durabilité ---------------------------------
-    if retaliation > 0 {
-        state.players.get_mut(current).unwrap().take_damage(retaliation as i32);
-    }
-
-    let player = state.players.get_mut(current).unwrap();
-    if let Some(w) = &mut player.stats.weapon {
-        w.health = w.health.map(|d| d.saturating_sub(1));
-        if w.health == Some(0) {
-            player.zones.graveyard.push(player.stats.weapon.take().unwrap());
-        }
-    }
-    player.hero_has_attacked = true;
-}
-
-// ===========================================================================
-// Gestion des morts
-// ===========================================================================
-fn handle_dead(
-    state: &mut GameState,
-    current: &PlayerId,
-    opponent: &PlayerId,
-    attacker_dead: Option<String>,
-    defender_dead: Option<String>,
-) {
-    // ─── Attaquant mort ────────────────────────────────────────────────
-    if let Some(id) = attacker_dead {
-        reborn_pass(state, current);                       // Reborn d’abord
-        // 1. retire du board
-        state.players
-            .get_mut(current)
-            .unwrap()
-            .zones
-            .board
-            .retain(|m| m.card_id != id);
-        // 2. pousse l’évènement une fois que la carte n’existe plus
-        state.event_queue.push_back(GameEvent::MinionDied {
-            card_id: id,
-            owner: *current,
-        });
-    }
-
-    // ─── Défenseur mort ────────────────────────────────────────────────
-    if let Some(id) = defender_dead {
-        reborn_pass(state, opponent);
-        state.players
-            .get_mut(opponent)
-            .unwrap()
-            .zones
-            .board
-            .retain(|m| m.card_id != id);
-        state.event_queue.push_back(GameEvent::MinionDied {
-            card_id: id,
-            owner: *opponent,
-        });
-    }
-
-    // dispatch une fois la file remplie
-    dispatch_events(state);
-}
-
-fn reborn_pass(state: &mut GameState, pid: &PlayerId) {
-    for m in &mut state.players.get_mut(pid).unwrap().zones.board {
-        if m.status.current_health.unwrap_or(0) <= 0 && m.has_kw(Keywords::REBORN) {
-            println!("{} revient en vie grâce à Reborn !", m.name);
-            m.remove_kw(Keywords::REBORN);
-            m.status.current_health = Some(1);
-        }
-    }
-}
-
-
-// ===========================================================================
-// Combat élémentaire
-// ===========================================================================
-pub fn perform_attack(attacker: &mut Card, defender: &mut Card) -> (bool, bool, i32, i32) {
-    let att_atk = attacker.effective_attack();
-    let def_atk = defender.effective_attack();
-
-    // Divine Shield
-    let mut dmg_def = att_atk;
-    if defender.has_kw(Keywords::DIVINE_SHIELD) && att_atk > 0 {
-        defender.remove_kw(Keywords::DIVINE_SHIELD);
-        dmg_def = 0;
-    }
-    let mut dmg_att = def_atk;
-    if attacker.has_kw(Keywords::DIVINE_SHIELD) && def_atk > 0 {
-        attacker.remove_kw(Keywords::DIVINE_SHIELD);
-        dmg_att = 0;
-    }
-
-    attacker.status.current_health = Some(attacker.effective_health() - dmg_att);
-    defender.status.current_health = Some(defender.effective_health() - dmg_def);
-
-    // Poisonous
-    if attacker.has_kw(Keywords::POISONOUS) && dmg_def > 0 && !defender.has_kw(Keywords::DIVINE_SHIELD) {
-        defender.status.current_health = Some(0);
-    }
-    if defender.has_kw(Keywords::POISONOUS) && dmg_att > 0 && !attacker.has_kw(Keywords::DIVINE_SHIELD) {
-        attacker.status.current_health = Some(0);
-    }
-
-    attacker.status.has_attacked = true;
-    attacker.remove_kw(Keywords::STEALTH);
-
-    let att_dead = attacker.status.current_health.unwrap_or(0) <= 0;
-    let def_dead = defender.status.current_health.unwrap_or(0) <= 0;
-
-    (att_dead, def_dead, dmg_def.max(0), dmg_att.max(0))
-}
-
-// ===========================================================================
-// Utilitaire d’index
-// ===========================================================================
-fn index_of(state: &GameState, pid: &PlayerId, card_id: &str) -> usize {
-    state.players[pid]
-        .zones
-        .board
-        .iter()
-        .position(|m| m.card_id == card_id)
-        .expect("card should exist on board")
-}
+use std::collections::HashMap;
+use rand::seq::SliceRandom;
+use crate::game::player::Player;
+use crate::game::effects::{bury_minion, remove_dead_minions, Effect};
+
+use crate::{
+    data::card_template::CardTemplate,
+    game::{
+        card::Card,
+        engine::{
+            choose::Chooser,
+            events::dispatch_events,
+            weapon::{destroy_weapon, lose_durability},
+            utils::{minion_stats_string, IdString},
+        },
+        event::GameEvent,
+        history::Character,
+        keywords::Keywords,
+        state::{GameState, PlayerId},
+    },
+};
+
+// ===========================================================================
+// API publique
+// ===========================================================================
+pub fn perform_attack_phase(
+    state: &mut GameState,
+    current: &PlayerId,
+    opponent: &PlayerId,
+    chooser: &dyn Chooser,
+    templates: &HashMap<String, CardTemplate>,
+) {
+    // ─────────────────────────────── attaques des serviteurs, puis du héros
+    let mut guard = 0;
+    loop {
+        guard += 1;
+        if guard > 5000 {
+            println!("⚠️ guard ATTACK_PHASE stop après 50 itérations");
+            break;
+        }
+
+        let attacker_id = match find_next_attacker(state, current) {
+            Some(id) => id,
+            None => break,
+        };
+
+        // Le héros attaque comme un serviteur, une fois les serviteurs passés
+        if attacker_id == HERO_ATTACKER_ID {
+            hero_attack(state, current, opponent, chooser, templates);
+            continue;
+        }
+
+        let defender_id = choose_defender(state, opponent);
+
+        // Skip Rush sans cible
+        if rush_cannot_attack(state, current, &attacker_id, defender_id.as_deref()) {
+            continue;
+        }
+
+        let Some(defender_id) = declare_attack(state, current, opponent, &attacker_id, defender_id.as_deref(), chooser) else {
+            continue;
+        };
+        match defender_id {
+            Some(def_id) => fight_minion(state, current, opponent, &attacker_id, &def_id, chooser),
+            None => attack_hero(state, current, opponent, &attacker_id, chooser),
+        }
+    }
+}
+
+/// Attaque hors de la phase d’attaque (attaque de suivi, « attaque l’ennemi… ») :
+/// Secrets, déclencheurs et morts comme d’habitude, mais l’attaque du serviteur
+/// n’est pas consommée.
+pub fn forced_attack(
+    state: &mut GameState,
+    owner: &PlayerId,
+    attacker_index: usize,
+    defender: &Character,
+    chooser: &dyn Chooser,
+) {
+    let opponent = owner.opponent();
+    let Some(attacker) = state.players[owner]
+        .zones
+        .board
+        .get(attacker_index)
+        .filter(|m| !m.is_location() && m.effective_health() > 0)
+    else {
+        return;
+    };
+    let (attacker_id, attacks) = (attacker.card_id.clone(), attacker.status.attacks_this_turn);
+    let defender_id = match defender {
+        Character::Minion { owner: o, card_id } if *o == opponent => Some(card_id.clone()),
+        Character::Hero(p) if *p == opponent => None,
+        _ => return,
+    };
+    println!("⚔️ {} attaque hors de la phase d’attaque", attacker.name);
+    let defender_alive = |id: &String| {
+        state.players[&opponent].zones.board.iter().any(|m| m.card_id == *id && m.effective_health() > 0)
+    };
+    if defender_id.as_ref().is_some_and(|id| !defender_alive(id)) {
+        return;
+    }
+
+    if let Some(defender_id) = declare_attack(state, owner, &opponent, &attacker_id, defender_id.as_deref(), chooser) {
+        match defender_id {
+            Some(def_id) => fight_minion(state, owner, &opponent, &attacker_id, &def_id, chooser),
+            None => attack_hero(state, owner, &opponent, &attacker_id, chooser),
+        }
+    }
+    if let Some(m) = state.players.get_mut(owner).unwrap().zones.board.iter_mut().find(|m| m.card_id == attacker_id) {
+        m.status.attacks_this_turn = attacks;
+    }
+}
+
+/// Redirige l’attaque en cours vers un autre personnage du camp défenseur, au hasard.
+pub fn redirect_attack(state: &mut GameState) {
+    let Some(current) = state.last_defender.clone() else {
+        return;
+    };
+    let side = match &current {
+        Character::Hero(pid) | Character::Minion { owner: pid, .. } => *pid,
+    };
+    let mut others: Vec<Character> = state.players[&side]
+        .zones
+        .board
+        .iter()
+        .filter(|m| !m.is_location() && m.effective_health() > 0)
+        .map(|m| Character::Minion { owner: side, card_id: m.card_id.clone() })
+        .collect();
+    others.push(Character::Hero(side));
+    others.retain(|c| *c != current);
+    state.attack_redirect = others.choose(&mut rand::thread_rng()).cloned();
+}
+
+/// Ennemi visé par une attaque forcée d’un serviteur de `owner` : celui qui a le moins
+/// de Vie (les serviteurs avant le héros à égalité), sinon un ennemi au hasard.
+pub fn enemy_defender(state: &GameState, owner: &PlayerId, lowest_health: bool) -> Character {
+    let opponent = owner.opponent();
+    let opp = &state.players[&opponent];
+    let mut enemies: Vec<(Character, i32)> = opp
+        .zones
+        .board
+        .iter()
+        .filter(|m| !m.is_location())
+        .map(|m| (Character::Minion { owner: opponent, card_id: m.card_id.clone() }, m.effective_health()))
+        .collect();
+    enemies.push((Character::Hero(opponent), opp.stats.health));
+    let picked = if lowest_health {
+        enemies.iter().min_by_key(|(_, health)| *health)
+    } else {
+        enemies.choose(&mut rand::thread_rng())
+    };
+    picked.map(|(c, _)| c.clone()).unwrap_or(Character::Hero(opponent))
+}
+
+// ===========================================================================
+// Sélection attaquant / défenseur
+// ===========================================================================
+fn find_next_attacker(state: &GameState, pid: &PlayerId) -> Option<String> {
+    let player = &state.players[pid];
+    player
+        .zones
+        .board
+        .iter()
+        .find(|m| {
+            if m.is_location()
+                || m.status.frozen
+                || m.effects.contains(&Effect::CantAttack)
+                || m.status.attacks_this_turn >= m.max_attacks_per_turn()
+            {
+                false
+            } else if m.status.just_played {
+                m.has_kw(Keywords::CHARGE) || m.has_kw(Keywords::RUSH)
+            } else {
+                true
+            }
+        })
+        .map(|m| m.card_id.clone())
+        .or_else(|| {
+            let opponent_alive = !state.players[&pid.opponent()].is_dead();
+            (opponent_alive && hero_can_attack(player)).then(|| HERO_ATTACKER_ID.to_string())
+        })
+}
+
+fn choose_defender(state: &GameState, opp_id: &PlayerId) -> Option<String> {
+    let opp_board: Vec<_> = state.players[opp_id]
+        .zones
+        .board
+        .iter()
+        .filter(|m| !m.is_location())
+        .collect();
+
+    if let Some(t) = opp_board
+        .iter()
+        .find(|m| m.has_kw(Keywords::TAUNT) && !m.has_kw(Keywords::STEALTH))
+    {
+        return Some(t.card_id.clone());
+    }
+
+    opp_board
+        .iter()
+        .find(|m| !m.has_kw(Keywords::STEALTH))
+        .map(|m| m.card_id.clone())
+}
+
+fn rush_cannot_attack(
+    state: &mut GameState,
+    pid: &PlayerId,
+    attacker_id: &str,
+    defender_opt: Option<&str>,
+) -> bool {
+    let attacker = state.players[pid]
+        .zones
+        .board
+        .iter()
+        .find(|m| m.card_id == attacker_id)
+        .unwrap();
+
+    if attacker.has_kw(Keywords::RUSH)
+        && !attacker.has_kw(Keywords::CHARGE)
+        && attacker.status.just_played
+        && defender_opt.is_none()
+    {
+        state.players.get_mut(pid).unwrap().zones.board
+            .iter_mut()
+            .find(|m| m.card_id == attacker_id)
+            .unwrap()
+            .status
+            .attacks_this_turn += 1;
+        true
+    } else {
+        false
+    }
+}
+
+// ===========================================================================
+// Combat serviteur ↔ serviteur
+// ===========================================================================
+/// combat entre deux serviteurs déjà identifiés
+fn fight_minion(
+    state: &mut GameState,
+    current: &PlayerId,
+    opponent: &PlayerId,
+    attacker_id: &str,
+    defender_id: &str,
+    chooser: &dyn Chooser,
+) {
+    // ── indices sur les boards (pas d’emprunt mutable ici)
+    let att_idx = index_of(state, current, attacker_id);
+    let def_idx = index_of(state, opponent, defender_id);
+
+    // ── log
+    println!(
+        "{} attaque {}",
+        minion_stats_string(&state.players[current].zones.board[att_idx]),
+        minion_stats_string(&state.players[opponent].zones.board[def_idx])
+    );
+
+    // ── bloc mutable simultané (unsafe contrôlé)
+    let (att_dead, def_dead, dmg_att, dmg_def, att_ls, def_ls) = {
+        let current_ptr  = state.players.get_mut(current).unwrap()  as *mut Player;
+        let opponent_ptr = state.players.get_mut(opponent).unwrap() as *mut Player;
+        let (cur_mut, opp_mut) = unsafe { (&mut *current_ptr, &mut *opponent_ptr) };
+
+        let attacker = &mut cur_mut.zones.board[att_idx];
+        let defender = &mut opp_mut.zones.board[def_idx];
+
+        // Capture des flags Lifesteal AVANT le combat
+        let att_ls_local = attacker.has_kw(Keywords::LIFESTEAL);
+        let def_ls_local = defender.has_kw(Keywords::LIFESTEAL);
+
+        // Combat
+        let (a_dead, d_dead, dmg_to_def, dmg_to_att) = perform_attack(attacker, defender);
+
+        // Compter l'attaque tant qu'on a encore &mut attacker
+        attacker.status.attacks_this_turn = attacker.status.attacks_this_turn.saturating_add(1);
+        println!("[ATK] {} -> attacks_this_turn={}", attacker.name, attacker.status.attacks_this_turn);
+        (a_dead, d_dead, dmg_to_def, dmg_to_att, att_ls_local, def_ls_local)
+    };
+
+    let hit = |owner: &PlayerId, card_id: &str| Character::Minion { owner: *owner, card_id: card_id.to_string() };
+    state.history.record_damage(hit(opponent, defender_id), *current, dmg_att);
+    state.history.record_damage(hit(current, attacker_id), *opponent, dmg_def);
+    let cleaved = cleave(state, current, opponent, att_idx, def_idx);
+
+    // Lifesteal (héros) uniquement si présent et si dégâts > 0
+    if att_ls && dmg_att > 0 {
+        state.players.get_mut(current).unwrap().heal(dmg_att);
+    }
+    if def_ls && dmg_def > 0 {
+        state.players.get_mut(opponent).unwrap().heal(dmg_def);
+    }
+    state.event_queue.push_back(GameEvent::AttackResolved {
+        attacker_id: attacker_id.to_string(),
+        owner: *current,
+        defender_id: Some(defender_id.to_string()),
+        killed: def_dead,
+    });
+
+    // Morts / Reborn / Deathrattles via file d’événements (voisins touchés par Cleave d’abord)
+    for id in cleaved {
+        reborn_pass(state, opponent);
+        remove_dead_from_board(state, opponent, &id);
+    }
+    handle_dead(
+        state,
+        current,
+        opponent,
+        att_dead.then_some(attacker_id.to_string()),
+        def_dead.then_some(defender_id.to_string()),
+        chooser,
+    );
+
+}
+
+// ===========================================================================
+// Combat serviteur ↦ héros
+// ===========================================================================
+fn attack_hero(
+    state: &mut GameState,
+    current: &PlayerId,
+    opponent: &PlayerId,
+    attacker_id: &str,
+    chooser: &dyn Chooser,
+) {
+    let att_idx = index_of(state, current, attacker_id);
+
+    // Modifications sur l'attaquant + dégâts au héros adverse
+    let (dmg, attacker_dead, att_ls) = {
+        use crate::game::player::Player;
+        let current_ptr  = state.players.get_mut(current).unwrap()  as *mut Player;
+        let opponent_ptr = state.players.get_mut(opponent).unwrap() as *mut Player;
+        let (cur_mut, opp_mut) = unsafe { (&mut *current_ptr, &mut *opponent_ptr) };
+
+        // (Rush sans Charge vers le héros : écarté par `rush_cannot_attack`)
+        let attacker = &mut cur_mut.zones.board[att_idx];
+
+        let att_ls = attacker.has_kw(Keywords::LIFESTEAL);
+        let dmg = attacker.effective_attack();
+
+        opp_mut.take_damage(dmg as i32);
+
+        // Marquer l'attaque et maj de l'état de l'attaquant
+        attacker.status.has_attacked = true;
+        attacker.status.attacks_this_turn =
+            attacker.status.attacks_this_turn.saturating_add(1);
+
+        // (pas de riposte quand on tape le héros)
+        attacker.status.current_health = Some(attacker.effective_health());
+
+        (dmg, attacker.status.current_health.unwrap_or(0) <= 0, att_ls)
+    };
+
+    println!(
+        "{} [{}|{}] attaque le héros adverse ({:?}) pour {}",
+        state.players[current].zones.board[att_idx].name,
+        state.players[current].zones.board[att_idx].effective_attack(),
+        state.players[current].zones.board[att_idx].effective_health(),
+        opponent,
+        dmg
+    );
+
+    // Lifesteal (soigne le héros du contrôleur de l’attaquant)
+    if att_ls && dmg > 0 {
+        state.players.get_mut(current).unwrap().heal(dmg);
+    }
+    if dmg > 0 {
+        state.event_queue.push_back(GameEvent::HeroDamaged { player: *opponent, amount: dmg, dealer: *current });
+    }
+    state.event_queue.push_back(GameEvent::AttackResolved {
+        attacker_id: attacker_id.to_string(),
+        owner: *current,
+        defender_id: None,
+        killed: false,
+    });
+
+    // Nettoyage / deathrattles via file d’événements
+    handle_dead(
+        state,
+        current,
+        opponent,
+        attacker_dead.then_some(attacker_id.to_string()),
+        None,
+        chooser,
+    );
+}
+
+
+// ===========================================================================
+// Héros + arme
+// ===========================================================================
+/// Identifiant d’attaquant utilisé pour le héros dans `AttackDeclared`.
+pub const HERO_ATTACKER_ID: &str = "hero";
+
+/// Attaque totale du héros : arme (si encore de la durabilité) + attaque gagnée ce tour
+/// + attaque gagnée sans durée.
+pub fn hero_attack_value(player: &Player) -> i32 {
+    let weapon_atk = match &player.stats.weapon {
+        Some(w) if w.health.unwrap_or(0) > 0 => w.attack.unwrap_or(0),
+        _ => 0,
+    };
+    weapon_atk + player.stats.hero_attack + player.stats.hero_base_attack
+}
+
+/// Mots-clés portés par l’arme équipée (Windfury, Lifesteal, Poisonous, Freeze…).
+fn weapon_keywords(player: &Player) -> Keywords {
+    player.stats.weapon.as_ref().map(|w| w.keywords).unwrap_or_default()
+}
+
+/// Mots-clés du héros pendant son attaque : les siens et ceux de son arme.
+fn hero_combat_keywords(player: &Player) -> Keywords {
+    player.stats.hero_keywords | weapon_keywords(player)
+}
+
+fn hero_can_attack(player: &Player) -> bool {
+    let max_attacks = if hero_combat_keywords(player).has(Keywords::WINDFURY) { 2 } else { 1 };
+    !player.hero_frozen
+        && player.hero_attacks_this_turn < max_attacks
+        && hero_attack_value(player) > 0
+}
+
+/// Une attaque du héros : même cible qu’un serviteur (Taunt/Stealth), Secrets,
+/// riposte, mots-clés de l’arme, perte de durabilité puis `HeroAttacked` ; une arme
+/// usée est détruite après les déclencheurs de l’attaque.
+fn hero_attack(
+    state: &mut GameState,
+    current: &PlayerId,
+    opponent: &PlayerId,
+    chooser: &dyn Chooser,
+    templates: &HashMap<String, CardTemplate>,
+) {
+    let defender_id = choose_defender(state, opponent);
+    let Some(defender_id) = declare_attack(state, current, opponent, HERO_ATTACKER_ID, defender_id.as_deref(), chooser) else {
+        return;
+    };
+
+    let (atk, kw) = {
+        let p = &state.players[current];
+        (hero_attack_value(p), hero_combat_keywords(p))
+    };
+
+    // ── bloc 1 : dégâts infligés ---------------------------------------
+    let (dealt, retaliation) = match &defender_id {
+        Some(def_id) => {
+            let def_idx = index_of(state, opponent, def_id);
+            let def = &mut state.players.get_mut(opponent).unwrap().zones.board[def_idx];
+            println!("Le héros de {} [{}] attaque {}", current.id_string(), atk, minion_stats_string(def));
+
+            // riposte calculée AVANT les dégâts
+            let retaliation = def.effective_attack();
+            let dealt = strike_minion(def, atk, kw.has(Keywords::POISONOUS));
+            if kw.has(Keywords::FREEZE) && dealt > 0 {
+                def.status.frozen = true;
+            }
+            state.history.record_damage(Character::Minion { owner: *opponent, card_id: def_id.clone() }, *current, dealt);
+            (dealt, retaliation)
+        }
+        None => {
+            println!("Le héros de {} [{}] attaque le héros adverse", current.id_string(), atk);
+            let opp = state.players.get_mut(opponent).unwrap();
+            opp.take_damage(atk);
+            if kw.has(Keywords::FREEZE) {
+                opp.hero_frozen = true;
+            }
+            state.event_queue.push_back(GameEvent::HeroDamaged { player: *opponent, amount: atk, dealer: *current });
+            (atk, 0)
+        }
+    };
+
+    // ── bloc 2 : riposte, Lifesteal, durabilité ------------------------
+    state.players.get_mut(current).unwrap().hero_attacks_this_turn += 1;
+    if kw.has(Keywords::IMMUNE) && retaliation > 0 {
+        println!("Le héros de {} est Insensible pendant son attaque", current.id_string());
+    } else if retaliation > 0 {
+        state.players.get_mut(current).unwrap().take_damage(retaliation);
+        state.event_queue.push_back(GameEvent::HeroDamaged { player: *current, amount: retaliation, dealer: *opponent });
+    }
+    if kw.has(Keywords::LIFESTEAL) && dealt > 0 {
+        state.players.get_mut(current).unwrap().heal(dealt);
+    }
+    let weapon_broken = lose_durability(state, current, 1);
+    state.event_queue.push_back(GameEvent::HeroAttacked {
+        owner: *current,
+        defender_id: defender_id.clone(),
+    });
+
+    // ── bloc 3 : morts + file d’événements (AfterHeroAttack…) ---------
+    let defender_dead = defender_id.filter(|id| {
+        state.players[opponent]
+            .zones
+            .board
+            .iter()
+            .any(|m| m.card_id == *id && m.effective_health() <= 0)
+    });
+    handle_dead(state, current, opponent, None, defender_dead, chooser);
+
+    // l'arme usée n'est détruite qu'après ses déclencheurs « après l'attaque »
+    if weapon_broken {
+        destroy_weapon(state, current, chooser, templates);
+        dispatch_events(state, chooser);
+    }
+}
+
+/// Dégâts non ripostés sur un serviteur (Divine Shield, Poisonous). Renvoie les dégâts infligés.
+fn strike_minion(defender: &mut Card, amount: i32, poisonous: bool) -> i32 {
+    if amount <= 0 {
+        return 0;
+    }
+    if defender.has_kw(Keywords::DIVINE_SHIELD) {
+        defender.remove_kw(Keywords::DIVINE_SHIELD);
+        return 0;
+    }
+    defender.status.current_health = Some(defender.effective_health() - amount);
+    if poisonous {
+        defender.status.current_health = Some(0);
+    }
+    amount
+}
+
+/// Cleave : les voisins du défenseur subissent aussi l’attaque de l’attaquant (sans riposte).
+/// Renvoie les ids des voisins tués.
+fn cleave(state: &mut GameState, current: &PlayerId, opponent: &PlayerId, att_idx: usize, def_idx: usize) -> Vec<String> {
+    let attacker = &state.players[current].zones.board[att_idx];
+    if !attacker.effects.contains(&Effect::Cleave) {
+        return Vec::new();
+    }
+    let (atk, poisonous) = (attacker.effective_attack(), attacker.has_kw(Keywords::POISONOUS));
+    let mut dead = Vec::new();
+    for i in state.players[opponent].adjacent_minions(def_idx) {
+        let minion = &mut state.players.get_mut(opponent).unwrap().zones.board[i];
+        let dealt = strike_minion(minion, atk, poisonous);
+        println!("🪓 {} est touché par Cleave ({} dégâts)", minion.name, dealt);
+        let card_id = minion.card_id.clone();
+        if minion.effective_health() <= 0 {
+            dead.push(card_id.clone());
+        }
+        state.history.record_damage(Character::Minion { owner: *opponent, card_id }, *current, dealt);
+    }
+    dead
+}
+
+// ===========================================================================
+// Déclaration d’attaque (Secrets adverses)
+// ===========================================================================
+fn defender_character(opponent: &PlayerId, defender_id: Option<&str>) -> Character {
+    match defender_id {
+        Some(id) => Character::Minion { owner: *opponent, card_id: id.to_string() },
+        None => Character::Hero(*opponent),
+    }
+}
+
+/// Annonce l’attaque et laisse réagir les Secrets et les déclencheurs « quand ce
+/// serviteur attaque » (avant les dégâts) ; ceux-ci peuvent rediriger l’attaque via
+/// `attack_redirect`. Renvoie le défenseur final (`Some(None)` : le héros adverse),
+/// ou `None` si l’attaque n’a plus lieu (attaquant ou défenseur retiré/mort
+/// entre-temps) ; l’attaque est alors consommée pour ne pas reboucler.
+fn declare_attack(
+    state: &mut GameState,
+    current: &PlayerId,
+    opponent: &PlayerId,
+    attacker_id: &str,
+    defender_id: Option<&str>,
+    chooser: &dyn Chooser,
+) -> Option<Option<String>> {
+    state.last_defender = Some(defender_character(opponent, defender_id));
+    state.attack_redirect = None;
+    state.event_queue.push_back(GameEvent::AttackDeclared {
+        attacker_id: attacker_id.to_string(),
+        owner: *current,
+        defender_id: defender_id.map(|s| s.to_string()),
+    });
+    dispatch_events(state, chooser);
+
+    let defender_id = match state.attack_redirect.take() {
+        Some(redirect) => {
+            println!("↪️ L’attaque de {} est redirigée vers {:?}", attacker_id, redirect);
+            match redirect {
+                Character::Minion { card_id, .. } => Some(card_id),
+                Character::Hero(_) => None,
+            }
+        }
+        None => defender_id.map(|s| s.to_string()),
+    };
+
+    let alive = |pid: &PlayerId, id: &str| {
+        if id == HERO_ATTACKER_ID && pid == current {
+            return !state.players[pid].is_dead();
+        }
+        state.players[pid]
+            .zones
+            .board
+            .iter()
+            .any(|m| m.card_id == id && m.effective_health() > 0)
+    };
+    let attacker_ok = alive(current, attacker_id);
+    let defender_ok = defender_id.as_deref().is_none_or(|d| alive(opponent, d));
+    if attacker_ok && defender_ok {
+        state.last_defender = Some(defender_character(opponent, defender_id.as_deref()));
+        return Some(defender_id);
+    }
+
+    println!("⚔️ L’attaque de {} est annulée", attacker_id);
+    if attacker_id == HERO_ATTACKER_ID {
+        let p = state.players.get_mut(current).unwrap();
+        p.hero_attacks_this_turn = p.hero_attacks_this_turn.saturating_add(1);
+    }
+    if let Some(m) = state.players.get_mut(current).unwrap().zones.board.iter_mut().find(|m| m.card_id == attacker_id) {
+        m.status.attacks_this_turn = m.status.attacks_this_turn.saturating_add(1);
+    }
+    remove_dead_minions(state);
+    None
+}
+
+// ===========================================================================
+// Gestion des morts
+// ===========================================================================
+fn handle_dead(
+    state: &mut GameState,
+    current: &PlayerId,
+    opponent: &PlayerId,
+    attacker_dead: Option<String>,
+    defender_dead: Option<String>,
+    chooser: &dyn Chooser,
+) {
+    // ─── Attaquant mort ────────────────────────────────────────────────
+    if let Some(id) = attacker_dead {
+        reborn_pass(state, current);                       // Reborn d’abord
+        // 1. retire du board
+        // 2. retire du board ; `MinionDied` part une fois la carte au cimetière
+        remove_dead_from_board(state, current, &id);
+    }
+
+    // ─── Défenseur mort ────────────────────────────────────────────────
+    if let Some(id) = defender_dead {
+        reborn_pass(state, opponent);
+        remove_dead_from_board(state, opponent, &id);
+    }
+
+    // dispatch une fois la file remplie
+    dispatch_events(state, chooser);
+}
+
+/// Retire du board les exemplaires morts de `id` (un Cadavre par serviteur).
+fn remove_dead_from_board(state: &mut GameState, pid: &PlayerId, id: &str) {
+    while let Some(pos) = state.players[pid]
+        .zones
+        .board
+        .iter()
+        .position(|m| m.card_id == id && m.status.current_health.unwrap_or(0) <= 0)
+    {
+        bury_minion(state, pid, pos);
+    }
+}
+
+fn reborn_pass(state: &mut GameState, pid: &PlayerId) {
+    for m in &mut state.players.get_mut(pid).unwrap().zones.board {
+        if m.status.current_health.unwrap_or(0) <= 0 && m.has_kw(Keywords::REBORN) {
+            println!("{} revient en vie grâce à Reborn !", m.name);
+            m.remove_kw(Keywords::REBORN);
+            m.status.current_health = Some(1);
+        }
+    }
+}
+
+
+// ===========================================================================
+// Combat élémentaire
+// ===========================================================================
+pub fn perform_attack(attacker: &mut Card, defender: &mut Card) -> (bool, bool, i32, i32) {
+    let att_atk = attacker.effective_attack();
+    let def_atk = defender.effective_attack();
+
+    // Divine Shield
+    let mut dmg_def = att_atk;
+    if defender.has_kw(Keywords::DIVINE_SHIELD) && att_atk > 0 {
+        defender.remove_kw(Keywords::DIVINE_SHIELD);
+        dmg_def = 0;
+    }
+    let mut dmg_att = def_atk;
+    if attacker.has_kw(Keywords::DIVINE_SHIELD) && def_atk > 0 {
+        attacker.remove_kw(Keywords::DIVINE_SHIELD);
+        dmg_att = 0;
+    }
+
+    attacker.status.current_health = Some(attacker.effective_health() - dmg_att);
+    defender.status.current_health = Some(defender.effective_health() - dmg_def);
+
+    // Poisonous
+    if attacker.has_kw(Keywords::POISONOUS) && dmg_def > 0 && !defender.has_kw(Keywords::DIVINE_SHIELD) {
+        defender.status.current_health = Some(0);
+    }
+    if defender.has_kw(Keywords::POISONOUS) && dmg_att > 0 && !attacker.has_kw(Keywords::DIVINE_SHIELD) {
+        attacker.status.current_health = Some(0);
+    }
+
+    attacker.status.has_attacked = true;
+    attacker.remove_kw(Keywords::STEALTH);
+
+    let att_dead = attacker.status.current_health.unwrap_or(0) <= 0;
+    let def_dead = defender.status.current_health.unwrap_or(0) <= 0;
+
+    (att_dead, def_dead, dmg_def.max(0), dmg_att.max(0))
+}
+
+// ===========================================================================
+// Utilitaire d’index
+// ===========================================================================
+fn index_of(state: &GameState, pid: &PlayerId, card_id: &str) -> usize {
+    state.players[pid]
+        .zones
+        .board
+        .iter()
+        .position(|m| m.card_id == card_id)
+        .expect("card should exist on board")
+}
@@ -1,85 +1,133 @@
-use rand::seq::SliceRandom;
-
-use crate::game::card::Card;
-use crate::game::state::GameState;
-
-
-/// Les différents types de choix qu'un joueur peut devoir faire
-#[derive(Debug, Clone)]
-pub enum Choice {
-    Mulligan { hand: Vec<Card> },
-    PlayCard { hand: Vec<Card>, mana: u8 },
-    PlayCardIndex(usize),
-    ChooseOne { options: Vec<Card> },
-    Discover { options: Vec<Card> },
-    Target { valid_targets: Vec<usize> },
-    EndTurn,
-}
-
-/// Trait à implémenter pour toute stratégie de prise de décision (IA, CLI, etc.)
-pub trait Chooser {
-    fn choose(&self, state: &GameState, choice: Choice) -> Choice;
-}
-
-/// Implémentation aléatoire naïve
-pub struct RandomChooser;
-
-impl Chooser for RandomChooser {
-    fn choose(&self, _state: &GameState, choice: Choice) -> Choice {
-        match &choice {
-            Choice::Mulligan { hand } => {
-                // Garde tout (naïvement)
-                Choice::Mulligan { hand: hand.clone() }
-            }
-
-            Choice::PlayCard { hand, mana } => {
-                let playable: Vec<_> = hand
-                    .iter()
-                    .enumerate()
-                    .filter(|(_, card)| card.cost <= *mana)
-                    .map(|(i, _)| i)
-                    .collect();
-
-                if let Some(&index) = playable.choose(&mut rand::thread_rng()) {
-                    Choice::PlayCardIndex(index)
-                } else {
-                    Choice::EndTurn
-                }
-            }
-
-
-            Choice::ChooseOne { options } | Choice::Discover { options } => {
-                if let Some(card) = options.choose(&mut rand::thread_rng()) {
-                    Choice::ChooseOne {
-                        options: vec![card.clone()],
-                    }
-                } else {
-                    choice
-                }
-            }
-
-            Choice::Target { valid_targets } => {
-                if let Some(&target) = valid_targets.choose(&mut rand::thread_rng()) {
-                    Choice::Target {
-                        valid_targets: vec![target],
-                    }
-                } else {
-                    choice
-                }
-            }
-
-            Choice::EndTurn => Choice::EndTurn,
-            _ => choice,
-        }
-    }
-}
-
-/// Méthode utilitaire pour extraire un index de carte à jouer
-impl Choice {
-    pub fn as_play_card(&self) -> Option<usize> {
-        match self {
-            Choice::PlayCardIndex(i) => Some(*i),
-            _ => None,
-        }
-    }
-}
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::game::card::Card;
+use crate::game::state::GameState;
+
+
+/// Les différents types de choix qu'un joueur peut devoir faire
+#[derive(Debug, Clone)]
+pub enum Choice {
+    Mulligan { hand: Vec<Card> },
+    /// `costs[i]` = coût effectif de `hand[i]` (réductions et auras comprises),
+    /// `playable[i]` = contraintes de zone respectées (Secret en double…)
+    PlayCard { hand: Vec<Card>, costs: Vec<u8>, playable: Vec<bool>, mana: u8 },
+    PlayCardIndex(usize),
+    /// Emplacement du serviteur/lieu `hand_index` de la main : `0..=board_len` (0 = tout à gauche)
+    BoardPosition { hand_index: usize, board_len: usize },
+    BoardPositionIndex(usize),
+    ChooseOne { options: Vec<Card> },
+    Discover { options: Vec<Card> },
+    Target { valid_targets: Vec<usize> },
+    /// Lieux activables (indices sur le board)
+    ActivateLocation { board_indices: Vec<usize> },
+    ActivateLocationIndex(usize),
+    /// Cartes Échangeables en main (indices) ; `TradeIndex` = carte à échanger
+    Trade { hand_indices: Vec<usize> },
+    TradeIndex(usize),
+    /// Carte à défausser parmi la main (indices) ; `DiscardIndex` = carte retenue
+    Discard { hand_indices: Vec<usize> },
+    DiscardIndex(usize),
+    EndTurn,
+}
+
+/// Trait à implémenter pour toute stratégie de prise de décision (IA, CLI, etc.)
+pub trait Chooser {
+    fn choose(&self, state: &GameState, choice: Choice) -> Choice;
+}
+
+/// Implémentation aléatoire naïve
+pub struct RandomChooser;
+
+impl Chooser for RandomChooser {
+    fn choose(&self, _state: &GameState, choice: Choice) -> Choice {
+        match &choice {
+            Choice::Mulligan { hand } => {
+                // Garde tout (naïvement)
+                Choice::Mulligan { hand: hand.clone() }
+            }
+
+            Choice::PlayCard { hand, costs, playable, mana } => {
+                let playable: Vec<_> = (0..hand.len())
+                    .filter(|&i| playable.get(i).copied().unwrap_or(true))
+                    .filter(|&i| costs.get(i).copied().unwrap_or(hand[i].cost) <= *mana)
+                    .collect();
+
+                if let Some(&index) = playable.choose(&mut rand::thread_rng()) {
+                    Choice::PlayCardIndex(index)
+                } else {
+                    Choice::EndTurn
+                }
+            }
+
+
+            Choice::BoardPosition { board_len, .. } => {
+                Choice::BoardPositionIndex(rand::thread_rng().gen_range(0..=*board_len))
+            }
+
+            Choice::ChooseOne { options } => {
+                if let Some(card) = options.choose(&mut rand::thread_rng()) {
+                    Choice::ChooseOne {
+                        options: vec![card.clone()],
+                    }
+                } else {
+                    choice
+                }
+            }
+
+            Choice::Discover { options } => {
+                if let Some(card) = options.choose(&mut rand::thread_rng()) {
+                    Choice::Discover {
+                        options: vec![card.clone()],
+                    }
+                } else {
+                    choice
+                }
+            }
+
+            Choice::Target { valid_targets } => {
+                if let Some(&target) = valid_targets.choose(&mut rand::thread_rng()) {
+                    Choice::Target {
+                        valid_targets: vec![target],
+                    }
+                } else {
+                    choice
+                }
+            }
+
+            Choice::ActivateLocation { board_indices } => {
+                match board_indices.choose(&mut rand::thread_rng()) {
+                    Some(&i) => Choice::ActivateLocationIndex(i),
+                    None => Choice::EndTurn,
+                }
+            }
+
+            Choice::Trade { hand_indices } => {
+                // Une chance de ne rien échanger (sinon on échangerait tout, toujours)
+                let pick = rand::thread_rng().gen_range(0..=hand_indices.len());
+                match hand_indices.get(pick) {
+                    Some(&i) => Choice::TradeIndex(i),
+                    None => Choice::EndTurn,
+                }
+            }
+
+            Choice::Discard { hand_indices } => match hand_indices.choose(&mut rand::thread_rng()) {
+                Some(&i) => Choice::DiscardIndex(i),
+                None => choice,
+            },
+
+            Choice::EndTurn => Choice::EndTurn,
+            _ => choice,
+        }
+    }
+}
+
+/// Méthode utilitaire pour extraire un index de carte à jouer
+impl Choice {
+    pub fn as_play_card(&self) -> Option<usize> {
+        match self {
+            Choice::PlayCardIndex(i) => Some(*i),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+
+use crate::{
+    data::card_template::CardTemplate,
+    game::{
+        card::Card,
+        effects::{apply_effect, Effect},
+        engine::{
+            choose::{Choice, Chooser},
+            utils::IdString,
+        },
+        state::{GameState, PlayerId},
+    },
+};
+
+/// Préfixe des cartes d'option présentées au `Chooser` (suivi de l'index de l'option).
+pub const CHOOSE_ONE_PREFIX: &str = "CHOOSE_ONE_";
+
+/// Choix des Mystiques : `amount` options parmi `options`, chacune résolue avec son
+/// propre ciblage. Avec un « combine_choose_one » actif (Fandral), tout est résolu.
+pub fn choose_one(
+    state: &mut GameState,
+    player_id: &PlayerId,
+    options: &[Effect],
+    amount: usize,
+    chooser: &dyn Chooser,
+    card_templates: &HashMap<String, CardTemplate>,
+) {
+    if combines_choose_one(state, player_id) {
+        println!("🌿 {} combine tous les effets du Choix des Mystiques", player_id.id_string());
+        for eff in options {
+            apply_effect(state, player_id, eff, chooser, card_templates);
+        }
+        return;
+    }
+
+    let mut remaining: Vec<Card> = options.iter().enumerate().map(|(i, e)| option_card(i, e)).collect();
+    for _ in 0..amount.min(options.len()) {
+        let picked_id = match chooser.choose(state, Choice::ChooseOne { options: remaining.clone() }) {
+            Choice::ChooseOne { options: picked } => picked.first().map(|c| c.card_id.clone()),
+            _ => None,
+        };
+        let pos = picked_id
+            .and_then(|id| remaining.iter().position(|c| c.card_id == id))
+            .unwrap_or(0);
+        let picked = remaining.remove(pos);
+        println!("🌿 {} choisit : {}", player_id.id_string(), picked.name);
+        for eff in &picked.effects {
+            apply_effect(state, player_id, eff, chooser, card_templates);
+        }
+    }
+}
+
+/// Un serviteur allié porte-t-il une aura « combine_choose_one » ?
+pub fn combines_choose_one(state: &GameState, player_id: &PlayerId) -> bool {
+    state.players[player_id]
+        .zones
+        .board
+        .iter()
+        .filter(|c| !c.status.silenced)
+        .flat_map(|c| c.effects.iter())
+        .any(|e| matches!(e, Effect::Aura { effect, .. } if Effect::from_value(effect) == Effect::CombineChooseOne))
+}
+
+/// Carte d'option montrée au joueur : porte l'effet de la branche.
+fn option_card(index: usize, effect: &Effect) -> Card {
+    let mut card = serde_json::from_value::<CardTemplate>(json!({
+        "card_id": format!("{}{}", CHOOSE_ONE_PREFIX, index),
+        "card_name": option_label(effect),
+        "card_class": "NEUTRAL",
+        "card_type": "SPELL",
+        "cost": 0
+    }))
+    .expect("template d'option valide")
+    .to_card();
+    card.effects = vec![effect.clone()];
+    card.native_effects = card.effects.clone();
+    card
+}
+
+fn option_label(effect: &Effect) -> String {
+    match effect {
+        Effect::GrantMechanic { mechanic, .. } => mechanic.clone(),
+        other => {
+            let debug = format!("{:?}", other);
+            debug.split([' ', '{', '(']).next().unwrap_or("Option").to_string()
+        }
+    }
+}
@@ -0,0 +1,323 @@
+use serde_json::Value;
+
+use crate::game::{
+    card::Card,
+    effects::Effect,
+    engine::utils::IdString,
+    filter::CardFilter,
+    enums::CardType,
+    state::{GameState, PlayerId},
+};
+
+/// Tag posé sur la source d'une aura « une fois par tour » déjà consommée.
+pub const AURA_USED_TAG: &str = "cost_aura_used";
+
+/// Nature d'une modification de coût.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CostMode {
+    /// Ajoute (ou retire si négatif) du mana au coût.
+    Delta(i32),
+    /// Fixe le coût à une valeur (Kalecgos…).
+    Set(u8),
+}
+
+/// Modification de coût temporaire posée sur un joueur
+/// (« votre prochain Démon coûte (2) de moins », « les sorts adverses coûtent (1) de plus »…).
+#[derive(Debug, Clone)]
+pub struct CostModifier {
+    pub source: String,
+    pub mode: CostMode,
+    pub card_type: Option<CardType>,
+    pub filter: Option<CardFilter>,
+    /// Consommée par la prochaine carte correspondante jouée.
+    pub one_shot: bool,
+    /// Nombre de fins de tour (tous joueurs confondus) avant expiration ; `None` = jusqu'à usage.
+    pub turn_ends_left: Option<u8>,
+}
+
+impl CostModifier {
+    pub fn applies_to(&self, card: &Card) -> bool {
+        self.card_type.as_ref().is_none_or(|t| *t == card.card_type)
+            && self.filter.as_ref().is_none_or(|f| f.matches(card))
+    }
+}
+
+// ===========================================================================
+// Calcul du coût effectif
+// ===========================================================================
+
+/// Coût réellement payé pour `card` (en main de `pid`) : coût de base,
+/// réductions permanentes, modificateurs temporaires du joueur, effets
+/// « coûte moins » de la carte elle-même et auras des plateaux. Jamais < 0.
+pub fn effective_cost(state: &GameState, pid: &PlayerId, card: &Card) -> u8 {
+    let mut cost = card.cost as i32 + card.status.cost_modifier;
+    let mut set: Option<u8> = None;
+
+    let mut apply = |mode: CostMode| match mode {
+        CostMode::Delta(d) => cost += d,
+        CostMode::Set(v) => set = Some(set.map_or(v, |s| s.min(v))),
+    };
+
+    // ① effets passifs portés par la carte elle-même (Dread Corsair, Stitched Giant…)
+    for eff in &card.effects {
+        if let Effect::ModifyCost { mode, amount, target, value, trigger: None, .. } = eff {
+            let on_self = target
+                .as_ref()
+                .is_none_or(|t| t.iter().any(|s| s == "self"));
+            if on_self {
+                if let Some(m) = cost_mode(state, pid, mode.as_deref(), *amount, value.as_ref()) {
+                    apply(m);
+                }
+            }
+        }
+    }
+
+    // ② modificateurs temporaires posés sur le joueur
+    for m in &state.players[pid].cost_modifiers {
+        if m.applies_to(card) {
+            apply(m.mode);
+        }
+    }
+
+    // ③ auras de coût présentes sur les plateaux
+    for (aura_owner, source) in cost_auras(state) {
+        if let Some(m) = aura_mode_for(state, &aura_owner, source, pid, card) {
+            apply(m);
+        }
+    }
+
+    let cost = match set {
+        Some(v) => v as i32,
+        None => cost,
+    };
+    cost.clamp(0, u8::MAX as i32) as u8
+}
+
+/// Coût effectif de chaque carte de la main, dans l'ordre de la main.
+pub fn hand_costs(state: &GameState, pid: &PlayerId) -> Vec<u8> {
+    state.players[pid]
+        .zones
+        .hand
+        .iter()
+        .map(|c| effective_cost(state, pid, c))
+        .collect()
+}
+
+/// Consomme les réductions « prochaine carte » et les auras « une fois par tour »
+/// utilisées par la carte qui vient d'être payée.
+pub fn consume_cost_modifiers(state: &mut GameState, pid: &PlayerId, card: &Card) {
+    let used_auras: Vec<(PlayerId, String)> = cost_auras(state)
+        .filter(|(owner, src)| {
+            aura_is_once_per_turn(src) && aura_mode_for(state, owner, src, pid, card).is_some()
+        })
+        .map(|(owner, src)| (owner, src.card_id.clone()))
+        .collect();
+    for (owner, src_id) in used_auras {
+        let board = &mut state.players.get_mut(&owner).unwrap().zones.board;
+        if let Some(src) = board.iter_mut().find(|c| c.card_id == src_id) {
+            src.tags.insert(AURA_USED_TAG.to_string(), 1);
+        }
+    }
+
+    let player = state.players.get_mut(pid).unwrap();
+    let before = player.cost_modifiers.len();
+    // toutes les réductions « prochaine carte » appliquées à la carte sont utilisées
+    player.cost_modifiers.retain(|m| !(m.one_shot && m.applies_to(card)));
+    if player.cost_modifiers.len() != before {
+        println!(
+            "[COST] {} consomme {} réduction(s) de coût avec {}",
+            pid.id_string(),
+            before - player.cost_modifiers.len(),
+            card.name
+        );
+    }
+}
+
+/// Fait vieillir les modificateurs temporaires des deux joueurs (appelé en fin de tour).
+pub fn expire_cost_modifiers(state: &mut GameState) {
+    for player in state.players.values_mut() {
+        for m in player.cost_modifiers.iter_mut() {
+            if let Some(n) = m.turn_ends_left.as_mut() {
+                *n = n.saturating_sub(1);
+            }
+        }
+        player.cost_modifiers.retain(|m| m.turn_ends_left != Some(0));
+    }
+}
+
+// ===========================================================================
+// Application de Effect::ModifyCost (battlecry, outcast…)
+// ===========================================================================
+/// `source` : id de la carte dont l'effet se résout, retenu sur les modificateurs posés.
+pub fn apply_modify_cost(state: &mut GameState, player_id: &PlayerId, effect: &Effect, source: &str) {
+    let Effect::ModifyCost { mode, amount, target, filter, value, duration, .. } = effect else {
+        return;
+    };
+    let Some(cost_mode) = cost_mode(state, player_id, mode.as_deref(), *amount, value.as_ref()) else {
+        println!("[COST] mode {:?} non géré", mode);
+        return;
+    };
+
+    // "until_played" / "until_used" / absent : jusqu'à la prochaine carte correspondante
+    let (one_shot, turn_ends_left) = match duration.as_deref() {
+        Some("this_turn") => (true, Some(1)),
+        Some("until_end_of_next_turn") => (false, Some(2)),
+        _ => (true, None),
+    };
+
+    let targets = target.clone().unwrap_or_default();
+    for tgt in &targets {
+        match tgt.as_str() {
+            "next_friendly_card" => {
+                push_modifier(state, player_id, CostModifier {
+                    source: source.to_string(),
+                    mode: cost_mode,
+                    card_type: None,
+                    filter: filter.clone(),
+                    one_shot,
+                    turn_ends_left,
+                });
+            }
+            "opponent_spells" => {
+                push_modifier(state, &player_id.opponent(), CostModifier {
+                    source: source.to_string(),
+                    mode: cost_mode,
+                    card_type: Some(CardType::Spell),
+                    filter: filter.clone(),
+                    one_shot: false,
+                    turn_ends_left,
+                });
+            }
+            "opponent_hero_power" => {
+                push_modifier(state, &player_id.opponent(), CostModifier {
+                    source: source.to_string(),
+                    mode: cost_mode,
+                    card_type: Some(CardType::HeroPower),
+                    filter: None,
+                    one_shot: true,
+                    turn_ends_left,
+                });
+            }
+            "leftmost_card_in_hand" | "rightmost_card_in_hand" => {
+                let hand = &mut state.players.get_mut(player_id).unwrap().zones.hand;
+                let idx = if tgt == "leftmost_card_in_hand" {
+                    Some(0)
+                } else {
+                    hand.len().checked_sub(1)
+                };
+                if let (Some(i), CostMode::Delta(d)) = (idx, cost_mode) {
+                    if let Some(card) = hand.get_mut(i) {
+                        card.status.cost_modifier += d;
+                        println!("[COST] {} coûte désormais {:+} ({})", card.name, card.status.cost_modifier, tgt);
+                    }
+                }
+            }
+            other => println!("[COST] cible {:?} non gérée pour ModifyCost", other),
+        }
+    }
+}
+
+// ===========================================================================
+// Helpers
+// ===========================================================================
+fn push_modifier(state: &mut GameState, pid: &PlayerId, m: CostModifier) {
+    println!("[COST] {} reçoit {:?} ({})", pid.id_string(), m.mode, m.source);
+    state.players.get_mut(pid).unwrap().cost_modifiers.push(m);
+}
+
+/// Convertit (mode, amount, value) en `CostMode`. `value` peut être un nombre
+/// ou une valeur dynamique (`weapon_attack`).
+fn cost_mode(
+    state: &GameState,
+    pid: &PlayerId,
+    mode: Option<&str>,
+    amount: Option<i32>,
+    value: Option<&Value>,
+) -> Option<CostMode> {
+    let n = match value {
+        Some(Value::Number(v)) => v.as_i64().map(|x| x as i32),
+        Some(Value::String(s)) => Some(dynamic_cost_value(state, pid, s)),
+        _ => None,
+    }
+    .or(amount)
+    .unwrap_or(0);
+
+    match mode {
+        Some("reduce") => Some(CostMode::Delta(-n)),
+        Some("increase") => Some(CostMode::Delta(n)),
+        Some("set") => Some(CostMode::Set(n.max(0) as u8)),
+        _ => None,
+    }
+}
+
+fn dynamic_cost_value(state: &GameState, pid: &PlayerId, key: &str) -> i32 {
+    let player = &state.players[pid];
+    match key {
+        "weapon_attack" => player
+            .stats
+            .weapon
+            .as_ref()
+            .map_or(0, |w| w.effective_attack()),
+        "corpses_spent_this_game" => player.corpses_spent_this_game as i32,
+        _ => 0,
+    }
+}
+
+/// Toutes les auras de coût actives sur les plateaux, avec leur contrôleur.
+fn cost_auras(state: &GameState) -> impl Iterator<Item = (PlayerId, &Card)> {
+    state.players.iter().flat_map(|(&owner, p)| {
+        p.zones
+            .board
+            .iter()
+            .filter(|c| !c.status.silenced && aura_cost_effect(c).is_some())
+            .map(move |c| (owner, c))
+    })
+}
+
+fn aura_cost_effect(card: &Card) -> Option<Effect> {
+    card.effects.iter().find_map(|e| match e {
+        Effect::Aura { effect, .. } => match Effect::from_value(effect) {
+            m @ Effect::ModifyCost { .. } => Some(m),
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
+fn aura_is_once_per_turn(source: &Card) -> bool {
+    matches!(
+        aura_cost_effect(source),
+        Some(Effect::ModifyCost { duration: Some(ref d), .. }) if d == "once_per_turn"
+    )
+}
+
+/// Modification qu'une aura de `source` (contrôlée par `aura_owner`) applique
+/// à `card` en main de `pid`, si elle s'applique.
+fn aura_mode_for(
+    state: &GameState,
+    aura_owner: &PlayerId,
+    source: &Card,
+    pid: &PlayerId,
+    card: &Card,
+) -> Option<CostMode> {
+    let Some(Effect::ModifyCost { mode, amount, target, filter, value, .. }) = aura_cost_effect(source) else {
+        return None;
+    };
+    if aura_is_once_per_turn(source) && source.tags.get(AURA_USED_TAG).copied().unwrap_or(0) > 0 {
+        return None;
+    }
+
+    let friendly = aura_owner == pid;
+    let hits = target.unwrap_or_default().iter().any(|t| match t.as_str() {
+        "first_friendly_spell_played" | "friendly_spells" => friendly && card.card_type == CardType::Spell,
+        "friendly_minions" => friendly && card.card_type == CardType::Minion,
+        "friendly_cards" => friendly,
+        "opponent_spells" => !friendly && card.card_type == CardType::Spell,
+        "opponent_minions" => !friendly && card.card_type == CardType::Minion,
+        _ => false,
+    });
+    if !hits || !filter.as_ref().is_none_or(|f| f.matches(card)) {
+        return None;
+    }
+    cost_mode(state, aura_owner, mode.as_deref(), amount, value.as_ref())
+}
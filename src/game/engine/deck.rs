@@ -0,0 +1,114 @@
+use rand::seq::IteratorRandom;
+use rand::Rng;
+
+use crate::game::{
+    card::Card,
+    engine::{
+        utils::IdString,
+        zones::{move_card, summon},
+    },
+    enums::{CardType, Zone},
+    event::GameEvent,
+    filter::CardFilter,
+    player::Player,
+    state::{GameState, PlayerId},
+};
+
+/// Carte présente dans le deck au début de la partie (posé par `Player::new`).
+/// Les cartes mélangées ensuite ne le portent pas (Steamcleaner).
+pub const STARTING_DECK_TAG: &str = "starting_deck";
+
+/// Emplacement d'une carte mélangée dans le deck. Le dessus du deck est la fin
+/// du `Vec` : la prochaine pioche fait un `pop`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DeckPosition {
+    #[default]
+    Random,
+    Top,
+    Bottom,
+}
+
+impl DeckPosition {
+    /// `"top"`, `"bottom"` ; toute autre valeur : au hasard.
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "top" => DeckPosition::Top,
+            "bottom" => DeckPosition::Bottom,
+            _ => DeckPosition::Random,
+        }
+    }
+}
+
+/// Mélange `card` (hors de toute zone : créée ou mise de côté) dans le deck de
+/// `player_id` ; renvoie son index dans le deck.
+pub fn shuffle_into_deck(state: &mut GameState, player_id: &PlayerId, card: Card, position: DeckPosition) -> usize {
+    let deck = &mut state.players.get_mut(player_id).unwrap().zones.deck;
+    let index = match position {
+        DeckPosition::Random => rand::thread_rng().gen_range(0..=deck.len()),
+        DeckPosition::Top => deck.len(),
+        DeckPosition::Bottom => 0,
+    };
+    println!("🔀 {} est mélangée dans le deck de {} ({:?})", card.name, player_id.id_string(), position);
+    let card_id = card.card_id.clone();
+    deck.insert(index, card);
+    state.event_queue.push_back(GameEvent::CardMoved { card_id, owner: *player_id, from: Zone::SetAside, to: Zone::Deck });
+    index
+}
+
+/// Carte du dessus du deck (la prochaine piochée), sans la retirer.
+pub fn peek_top(player: &Player) -> Option<&Card> {
+    player.zones.deck.last()
+}
+
+/// Révèle la carte du dessus du deck de `player_id` : elle reste en place.
+pub fn reveal_top(state: &GameState, player_id: &PlayerId) -> Option<Card> {
+    let top = peek_top(&state.players[player_id]).cloned();
+    match &top {
+        Some(card) => println!("👁️ {} révèle {} (dessus du deck)", player_id.id_string(), card.name),
+        None => println!("👁️ Deck vide : {} ne révèle rien", player_id.id_string()),
+    }
+    top
+}
+
+/// Invoque jusqu'à `amount` serviteurs du deck satisfaisant `filter`, tirés au hasard.
+/// S'arrête quand le board est plein ; renvoie le nombre de serviteurs invoqués.
+pub fn summon_from_deck(state: &mut GameState, player_id: &PlayerId, filter: &CardFilter, amount: usize) -> usize {
+    let mut filter = filter.clone().for_player(state, player_id);
+    filter.card_type.get_or_insert(CardType::Minion);
+    let mut summoned = 0;
+    while summoned < amount && state.players[player_id].has_room(&Zone::Board) {
+        let deck = &state.players[player_id].zones.deck;
+        let Some(index) = (0..deck.len())
+            .filter(|&i| filter.matches(&deck[i]))
+            .choose(&mut rand::thread_rng())
+        else {
+            break;
+        };
+        let card = state.players.get_mut(player_id).unwrap().zones.deck.remove(index);
+        println!("📤 {} invoque {} depuis son deck", player_id.id_string(), card.name);
+        let card_id = card.card_id.clone();
+        if summon(state, player_id, card).is_some() {
+            state.event_queue.push_back(GameEvent::CardMoved { card_id, owner: *player_id, from: Zone::Deck, to: Zone::Board });
+        }
+        summoned += 1;
+    }
+    summoned
+}
+
+/// Index des cartes du deck qui n'y étaient pas au début de la partie.
+pub fn not_starting_in_deck(player: &Player) -> Vec<usize> {
+    (0..player.zones.deck.len())
+        .filter(|&i| !player.zones.deck[i].tags.contains_key(STARTING_DECK_TAG))
+        .collect()
+}
+
+/// Détruit les cartes du deck de `player_id` qui n'y étaient pas au départ
+/// (elles rejoignent le cimetière). Renvoie le nombre de cartes détruites.
+pub fn destroy_not_starting_in_deck(state: &mut GameState, player_id: &PlayerId) -> usize {
+    let indices = not_starting_in_deck(&state.players[player_id]);
+    for &i in indices.iter().rev() {
+        println!("🧽 {} est détruite dans le deck de {}", state.players[player_id].zones.deck[i].name, player_id.id_string());
+        let _ = move_card(state, player_id, Zone::Deck, i, Zone::Graveyard, None);
+    }
+    indices.len()
+}
@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+
+use rand::Rng;
+use serde_json::{json, Value};
+
+use crate::{
+    data::card_template::CardTemplate,
+    game::{
+        card::Card,
+        effects::{apply_effect, Effect},
+        engine::{
+            choose::{Choice, Chooser},
+            utils::IdString,
+            zones::{add_to_hand, summon},
+        },
+        enums::{CardClass, CardType},
+        filter::CardFilter,
+        state::{GameState, PlayerId},
+    },
+};
+
+/// Nombre de cartes proposées par une Découverte.
+pub const DISCOVER_OPTIONS: usize = 3;
+/// Poids des cartes de la classe du joueur face aux neutres.
+pub const CLASS_WEIGHT: u32 = 4;
+/// Option « choix mystère » de `discover_mystery` : une carte aléatoire du même pool.
+pub const MYSTERY_CARD_ID: &str = "DISCOVER_MYSTERY";
+
+/// Découvre une carte : trois options distinctes, choix du `Chooser`, puis la carte
+/// va en main, est lancée ou invoquée selon `destination` ("hand" par défaut).
+pub fn discover(
+    state: &mut GameState,
+    player_id: &PlayerId,
+    source_pool: Option<&Value>,
+    destination: Option<&str>,
+    mystery: bool,
+    chooser: &dyn Chooser,
+    card_templates: &HashMap<String, CardTemplate>,
+) {
+    let candidates = discover_candidates(state, player_id, source_pool, card_templates);
+    let mut options = pick_distinct(&candidates, DISCOVER_OPTIONS);
+    if options.is_empty() {
+        println!("🔎 {} : aucune carte à découvrir", player_id.id_string());
+        return;
+    }
+    if mystery {
+        options.push(mystery_card());
+    }
+    println!(
+        "🔎 {} découvre parmi : {}",
+        player_id.id_string(),
+        options.iter().map(|c| c.name.as_str()).collect::<Vec<_>>().join(", ")
+    );
+
+    let picked_id = match chooser.choose(state, Choice::Discover { options: options.clone() }) {
+        Choice::Discover { options: picked } | Choice::ChooseOne { options: picked } => {
+            picked.first().map(|c| c.card_id.clone())
+        }
+        _ => None,
+    };
+    let mut picked = picked_id
+        .and_then(|id| options.iter().find(|c| c.card_id == id).cloned())
+        .unwrap_or_else(|| options[0].clone());
+
+    // Choix mystère : une carte aléatoire du pool, sans la voir
+    if picked.card_id == MYSTERY_CARD_ID {
+        let idx = rand::thread_rng().gen_range(0..candidates.len());
+        picked = candidates[idx].0.clone();
+        println!("❓ Le choix mystère révèle {}", picked.name);
+    } else {
+        println!("✨ {} choisit {}", player_id.id_string(), picked.name);
+    }
+
+    resolve_discovered(state, player_id, picked, destination, chooser, card_templates);
+}
+
+/// Pool de Découverte avec le poids de chaque carte.
+pub fn discover_candidates(
+    state: &GameState,
+    player_id: &PlayerId,
+    source_pool: Option<&Value>,
+    card_templates: &HashMap<String, CardTemplate>,
+) -> Vec<(Card, u32)> {
+    let pool = source_pool.cloned().unwrap_or(Value::Null);
+
+    // « une copie d'une carte de la main adverse » : le pool est la main adverse
+    if pool.get("zone").and_then(|z| z.as_str()) == Some("opponent_hand") {
+        return state.players[&player_id.opponent()]
+            .zones
+            .hand
+            .iter()
+            .map(|c| (c.clone(), 1))
+            .collect();
+    }
+
+    let class = state.players[player_id].stats.class.clone();
+    let other_class = pool.get("card_class").and_then(|v| v.as_str()) == Some("other");
+    let mut candidates: Vec<(Card, u32)> = card_templates
+        .values()
+        .filter(|t| t.collectible == Some(true))
+        .filter(|t| !matches!(t.card_type, CardType::HeroPower | CardType::Enchantment))
+        .filter_map(|t| {
+            let weight = if other_class {
+                (t.card_class != class && t.card_class != CardClass::Neutral).then_some(1)
+            } else if t.card_class == class {
+                Some(CLASS_WEIGHT)
+            } else if t.card_class == CardClass::Neutral {
+                Some(1)
+            } else {
+                None
+            }?;
+            pool_matches(t, &pool).then(|| (t.to_card(), weight))
+        })
+        .collect();
+    // ordre stable : le hasard ne vient que du tirage
+    candidates.sort_by(|a, b| a.0.card_id.cmp(&b.0.card_id));
+    candidates
+}
+
+/// Critères du pool tels qu'écrits dans le JSON (`filter`/`source_pool`) ;
+/// la classe est déjà traitée par la pondération.
+fn pool_matches(template: &CardTemplate, pool: &Value) -> bool {
+    let mut filter = CardFilter::from_value(pool);
+    filter.class = None;
+    filter.matches(template)
+}
+
+/// Tirage pondéré sans remise de `n` cartes d'identifiants distincts.
+fn pick_distinct(candidates: &[(Card, u32)], n: usize) -> Vec<Card> {
+    let mut rng = rand::thread_rng();
+    let mut remaining: Vec<&(Card, u32)> = candidates.iter().collect();
+    let mut picked: Vec<Card> = Vec::new();
+    while picked.len() < n && !remaining.is_empty() {
+        let total: u32 = remaining.iter().map(|(_, w)| *w).sum();
+        let mut roll = rng.gen_range(0..total.max(1));
+        let idx = remaining
+            .iter()
+            .position(|(_, w)| {
+                if roll < *w {
+                    true
+                } else {
+                    roll -= *w;
+                    false
+                }
+            })
+            .unwrap_or(0);
+        let (card, _) = remaining.remove(idx);
+        remaining.retain(|(c, _)| c.card_id != card.card_id);
+        picked.push(card.clone());
+    }
+    picked
+}
+
+fn mystery_card() -> Card {
+    serde_json::from_value::<CardTemplate>(json!({
+        "card_id": MYSTERY_CARD_ID,
+        "card_name": "Choix mystère",
+        "card_class": "NEUTRAL",
+        "card_type": "SPELL",
+        "cost": 0
+    }))
+    .expect("template mystère valide")
+    .to_card()
+}
+
+fn resolve_discovered(
+    state: &mut GameState,
+    player_id: &PlayerId,
+    card: Card,
+    destination: Option<&str>,
+    chooser: &dyn Chooser,
+    card_templates: &HashMap<String, CardTemplate>,
+) {
+    match destination.unwrap_or("hand") {
+        "cast" => {
+            println!("{} lance {} (découverte)", player_id.id_string(), card.name);
+            for eff in card.effects.iter().filter(|e| {
+                !matches!(e, Effect::Overload { .. }) && !card.triggers.iter().any(|t| t.effect == **e)
+            }) {
+                apply_effect(state, player_id, eff, chooser, card_templates);
+            }
+        }
+        "summon" => {
+            println!("{} invoque {} (découverte)", player_id.id_string(), card.name);
+            summon(state, player_id, card);
+        }
+        _ => {
+            add_to_hand(state, player_id, card);
+        }
+    }
+}
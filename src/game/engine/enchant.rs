@@ -0,0 +1,76 @@
+use crate::game::{
+    card::{Card, Enchantment},
+    engine::{choose::Chooser, targeting::resolve_minion_targets, utils::IdString},
+    state::{GameState, PlayerId},
+    targets::Target,
+};
+
+/// Carte dont l'effet en cours se résout : le mort d'un Râle d'agonie, sinon le serviteur source.
+pub fn source_card_id(state: &GameState, player_id: &PlayerId) -> Option<String> {
+    let player = &state.players[player_id];
+    match player.deathrattle_source {
+        Some(i) => player.zones.graveyard.get(i).map(|c| c.card_id.clone()),
+        None => player.source_position.and_then(|i| player.zones.board.get(i)).map(|c| c.card_id.clone()),
+    }
+}
+
+/// Cartes de la main de `player_id` visées par `target` (`HandMinion` : les serviteurs).
+fn hand_targets(state: &GameState, player_id: &PlayerId, target: &Target) -> Vec<usize> {
+    let Target::HandMinion { card_type, .. } = target else {
+        return Vec::new();
+    };
+    let hand = &state.players[player_id].zones.hand;
+    (0..hand.len()).filter(|&i| hand[i].card_type == *card_type).collect()
+}
+
+/// Enchante les cartes visées : serviteurs du board (au hasard si `random`) ou cartes en main.
+/// Renvoie le nombre de cartes enchantées.
+pub fn enchant_targets(
+    state: &mut GameState,
+    player_id: &PlayerId,
+    target: &Target,
+    random: bool,
+    enchantment: Enchantment,
+    chooser: &dyn Chooser,
+) -> usize {
+    let mut enchanted: Vec<&mut Card> = Vec::new();
+    match target {
+        Target::HandMinion { .. } => {
+            let indices = hand_targets(state, player_id, target);
+            let hand = &mut state.players.get_mut(player_id).unwrap().zones.hand;
+            enchanted.extend(hand.iter_mut().enumerate().filter(|(i, _)| indices.contains(i)).map(|(_, c)| c));
+        }
+        _ => {
+            let hits = resolve_minion_targets(state, player_id, target, None, random, chooser);
+            for (pid, player) in state.players.iter_mut() {
+                enchanted.extend(
+                    player
+                        .zones
+                        .board
+                        .iter_mut()
+                        .enumerate()
+                        .filter(|(i, _)| hits.contains(&(*pid, *i)))
+                        .map(|(_, c)| c),
+                );
+            }
+        }
+    }
+    for card in enchanted.iter_mut() {
+        println!("✨ {} est enchanté ({:?}, {} déclencheur(s))", card.name, enchantment.keywords, enchantment.triggers.len());
+        card.enchant(enchantment.clone());
+    }
+    enchanted.len()
+}
+
+/// Réduit au silence les serviteurs visés ; les auras de voisinage sont recalculées.
+pub fn silence_targets(state: &mut GameState, player_id: &PlayerId, target: &Target, chooser: &dyn Chooser) -> usize {
+    let hits = resolve_minion_targets(state, player_id, target, None, false, chooser);
+    for (pid, i) in &hits {
+        let player = state.players.get_mut(pid).unwrap();
+        let minion = &mut player.zones.board[*i];
+        minion.silence();
+        println!("🤫 {} ({}) est réduit au silence", minion.name, pid.id_string());
+        player.refresh_adjacency_auras();
+    }
+    hits.len()
+}
@@ -1,65 +1,163 @@
-use crate::game::{
-    event::GameEvent,
-    state::{GameState, PlayerId},
-};
-use crate::game::engine::choose::RandomChooser;
-use crate::game::effects::{apply_effect, Effect};
-use std::collections::HashMap;
-
-pub fn dispatch_events(state: &mut GameState) {
-    let mut chooser = RandomChooser;
-    let card_templates: HashMap<String, crate::data::card_template::CardTemplate> = HashMap::new();
-
-    // Limiteur d'événements uniquement en debug (pas en release)
-    #[cfg(debug_assertions)]
-    const MAX_EVENTS_DEBUG: usize = 50;
-    #[cfg(debug_assertions)]
-    let mut guard = 0usize;
-
-    while let Some(event) = state.event_queue.pop_front() {
-        #[cfg(debug_assertions)]
-        {
-            guard += 1;
-            println!("event #{}: {:?}", guard, event);
-            if guard > MAX_EVENTS_DEBUG {
-                println!("⚠️ guard dispatch_events STOP ({MAX_EVENTS_DEBUG} événements)");
-                break;
-            }
-            // Affiche les triggers de la carte jouée — utile en debug
-            if let GameEvent::CardPlayed { ref card_id, .. } = event {
-                for (&pid, pl) in &state.players {
-                    for c in &pl.zones.board {
-                        if c.card_id == *card_id {
-                            println!(
-                                "DBG: on-board {} (owner={:?}) has {} trigger(s): {:?}",
-                                c.name,
-                                pid,
-                                c.triggers.len(),
-                                c.triggers.iter().map(|t| &t.when).collect::<Vec<_>>()
-                            );
-                        }
-                    }
-                }
-            }
-        }
-
-        // 1) Collecte (emprunts immuables uniquement)
-        let mut pending: Vec<(PlayerId, Effect)> = Vec::new();
-        for (&owner_id, player) in &state.players {
-            for card in &player.zones.board {
-                for trig in &card.triggers {
-                    if trig.matches(&event, owner_id, &card.card_id) {
-                        #[cfg(debug_assertions)]
-                        println!("➡️  Trigger {:?} sur {}", trig.when, card.name);
-                        pending.push((owner_id, trig.effect.clone()));
-                    }
-                }
-            }
-        }
-
-        // 2) Application (emprunt mutable ensuite)
-        for (owner_id, eff) in pending {
-            apply_effect(state, &owner_id, &eff, &mut chooser, &card_templates);
-        }
-    }
-}
+use crate::game::{
+    event::GameEvent,
+    history::Character,
+    state::{GameState, PlayerId},
+};
+use crate::game::engine::choose::Chooser;
+use crate::game::engine::utils::IdString;
+use crate::game::effects::{apply_effect, Effect};
+use crate::game::triggers::Trigger;
+
+/// Carte dont le déclencheur produit un effet : elle est le « self » de sa résolution.
+enum Origin {
+    /// Serviteur du board (retrouvé par son id au moment de la résolution)
+    Board(String),
+    /// Râle d'agonie : emplacement des invocations, carte morte au cimetière
+    Deathrattle(usize, usize),
+    Other,
+}
+
+type PendingEffect = (PlayerId, Effect, Origin);
+
+/// Résout la file d'événements ; les choix des effets déclenchés (Découverte,
+/// Choose One, cibles…) passent par `chooser`.
+pub fn dispatch_events(state: &mut GameState, chooser: &dyn Chooser) {
+    let card_templates = std::rc::Rc::clone(&state.card_templates);
+
+    // Limiteur d'événements uniquement en debug (pas en release)
+    #[cfg(debug_assertions)]
+    const MAX_EVENTS_DEBUG: usize = 50;
+    #[cfg(debug_assertions)]
+    let mut guard = 0usize;
+
+    while let Some(event) = state.event_queue.pop_front() {
+        #[cfg(debug_assertions)]
+        {
+            guard += 1;
+            println!("event #{}: {:?}", guard, event);
+            if guard > MAX_EVENTS_DEBUG {
+                println!("⚠️ guard dispatch_events STOP ({MAX_EVENTS_DEBUG} événements)");
+                break;
+            }
+            // Affiche les triggers de la carte jouée — utile en debug
+            if let GameEvent::CardPlayed { ref card_id, .. } = event {
+                for (&pid, pl) in &state.players {
+                    for c in &pl.zones.board {
+                        if c.card_id == *card_id {
+                            println!(
+                                "DBG: on-board {} (owner={:?}) has {} trigger(s): {:?}",
+                                c.name,
+                                pid,
+                                c.triggers.len(),
+                                c.triggers.iter().map(|t| &t.when).collect::<Vec<_>>()
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        // Les dégâts aux héros passent tous par cet événement
+        if let GameEvent::HeroDamaged { player, amount, dealer } = &event {
+            state.history.record_damage(Character::Hero(*player), *dealer, *amount);
+        }
+
+        // 1) Collecte (emprunts immuables uniquement)
+        let mut pending: Vec<PendingEffect> = Vec::new();
+        for (&owner_id, player) in &state.players {
+            for card in &player.zones.board {
+                // un serviteur vivant ne déclenche pas son Râle d'agonie (copie homonyme morte)
+                for trig in card.triggers.iter().filter(|t| t.when != Trigger::Deathrattle) {
+                    if trig.matches(&event, owner_id, &card.card_id) {
+                        #[cfg(debug_assertions)]
+                        println!("➡️  Trigger {:?} sur {}", trig.when, card.name);
+                        pending.push((owner_id, trig.effect.clone(), Origin::Board(card.card_id.clone())));
+                    }
+                }
+            }
+            // Carte Héros jouée et arme équipée : leurs déclencheurs restent actifs
+            for card in player.stats.hero.iter().chain(player.stats.weapon.iter()) {
+                for trig in &card.triggers {
+                    if trig.matches(&event, owner_id, &card.card_id) {
+                        pending.push((owner_id, trig.effect.clone(), Origin::Other));
+                    }
+                }
+            }
+        }
+
+        // 1a) Râle d'agonie : la carte morte est au cimetière, ses invocations prennent sa place
+        if let GameEvent::MinionDied { card_id, owner, position } = &event {
+            let graveyard = &state.players[owner].zones.graveyard;
+            let dead = graveyard.iter().rposition(|c| c.card_id == *card_id);
+            for (index, trig) in dead
+                .filter(|&i| !graveyard[i].status.silenced)
+                .into_iter()
+                .flat_map(|i| graveyard[i].triggers.iter().map(move |t| (i, t)))
+            {
+                if trig.matches(&event, *owner, card_id) {
+                    pending.push((*owner, trig.effect.clone(), Origin::Deathrattle(*position, index)));
+                }
+            }
+        }
+
+        // 1b) Secrets : uniquement pendant le tour adverse
+        let mut revealed: Vec<(PlayerId, String)> = Vec::new();
+        for (&owner_id, player) in &state.players {
+            if owner_id == state.current_player {
+                continue;
+            }
+            for secret in &player.zones.secrets {
+                for trig in &secret.triggers {
+                    if trig.matches(&event, owner_id, &secret.card_id) {
+                        pending.push((owner_id, trig.effect.clone(), Origin::Other));
+                        if !revealed.iter().any(|(o, id)| *o == owner_id && *id == secret.card_id) {
+                            revealed.push((owner_id, secret.card_id.clone()));
+                        }
+                    }
+                }
+            }
+        }
+        for (owner_id, secret_id) in revealed {
+            reveal_secret(state, &owner_id, &secret_id);
+        }
+
+        // 2) Application (emprunt mutable ensuite)
+        for (owner_id, eff, origin) in pending {
+            match origin {
+                Origin::Board(card_id) => {
+                    // un serviteur retiré entre-temps garde la source courante
+                    let p = state.players.get_mut(&owner_id).unwrap();
+                    let saved = p.source_position;
+                    if let Some(index) = p.zones.board.iter().position(|c| c.card_id == card_id) {
+                        p.source_position = Some(index);
+                    }
+                    apply_effect(state, &owner_id, &eff, chooser, &card_templates);
+                    state.players.get_mut(&owner_id).unwrap().source_position = saved;
+                }
+                Origin::Deathrattle(pos, dead) => {
+                    // Râle d'agonie pendant un autre effet : on restaure les emplacements ensuite
+                    let p = state.players.get_mut(&owner_id).unwrap();
+                    let saved = (
+                        p.source_position.take(),
+                        p.summon_position.replace(pos),
+                        p.deathrattle_source.replace(dead),
+                    );
+                    apply_effect(state, &owner_id, &eff, chooser, &card_templates);
+                    let p = state.players.get_mut(&owner_id).unwrap();
+                    (p.source_position, p.summon_position, p.deathrattle_source) = saved;
+                }
+                Origin::Other => apply_effect(state, &owner_id, &eff, chooser, &card_templates),
+            }
+        }
+    }
+}
+
+/// Révèle un Secret déclenché : il quitte la zone Secret pour le cimetière.
+fn reveal_secret(state: &mut GameState, owner_id: &PlayerId, secret_id: &str) {
+    let player = state.players.get_mut(owner_id).unwrap();
+    if let Some(pos) = player.zones.secrets.iter().position(|s| s.card_id == secret_id) {
+        let secret = player.zones.secrets.remove(pos);
+        println!("🔓 Secret révélé ({}) : {}", owner_id.id_string(), secret.name);
+        player.zones.graveyard.push(secret);
+    }
+}
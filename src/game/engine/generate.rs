@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+
+use rand::seq::{IteratorRandom, SliceRandom};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    data::card_template::CardTemplate,
+    game::{
+        card::Card,
+        engine::{utils::IdString, zones::add_to_hand},
+        enums::{CardType, Zone},
+        filter::CardFilter,
+        state::{GameState, PlayerId},
+    },
+};
+
+/// Retouches appliquées aux cartes générées : coût fixé ou modifié, bonus de stats.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CardModification {
+    /// Nouveau coût imprimé
+    pub cost: Option<u8>,
+    /// Réduction (négative) ou hausse permanente du coût
+    pub cost_change: i32,
+    pub attack: i32,
+    pub health: i32,
+}
+
+impl CardModification {
+    pub fn apply(&self, card: &mut Card) {
+        if let Some(cost) = self.cost {
+            card.cost = cost;
+        }
+        card.status.cost_modifier += self.cost_change;
+        if card.card_type == CardType::Minion {
+            card.status.attack_modifiers += self.attack;
+            card.health = card.health.map(|h| h + self.health);
+            card.max_health = card.max_health.map(|h| h + self.health);
+            card.status.current_health = card.status.current_health.map(|h| h + self.health);
+        }
+    }
+}
+
+/// Cartes à collectionner satisfaisant `filter`, triées par identifiant
+/// (le hasard ne vient que du tirage).
+pub fn random_pool<'a>(card_templates: &'a HashMap<String, CardTemplate>, filter: &CardFilter) -> Vec<&'a CardTemplate> {
+    let mut pool: Vec<_> = card_templates
+        .values()
+        .filter(|t| t.collectible == Some(true))
+        .filter(|t| !matches!(t.card_type, CardType::HeroPower | CardType::Enchantment))
+        .filter(|t| filter.matches(*t))
+        .collect();
+    pool.sort_by(|a, b| a.card_id.cmp(&b.card_id));
+    pool
+}
+
+/// Zone d'où l'on copie, vue de `player_id` : `hand`, `deck`, `board`,
+/// ou leurs variantes `opponent_*`.
+pub fn source_zone(player_id: &PlayerId, zone: &str) -> Option<(PlayerId, Zone)> {
+    let (owner, name) = match zone.strip_prefix("opponent_") {
+        Some(rest) => (player_id.opponent(), rest),
+        None => (*player_id, zone),
+    };
+    let zone = match name {
+        "hand" => Zone::Hand,
+        "deck" => Zone::Deck,
+        "board" => Zone::Board,
+        _ => return None,
+    };
+    Some((owner, zone))
+}
+
+/// Copies de cartes de `zone` : aux positions demandées (`leftmost`, `rightmost`),
+/// sinon `amount` cartes distinctes au hasard parmi celles satisfaisant `filter`.
+pub fn copies_from_zone(
+    state: &GameState,
+    player_id: &PlayerId,
+    zone: &str,
+    positions: &[String],
+    amount: usize,
+    filter: &CardFilter,
+) -> Vec<Card> {
+    let Some((owner, zone)) = source_zone(player_id, zone) else {
+        println!("[COPY] Zone « {} » non gérée", zone);
+        return Vec::new();
+    };
+    let cards = state.players[&owner].zone(&zone);
+    let filter = filter.clone().for_player(state, player_id);
+    let eligible: Vec<&Card> = cards.iter().filter(|c| !c.is_location() && filter.matches(*c)).collect();
+
+    if positions.is_empty() {
+        return eligible.into_iter().cloned().choose_multiple(&mut rand::thread_rng(), amount);
+    }
+    positions
+        .iter()
+        .filter_map(|p| match p.as_str() {
+            "leftmost" => eligible.first(),
+            "rightmost" => eligible.last(),
+            other => {
+                println!("[COPY] Position « {} » non gérée", other);
+                None
+            }
+        })
+        .map(|c| (*c).clone())
+        .collect()
+}
+
+/// Donne `cards` à `player_id`, retouches appliquées ; au-delà de `MAX_HAND`, elles
+/// sont brûlées. Renvoie le nombre de cartes arrivées en main.
+pub fn give_cards(
+    state: &mut GameState,
+    player_id: &PlayerId,
+    cards: Vec<Card>,
+    modification: Option<&CardModification>,
+) -> usize {
+    let mut added = 0;
+    for mut card in cards {
+        if let Some(m) = modification {
+            m.apply(&mut card);
+        }
+        println!("🃏 {} reçoit {}", player_id.id_string(), card.name);
+        if add_to_hand(state, player_id, card) {
+            added += 1;
+        }
+    }
+    added
+}
+
+/// `amount` cartes tirées au hasard (avec remise) dans le pool de `filter`.
+pub fn random_cards(
+    state: &GameState,
+    player_id: &PlayerId,
+    filter: &CardFilter,
+    amount: usize,
+    card_templates: &HashMap<String, CardTemplate>,
+) -> Vec<Card> {
+    let filter = filter.clone().for_player(state, player_id);
+    let pool = random_pool(card_templates, &filter);
+    if pool.is_empty() {
+        println!("[GENERATE] Aucune carte ne correspond au filtre {:?}", filter);
+        return Vec::new();
+    }
+    let mut rng = rand::thread_rng();
+    (0..amount).filter_map(|_| pool.choose(&mut rng)).map(|t| t.to_card()).collect()
+}
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+
+use rand::Rng;
+
+use crate::{
+    data::card_template::CardTemplate,
+    game::{
+        card::Card,
+        engine::{utils::IdString, zones::summon},
+        filter::CardFilter,
+        player::Player,
+        state::{GameState, PlayerId},
+    },
+};
+
+/// Rang de la mort d'un serviteur dans la partie (1 = premier mort, tous joueurs confondus).
+/// Seules les cartes mortes sur le board le portent : une carte brûlée ou un sort n'est pas « mort ».
+pub const DEATH_ORDER_TAG: &str = "death_order";
+
+/// Serviteurs de `player` morts pendant la partie, du premier au dernier mort.
+pub fn dead_minions(player: &Player) -> Vec<&Card> {
+    let mut dead: Vec<&Card> = player
+        .zones
+        .graveyard
+        .iter()
+        .filter(|c| c.tags.contains_key(DEATH_ORDER_TAG))
+        .collect();
+    dead.sort_by_key(|c| c.tags[DEATH_ORDER_TAG]);
+    dead
+}
+
+/// Cartes du cimetière de `player_id` satisfaisant `filter` (ordre d'arrivée).
+pub fn graveyard_query<'a>(state: &'a GameState, player_id: &PlayerId, filter: &CardFilter) -> Vec<&'a Card> {
+    let filter = filter.clone().for_player(state, player_id);
+    state.players[player_id]
+        .zones
+        .graveyard
+        .iter()
+        .filter(|c| filter.matches(*c))
+        .collect()
+}
+
+/// Nouvel exemplaire d'une carte, tel qu'imprimé : depuis son template si on le connaît,
+/// sinon la carte remise à zéro (PV pleins, modificateurs, enchantements et état effacés).
+pub fn fresh_copy(card: &Card, card_templates: &HashMap<String, CardTemplate>) -> Card {
+    if let Some(template) = card_templates.get(&card.card_id) {
+        return template.to_card();
+    }
+    let mut copy = card.clone();
+    copy.effects = copy.native_effects.clone();
+    for enchantment in std::mem::take(&mut copy.enchantments) {
+        copy.remove_kw(enchantment.keywords);
+        copy.triggers.retain(|t| !enchantment.triggers.contains(t));
+    }
+    copy.tags.clear();
+    copy.status.current_health = copy.max_health.or(copy.health);
+    copy.status.attack_modifiers = 0;
+    copy.status.health_modifiers = 0;
+    copy.status.silenced = false;
+    copy.status.frozen = false;
+    copy.status.has_attacked = false;
+    copy.status.just_played = true;
+    copy.status.attacks_this_turn = 0;
+    copy.status.cost_modifier = 0;
+    copy.status.aura_attack = 0;
+    copy
+}
+
+/// Ressuscite jusqu'à `amount` serviteurs alliés morts pendant la partie (chacun au plus une fois) :
+/// au hasard, ou le plus cher d'abord (`highest_cost`). Renvoie le nombre de serviteurs invoqués.
+pub fn resurrect(
+    state: &mut GameState,
+    player_id: &PlayerId,
+    amount: usize,
+    filter: Option<&CardFilter>,
+    highest_cost: bool,
+    card_templates: &HashMap<String, CardTemplate>,
+) -> usize {
+    let filter = filter.cloned().unwrap_or_default().for_player(state, player_id);
+    let mut pool: Vec<Card> = dead_minions(&state.players[player_id])
+        .into_iter()
+        .filter(|c| filter.matches(*c))
+        .cloned()
+        .collect();
+
+    let mut rng = rand::thread_rng();
+    let mut summoned = 0;
+    while summoned < amount && !pool.is_empty() {
+        let candidates: Vec<usize> = if highest_cost {
+            let max = pool.iter().map(|c| c.cost).max().unwrap_or(0);
+            (0..pool.len()).filter(|&i| pool[i].cost == max).collect()
+        } else {
+            (0..pool.len()).collect()
+        };
+        let dead = pool.remove(candidates[rng.gen_range(0..candidates.len())]);
+        println!("✨ {} ressuscite {}", player_id.id_string(), dead.name);
+        if summon(state, player_id, fresh_copy(&dead, card_templates)).is_none() {
+            break;
+        }
+        summoned += 1;
+    }
+    if summoned == 0 {
+        println!("✨ Aucun serviteur à ressusciter pour {}", player_id.id_string());
+    }
+    summoned
+}
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+
+use crate::{
+    data::card_template::CardTemplate,
+    game::{
+        effects::apply_effect,
+        engine::{choose::Chooser, utils::IdString},
+        state::{GameState, PlayerId},
+        targets::Target,
+    },
+};
+
+/// Tag posé sur un lieu activé : il se recharge au début du prochain tour de son contrôleur.
+pub const LOCATION_COOLDOWN_TAG: &str = "location_cooldown";
+
+/// Indices (sur le board de `pid`) des lieux activables ce tour-ci.
+pub fn ready_locations(state: &GameState, pid: &PlayerId) -> Vec<usize> {
+    state.players[pid]
+        .zones
+        .board
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| {
+            c.is_location()
+                && c.effective_health() > 0
+                && c.tags.get(LOCATION_COOLDOWN_TAG).copied().unwrap_or(0) == 0
+        })
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Active le lieu en position `board_index` : ses effets passent par `apply_effect`,
+/// il perd 1 de durabilité, entre en recharge et est détruit à 0.
+pub fn activate_location(
+    state: &mut GameState,
+    player_id: &PlayerId,
+    board_index: usize,
+    chooser: &dyn Chooser,
+    card_templates: &HashMap<String, CardTemplate>,
+) -> bool {
+    if !ready_locations(state, player_id).contains(&board_index) {
+        println!("⚠️ Aucun lieu activable en position {}", board_index);
+        return false;
+    }
+
+    let location = state.players[player_id].zones.board[board_index].clone();
+    println!(
+        "{} active le lieu {} (durabilité {})",
+        player_id.id_string(),
+        location.name,
+        location.effective_health()
+    );
+
+    // Recharge AVANT les effets (ils peuvent déplacer le board)
+    state.players.get_mut(player_id).unwrap().zones.board[board_index]
+        .tags
+        .insert(LOCATION_COOLDOWN_TAG.to_string(), 1);
+
+    // Effets sans déclencheur = texte d'activation du lieu
+    for eff in location
+        .effects
+        .iter()
+        .filter(|e| !location.triggers.iter().any(|t| t.effect == **e))
+    {
+        apply_effect(state, player_id, eff, chooser, card_templates);
+    }
+
+    // Perte de durabilité APRÈS les effets : le nettoyage des morts ne doit pas
+    // retirer le lieu sans passer par le cimetière
+    let player = state.players.get_mut(player_id).unwrap();
+    if let Some(pos) = player.zones.board.iter().position(|c| {
+        c.card_id == location.card_id && c.is_location() && c.tags.contains_key(LOCATION_COOLDOWN_TAG)
+    }) {
+        let loc = &mut player.zones.board[pos];
+        loc.status.current_health = Some(loc.effective_health() - 1);
+        if loc.effective_health() <= 0 {
+            let destroyed = player.remove_from_board(pos);
+            println!("🏚️ {} est détruit (durabilité épuisée)", destroyed.name);
+            player.zones.graveyard.push(destroyed);
+        }
+    }
+    true
+}
+
+/// Lieux visés par une cible `*_location`, sous forme (contrôleur, index sur le board).
+pub fn location_targets(state: &GameState, owner: &PlayerId, target: &Target) -> Vec<(PlayerId, usize)> {
+    let sides = match target {
+        Target::FriendlyLocation => vec![*owner],
+        Target::EnemyLocation => vec![owner.opponent()],
+        Target::AnyLocation => vec![*owner, owner.opponent()],
+        _ => vec![],
+    };
+    sides
+        .into_iter()
+        .flat_map(|pid| {
+            state.players[&pid]
+                .zones
+                .board
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| c.is_location())
+                .map(move |(i, _)| (pid, i))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
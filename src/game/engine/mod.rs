@@ -1,8 +1,21 @@
-pub mod attack;
-pub mod play_card;
-pub mod play_turn;
-pub mod utils;
-pub mod draw;
-pub mod choose;
-pub mod events;
-
+pub mod attack;
+pub mod play_card;
+pub mod play_turn;
+pub mod utils;
+pub mod draw;
+pub mod choose;
+pub mod choose_one;
+pub mod cost;
+pub mod deck;
+pub mod discover;
+pub mod enchant;
+pub mod events;
+pub mod generate;
+pub mod graveyard;
+pub mod location;
+pub mod replay;
+pub mod targeting;
+pub mod trade;
+pub mod zones;
+pub mod weapon;
+
@@ -1,115 +1,314 @@
-use crate::game::state::{GameState, PlayerId};
-use crate::game::enums::CardType;
-use crate::game::targets::Target;
-use crate::game::effects::{Effect, apply_effect};
-use crate::game::engine::utils::{minion_stats_string, IdString};
-use crate::game::triggers::Trigger;
-use crate::game::event::GameEvent;
-use crate::game::engine::events::dispatch_events;
-
-
-pub fn play_card_at_index(
-    state: &mut GameState,
-    player_id: &PlayerId,
-    hand_index: usize,
-    chooser: &dyn crate::game::engine::choose::Chooser,
-    card_templates: &std::collections::HashMap<String, crate::data::card_template::CardTemplate>,
-) -> bool {
-    // Attention, on NE récupère plus player tout de suite
-    if let Some(player) = state.players.get_mut(player_id) {
-        if hand_index >= player.zones.hand.len() {
-            println!("⚠️ Index {} invalide pour la main du joueur.", hand_index);
-            return false;
-        }
-    }
-
-    // On retire la carte (besoin de réemprunter plus tard)
-    let card = {
-        let player = state.players.get_mut(player_id).unwrap();
-        player.zones.hand.remove(hand_index)
-    };
-
-    // On paie le mana avant tout
-    let can_play = {
-        let player = state.players.get_mut(player_id).unwrap();
-        player.stats.mana.spend(card.cost)
-    };
-
-    if can_play {
-        match card.card_type {
-            CardType::Weapon => {
-                let player = state.players.get_mut(player_id).unwrap();
-                if let Some(old_weapon) = player.stats.weapon.replace(card.clone()) {
-                    player.zones.graveyard.push(old_weapon);
-                    println!(
-                        "{} brise son ancienne arme et équipe {} (ATK {}, DUR {})",
-                        player.id_string(),
-                        card.name,
-                        card.attack.unwrap_or(0),
-                        card.health.unwrap_or(0)
-                    );
-                } else {
-                    println!(
-                        "{} équipe l'arme {} (ATK {}, DUR {})",
-                        player.id_string(),
-                        card.name,
-                        card.attack.unwrap_or(0),
-                        card.health.unwrap_or(0)
-                    );
-                }
-                true
-            }
-            CardType::Minion => {
-                println!(
-                    "{} joue la carte: {}",
-                    player_id.id_string(),
-                    minion_stats_string(&card)
-                );
-
-                // 1) On place D’ABORD le serviteur sur le board
-                //    (pour que dispatch_events voie ses triggers)
-                let played_id: String;
-                {
-                    let mut played = card;              // carte retirée de la main
-                    played.status.just_played = true;
-                    played_id = played.card_id.clone();
-
-                    let player = state.players.get_mut(player_id).unwrap();
-                    player.zones.board.push(played);
-
-                    // Petit debug: combien de triggers sur cette carte ?
-                    let trig_count = player.zones.board.last().map(|c| c.triggers.len()).unwrap_or(0);
-                    println!("·· played {} with {} trigger(s)", played_id, trig_count);
-                } // <-- fin d’emprunt de `player`, on peut ré-emprunter `state` ensuite
-
-                // 2) On pousse l’événement "CardPlayed" puis on le traite
-                state.event_queue.push_back(GameEvent::CardPlayed {
-                    card_id: played_id,
-                    owner: *player_id,
-                });
-                dispatch_events(state);
-
-                true
-            }
-
-            _ => {
-                let player = state.players.get_mut(player_id).unwrap();
-                println!(
-                    "{} joue une carte non gérée: {}",
-                    player.id_string(),
-                    card.name
-                );
-                true
-            }
-        }
-    } else {
-        // Pas assez de mana → remettre la carte
-        let player = state.players.get_mut(player_id).unwrap();
-        println!(
-            "❌ Pas assez de mana pour jouer {} (coût {})",
-            card.name, card.cost
-        );
-        player.zones.hand.insert(hand_index, card);
-        false
-    }
-}
+use crate::game::state::{GameState, PlayerId};
+use crate::game::enums::{CardType, Zone};
+use crate::game::targets::Target;
+use crate::game::effects::{Effect, apply_effect};
+use crate::game::engine::utils::{minion_stats_string, IdString};
+use crate::game::triggers::Trigger;
+use crate::game::event::GameEvent;
+use crate::game::engine::events::dispatch_events;
+use crate::game::engine::cost::{consume_cost_modifiers, effective_cost};
+use crate::game::engine::weapon::equip_weapon;
+use crate::game::engine::choose::Choice;
+use crate::game::card::Card;
+use crate::game::keywords::Keywords;
+
+
+/// Emplacement choisi par le `Chooser` pour un serviteur ou un lieu de la main
+/// (`None` pour les autres cartes : elles n'occupent pas le board).
+pub fn choose_board_position(
+    state: &GameState,
+    player_id: &PlayerId,
+    hand_index: usize,
+    chooser: &dyn crate::game::engine::choose::Chooser,
+) -> Option<usize> {
+    let player = &state.players[player_id];
+    let card = player.zones.hand.get(hand_index)?;
+    if !matches!(card.card_type, CardType::Minion | CardType::Location) {
+        return None;
+    }
+    let board_len = player.zones.board.len();
+    match chooser.choose(state, Choice::BoardPosition { hand_index, board_len }) {
+        Choice::BoardPositionIndex(pos) => Some(pos.min(board_len)),
+        _ => None,
+    }
+}
+
+/// Joue la carte `hand_index` ; un serviteur ou un lieu arrive tout à droite du board.
+pub fn play_card_at_index(
+    state: &mut GameState,
+    player_id: &PlayerId,
+    hand_index: usize,
+    chooser: &dyn crate::game::engine::choose::Chooser,
+    card_templates: &std::collections::HashMap<String, crate::data::card_template::CardTemplate>,
+) -> bool {
+    play_card_at_position(state, player_id, hand_index, None, chooser, card_templates)
+}
+
+/// Joue la carte `hand_index` ; un serviteur ou un lieu est posé à `position`
+/// sur le board (bornée, à droite si `None`).
+pub fn play_card_at_position(
+    state: &mut GameState,
+    player_id: &PlayerId,
+    hand_index: usize,
+    position: Option<usize>,
+    chooser: &dyn crate::game::engine::choose::Chooser,
+    card_templates: &std::collections::HashMap<String, crate::data::card_template::CardTemplate>,
+) -> bool {
+    // Attention, on NE récupère plus player tout de suite
+    if let Some(player) = state.players.get_mut(player_id) {
+        if hand_index >= player.zones.hand.len() {
+            println!("⚠️ Index {} invalide pour la main du joueur.", hand_index);
+            return false;
+        }
+    }
+
+    if !can_play_card(state, player_id, &state.players[player_id].zones.hand[hand_index]) {
+        println!("❌ {} ne peut pas être jouée maintenant", state.players[player_id].zones.hand[hand_index].name);
+        return false;
+    }
+
+    // Coût effectif calculé tant que la carte est encore en main
+    let cost = effective_cost(state, player_id, &state.players[player_id].zones.hand[hand_index]);
+
+    // Position en main et séquence du tour, figées avant de retirer la carte
+    let (outcast, combo) = {
+        let player = &state.players[player_id];
+        let last = player.zones.hand.len() - 1;
+        (hand_index == 0 || hand_index == last, player.cards_played_this_turn > 0)
+    };
+
+    // On retire la carte (besoin de réemprunter plus tard)
+    let card = {
+        let player = state.players.get_mut(player_id).unwrap();
+        player.zones.hand.remove(hand_index)
+    };
+
+    // On paie le mana avant tout
+    let can_play = {
+        let player = state.players.get_mut(player_id).unwrap();
+        player.stats.mana.spend(cost)
+    };
+
+    if can_play {
+        consume_cost_modifiers(state, player_id, &card);
+        state.players.get_mut(player_id).unwrap().cards_played_this_turn += 1;
+        state.history.record_card_played(player_id, &card);
+
+        // Surcharge : s'accumule dès que la carte est jouée, quel que soit son type
+        let overload: i32 = card
+            .effects
+            .iter()
+            .filter_map(|e| match e {
+                Effect::Overload { amount } => *amount,
+                _ => None,
+            })
+            .sum();
+        if overload > 0 {
+            state.players.get_mut(player_id).unwrap().add_overload(overload as u8);
+        }
+        match card.card_type {
+            CardType::Weapon => {
+                // L'ancienne arme est détruite (Râle d'agonie) avant que la nouvelle n'arrive
+                let played_id = card.card_id.clone();
+                equip_weapon(state, player_id, card, chooser, card_templates);
+
+                // Cri de guerre de l'arme via ses déclencheurs
+                push_card_played(state, player_id, played_id, combo, outcast);
+                dispatch_events(state, chooser);
+                true
+            }
+            CardType::Minion => {
+                println!(
+                    "{} joue la carte: {}",
+                    player_id.id_string(),
+                    minion_stats_string(&card)
+                );
+
+                // 1) On place D’ABORD le serviteur sur le board
+                //    (pour que dispatch_events voie ses triggers)
+                let played_id: String;
+                {
+                    let mut played = card;              // carte retirée de la main
+                    played.status.just_played = true;
+                    played_id = played.card_id.clone();
+
+                    let player = state.players.get_mut(player_id).unwrap();
+                    let index = player.place_on_board(played, position);
+                    // Cri de guerre : « self » est ce serviteur, ses invocations à sa droite
+                    player.source_position = Some(index);
+                    player.summon_position = Some(index + 1);
+
+                    // Petit debug: combien de triggers sur cette carte ?
+                    let trig_count = player.zones.board[index].triggers.len();
+                    println!("·· played {} at position {} with {} trigger(s)", played_id, index, trig_count);
+                } // <-- fin d’emprunt de `player`, on peut ré-emprunter `state` ensuite
+
+                // 2) On pousse l’événement "CardPlayed" puis on le traite
+                push_card_played(state, player_id, played_id.clone(), combo, outcast);
+                state.event_queue.push_back(GameEvent::MinionPlayed {
+                    card_id: played_id,
+                    owner: *player_id,
+                });
+                dispatch_events(state, chooser);
+
+                let player = state.players.get_mut(player_id).unwrap();
+                player.source_position = None;
+                player.summon_position = None;
+                true
+            }
+
+            CardType::Location => {
+                // Un lieu occupe un emplacement du board ; sa durabilité est sa « vie »
+                let played_id = card.card_id.clone();
+                println!(
+                    "{} pose le lieu {} (durabilité {})",
+                    player_id.id_string(),
+                    card.name,
+                    card.effective_health()
+                );
+                state.players.get_mut(player_id).unwrap().place_on_board(card, position);
+
+                push_card_played(state, player_id, played_id, combo, outcast);
+                dispatch_events(state, chooser);
+                true
+            }
+
+            CardType::Hero => {
+                // Le nouveau héros garde les PV actuels : il apporte son Armure,
+                // remplace le pouvoir héroïque et peut attaquer dès ce tour
+                let played_id = card.card_id.clone();
+                let hero_power = card.hero_power.as_ref().and_then(|id| {
+                    let hp = card_templates.get(id).map(|t| t.to_card());
+                    if hp.is_none() {
+                        println!("⚠️ Pouvoir héroïque {} introuvable", id);
+                    }
+                    hp
+                });
+                {
+                    let player = state.players.get_mut(player_id).unwrap();
+                    println!("{} devient {}", player.id_string(), card.name);
+                    if let Some(armor) = card.armor {
+                        player.gain_armor(armor);
+                    }
+                    if let Some(hp) = hero_power {
+                        println!("Nouveau pouvoir héroïque : {}", hp.name);
+                        player.stats.hero_power = Some(hp);
+                    }
+                    player.stats.hero_attack += card.attack.unwrap_or(0);
+                    player.stats.hero = Some(card);
+                }
+
+                // Le Cri de guerre se résout via les déclencheurs de la carte Héros
+                push_card_played(state, player_id, played_id, combo, outcast);
+                dispatch_events(state, chooser);
+                true
+            }
+
+            CardType::Spell => {
+                state.history.record_spell_cast(player_id, &card, cost);
+                // Le sort est annoncé avant de se résoudre : les Secrets adverses réagissent ici
+                state.event_queue.push_back(GameEvent::SpellCast {
+                    card_id: card.card_id.clone(),
+                    owner: *player_id,
+                });
+                dispatch_events(state, chooser);
+
+                if card.has_kw(Keywords::SECRET) {
+                    // Le nom du Secret n'est pas révélé à l'adversaire
+                    let player = state.players.get_mut(player_id).unwrap();
+                    player.zones.secrets.push(card.clone());
+                    println!("{} pose un Secret ({} actif(s))", player_id.id_string(), player.zones.secrets.len());
+                } else {
+                    println!("{} lance le sort: {}", player_id.id_string(), card.name);
+                    resolve_spell_text(state, player_id, &card, combo, outcast, chooser, card_templates);
+                    // Un sort lancé finit au cimetière
+                    state.players.get_mut(player_id).unwrap().zones.graveyard.push(card.clone());
+                }
+
+                state.event_queue.push_back(GameEvent::CardPlayed {
+                    card_id: card.card_id.clone(),
+                    owner: *player_id,
+                });
+                dispatch_events(state, chooser);
+                true
+            }
+
+            _ => {
+                let player = state.players.get_mut(player_id).unwrap();
+                println!(
+                    "{} joue une carte non gérée: {}",
+                    player.id_string(),
+                    card.name
+                );
+                true
+            }
+        }
+    } else {
+        // Pas assez de mana → remettre la carte
+        let player = state.players.get_mut(player_id).unwrap();
+        println!(
+            "❌ Pas assez de mana pour jouer {} (coût {})",
+            card.name, cost
+        );
+        player.zones.hand.insert(hand_index, card);
+        false
+    }
+}
+
+/// Résout le texte d'un sort : ses effets sans déclencheur (la Surcharge est comptée
+/// à part), puis Combo / Outcast s'ils sont actifs.
+pub fn resolve_spell_text(
+    state: &mut GameState,
+    player_id: &PlayerId,
+    card: &Card,
+    combo: bool,
+    outcast: bool,
+    chooser: &dyn crate::game::engine::choose::Chooser,
+    card_templates: &std::collections::HashMap<String, crate::data::card_template::CardTemplate>,
+) {
+    state.history.begin_spell_resolution();
+    for eff in card.effects.iter().filter(|e| {
+        !matches!(e, Effect::Overload { .. })
+            && !card.triggers.iter().any(|t| t.effect == **e)
+    }) {
+        apply_effect(state, player_id, eff, chooser, card_templates);
+    }
+    for trig in card.triggers.iter().filter(|t| {
+        (t.when == Trigger::Combo && combo) || (t.when == Trigger::Outcast && outcast)
+    }) {
+        apply_effect(state, player_id, &trig.effect, chooser, card_templates);
+    }
+    state.history.end_spell_resolution();
+}
+
+/// `CardPlayed`, suivi de Combo (une autre carte jouée avant ce tour-ci) et
+/// d'Outcast (carte jouée depuis une extrémité de la main).
+fn push_card_played(state: &mut GameState, player_id: &PlayerId, card_id: String, combo: bool, outcast: bool) {
+    state.event_queue.push_back(GameEvent::CardPlayed {
+        card_id: card_id.clone(),
+        owner: *player_id,
+    });
+    if combo {
+        state.event_queue.push_back(GameEvent::ComboActivated {
+            card_id: card_id.clone(),
+            owner: *player_id,
+        });
+    }
+    if outcast {
+        state.event_queue.push_back(GameEvent::OutcastActivated {
+            card_id,
+            owner: *player_id,
+        });
+    }
+}
+
+/// Contraintes de zone indépendantes du mana (Secret déjà actif, zone pleine…).
+pub fn can_play_card(state: &GameState, player_id: &PlayerId, card: &Card) -> bool {
+    let player = &state.players[player_id];
+    match card.card_type {
+        CardType::Spell if card.has_kw(Keywords::SECRET) => player.can_add_secret(card),
+        // Un serviteur ou un lieu a besoin d'un emplacement libre
+        CardType::Minion | CardType::Location => player.has_room(&Zone::Board),
+        _ => true,
+    }
+}
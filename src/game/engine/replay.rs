@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+
+use crate::data::card_template::CardTemplate;
+use crate::game::{
+    card::Card,
+    effects::{apply_effect, Effect},
+    engine::{
+        choose::{Chooser, RandomChooser},
+        play_card::resolve_spell_text,
+        targeting::{can_be_targeted, is_single_minion, minion_candidates, pick_target},
+        utils::IdString,
+        zones::summon,
+    },
+    enums::CardType,
+    filter::CardFilter,
+    history::Character,
+    keywords::Keywords,
+    state::{GameState, PlayerId},
+    targets::Target,
+    triggers::Trigger,
+};
+
+/// Effets des déclencheurs `when` de la carte.
+fn trigger_effects(card: &Card, when: Trigger) -> Vec<Effect> {
+    card.triggers.iter().filter(|t| t.when == when).map(|t| t.effect.clone()).collect()
+}
+
+/// Résout `effects` avec le serviteur `index` comme « self » ; ses invocations arrivent à sa droite.
+fn resolve_as_source(
+    state: &mut GameState,
+    player_id: &PlayerId,
+    index: usize,
+    effects: &[Effect],
+    chooser: &dyn Chooser,
+    card_templates: &HashMap<String, CardTemplate>,
+) {
+    let p = state.players.get_mut(player_id).unwrap();
+    let source_id = p.source_position.and_then(|i| p.zones.board.get(i)).map(|c| c.card_id.clone());
+    let saved = (
+        p.source_position.replace(index),
+        p.summon_position.replace(index + 1),
+        p.deathrattle_source.take(),
+    );
+    for eff in effects {
+        apply_effect(state, player_id, eff, chooser, card_templates);
+    }
+    // Le board a pu bouger : la source d'origine est retrouvée au plus près, ses invocations suivent
+    let p = state.players.get_mut(player_id).unwrap();
+    let (source, summon_at, deathrattle) = saved;
+    let moved = source.zip(source_id).and_then(|(old, id)| {
+        let board = &p.zones.board;
+        (0..board.len()).filter(|&i| board[i].card_id == id).min_by_key(|&i| i.abs_diff(old)).map(|new| (old, new))
+    });
+    (p.source_position, p.summon_position, p.deathrattle_source) = match moved {
+        Some((old, new)) => (Some(new), summon_at.map(|s| (s + new).saturating_sub(old)), deathrattle),
+        None => (source, summon_at, deathrattle),
+    };
+}
+
+/// Déclenche le(s) Râle(s) d'agonie des serviteurs visés sans les tuer (seuls ceux qui en
+/// ont un sont visables). Renvoie le nombre de serviteurs déclenchés.
+pub fn trigger_deathrattles(
+    state: &mut GameState,
+    player_id: &PlayerId,
+    target: &Target,
+    random: bool,
+    chooser: &dyn Chooser,
+    card_templates: &HashMap<String, CardTemplate>,
+) -> usize {
+    let single = is_single_minion(target);
+    let candidates: Vec<_> = minion_candidates(state, player_id, target)
+        .into_iter()
+        .filter(|(pid, i)| {
+            let minion = &state.players[pid].zones.board[*i];
+            !trigger_effects(minion, Trigger::Deathrattle).is_empty()
+                && (!single || can_be_targeted(&state.history, minion))
+        })
+        .collect();
+    let hits = if single {
+        pick_target(state, &candidates, random, chooser).into_iter().collect()
+    } else {
+        candidates
+    };
+
+    // Les ids d'abord : un Râle d'agonie peut décaler le board
+    let hits: Vec<(PlayerId, String, Vec<Effect>)> = hits
+        .into_iter()
+        .map(|(pid, i)| {
+            let minion = &state.players[&pid].zones.board[i];
+            (pid, minion.card_id.clone(), trigger_effects(minion, Trigger::Deathrattle))
+        })
+        .collect();
+    let count = hits.len();
+    for (pid, card_id, effects) in hits {
+        let Some(index) = state.players[&pid].zones.board.iter().position(|c| c.card_id == card_id) else {
+            continue;
+        };
+        println!("💀 Le Râle d'agonie de {} ({}) se déclenche", state.players[&pid].zones.board[index].name, pid.id_string());
+        resolve_as_source(state, &pid, index, &effects, chooser, card_templates);
+    }
+    count
+}
+
+/// Rejoue les cartes jouées par `player_id` pendant la partie qui satisfont `filter`, dans
+/// l'ordre (Tess Greymane). Les sorts visent leur cible d'origine si elle est encore valide,
+/// ou des cibles aléatoires avec `random_targets`. Une carte qui rejoue des cartes n'est pas
+/// rejouée. Renvoie le nombre de cartes rejouées.
+pub fn replay_cards(
+    state: &mut GameState,
+    player_id: &PlayerId,
+    filter: &CardFilter,
+    random_targets: bool,
+    chooser: &dyn Chooser,
+    card_templates: &HashMap<String, CardTemplate>,
+) -> usize {
+    let filter = filter.clone().for_player(state, player_id);
+    let mut spells = state
+        .history
+        .spells_cast_by(player_id, None)
+        .into_iter()
+        .map(|r| (r.card_id.clone(), r.target.clone()))
+        .collect::<Vec<_>>()
+        .into_iter();
+    let played: Vec<(String, Option<Character>)> = state
+        .history
+        .cards_played_by(player_id, None)
+        .iter()
+        .map(|r| {
+            let target = match r.card_type {
+                CardType::Spell => spells.find(|(id, _)| *id == r.card_id).and_then(|(_, t)| t),
+                _ => None,
+            };
+            (r.card_id.clone(), target)
+        })
+        .collect();
+
+    let random_chooser = RandomChooser;
+    let chooser: &dyn Chooser = if random_targets { &random_chooser } else { chooser };
+    let mut replayed = 0;
+    state.history.begin_replay();
+    for (card_id, target) in played {
+        let Some(template) = card_templates.get(&card_id) else {
+            continue;
+        };
+        let card = template.to_card();
+        if !filter.matches(&card) || card.effects.iter().any(|e| matches!(e, Effect::ReplayCards { .. })) {
+            continue;
+        }
+        println!("🔁 {} rejoue {}", player_id.id_string(), card.name);
+        if !random_targets {
+            state.replay_target = target;
+        }
+        replay_card(state, player_id, card, chooser, card_templates);
+        state.replay_target = None;
+        replayed += 1;
+    }
+    state.history.end_replay();
+    replayed
+}
+
+/// Rejoue une carte sans la payer ni la compter comme jouée : texte d'un sort, Secret
+/// posé, ou serviteur invoqué avec son Cri de guerre.
+fn replay_card(
+    state: &mut GameState,
+    player_id: &PlayerId,
+    card: Card,
+    chooser: &dyn Chooser,
+    card_templates: &HashMap<String, CardTemplate>,
+) {
+    match card.card_type {
+        CardType::Spell if card.has_kw(Keywords::SECRET) => {
+            let player = state.players.get_mut(player_id).unwrap();
+            if player.can_add_secret(&card) {
+                player.zones.secrets.push(card);
+            }
+        }
+        CardType::Spell => resolve_spell_text(state, player_id, &card, false, false, chooser, card_templates),
+        CardType::Minion => {
+            let battlecries = trigger_effects(&card, Trigger::Battlecry);
+            if let Some(index) = summon(state, player_id, card) {
+                resolve_as_source(state, player_id, index, &battlecries, chooser, card_templates);
+            }
+        }
+        _ => println!("⚠️ {} : rejouer ce type de carte n'est pas géré", card.name),
+    }
+}
@@ -0,0 +1,149 @@
+use rand::seq::SliceRandom;
+
+use crate::game::{
+    card::Card,
+    condition::EffectCondition,
+    effects::Effect,
+    engine::choose::{Chooser, Choice},
+    history::{Character, GameHistory},
+    state::{GameState, PlayerId},
+    targets::Target,
+};
+
+/// Serviteur visé, sous forme (contrôleur, index sur le board).
+pub type MinionRef = (PlayerId, usize);
+
+/// Personnage visé, sous forme (joueur, index sur le board ; `None` pour son héros).
+pub type CharacterRef = (PlayerId, Option<usize>);
+
+/// Insaisissable : ne peut pas être la cible des sorts ni des pouvoirs héroïques.
+pub fn is_elusive(card: &Card) -> bool {
+    card.effects.contains(&Effect::Elusive)
+}
+
+/// Le serviteur peut-il être ciblé en ce moment (ni un sort ni un pouvoir héroïque
+/// en cours ne visent un Insaisissable) ?
+pub fn can_be_targeted(history: &GameHistory, card: &Card) -> bool {
+    !((history.is_resolving_spell() || history.is_resolving_hero_power()) && is_elusive(card))
+}
+
+/// Cible désignant un seul serviteur, choisi au moment de la résolution.
+pub fn is_single_minion(target: &Target) -> bool {
+    matches!(
+        target,
+        Target::AnyMinion | Target::EnemyMinion | Target::FriendlyMinion | Target::OtherMinion | Target::OtherFriendlyMinion
+    )
+}
+
+/// Serviteurs couverts par `target` pour `owner` (lieux exclus). Le serviteur source
+/// ne se vise pas lui-même avec une cible unique ni une cible « other ».
+pub fn minion_candidates(state: &GameState, owner: &PlayerId, target: &Target) -> Vec<MinionRef> {
+    let sides = match target {
+        Target::AnyMinion | Target::AllMinion | Target::OtherMinion | Target::AllOtherMinion => vec![*owner, owner.opponent()],
+        Target::EnemyMinion | Target::AllEnemyMinion => vec![owner.opponent()],
+        Target::FriendlyMinion | Target::AllFriendlyMinion | Target::OtherFriendlyMinion => vec![*owner],
+        Target::SelfTarget => {
+            return state.players[owner].source_minion_index().map(|i| vec![(*owner, i)]).unwrap_or_default();
+        }
+        Target::AdjacentFriendlyMinion => {
+            let player = &state.players[owner];
+            let neighbours = player.source_minion_index().map(|i| player.adjacent_minions(i)).unwrap_or_default();
+            return neighbours.into_iter().map(|i| (*owner, i)).collect();
+        }
+        _ => vec![],
+    };
+    let excluded = match target {
+        Target::AllMinion | Target::AllEnemyMinion | Target::AllFriendlyMinion => None,
+        _ => state.players[owner].source_position.map(|i| (*owner, i)),
+    };
+    sides
+        .into_iter()
+        .flat_map(|pid| {
+            state.players[&pid]
+                .zones
+                .board
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| !c.is_location())
+                .map(move |(i, _)| (pid, i))
+                .collect::<Vec<_>>()
+        })
+        .filter(|m| Some(*m) != excluded)
+        .collect()
+}
+
+/// Serviteurs touchés par un effet : tous ceux d'une cible multiple, ou celui retenu pour une
+/// cible unique (au hasard si `random`, sinon par le chooser). `condition` restreint les cibles
+/// valides (Chasseur de gros gibier…) ; la cible d'un sort est notée dans l'historique.
+pub fn resolve_minion_targets(
+    state: &mut GameState,
+    owner: &PlayerId,
+    target: &Target,
+    condition: Option<&EffectCondition>,
+    random: bool,
+    chooser: &dyn Chooser,
+) -> Vec<MinionRef> {
+    let single = is_single_minion(target);
+    let candidates: Vec<MinionRef> = minion_candidates(state, owner, target)
+        .into_iter()
+        .filter(|(pid, i)| {
+            let minion = &state.players[pid].zones.board[*i];
+            condition.is_none_or(|c| c.allows_target(minion)) && (!single || can_be_targeted(&state.history, minion))
+        })
+        .collect();
+    if !single {
+        return candidates;
+    }
+    let picked = pick_target(state, &candidates, random, chooser);
+    if let Some((pid, i)) = picked {
+        let card_id = state.players[&pid].zones.board[i].card_id.clone();
+        state.history.note_target(Character::Minion { owner: pid, card_id });
+    }
+    picked.into_iter().collect()
+}
+
+/// Retient une cible parmi `candidates` : la cible d'origine d'un sort rejoué si elle en
+/// fait partie, sinon au hasard si `random`, sinon par le chooser.
+pub fn pick_target(state: &GameState, candidates: &[MinionRef], random: bool, chooser: &dyn Chooser) -> Option<MinionRef> {
+    if candidates.is_empty() {
+        return None;
+    }
+    let original = candidates.iter().find(|(pid, i)| is_replay_target(state, pid, Some(*i)));
+    if original.is_some() {
+        return original.copied();
+    }
+    if random {
+        return candidates.choose(&mut rand::thread_rng()).copied();
+    }
+    match chooser.choose(state, Choice::Target { valid_targets: (0..candidates.len()).collect() }) {
+        Choice::Target { valid_targets } => valid_targets.first().and_then(|&i| candidates.get(i)).copied(),
+        _ => None,
+    }
+}
+
+/// Retient un personnage (héros ou serviteur) parmi `candidates` : la cible d'origine d'un
+/// sort rejoué si elle en fait partie, sinon par le chooser. Renvoie son index dans `candidates`.
+pub fn pick_character(state: &GameState, candidates: &[CharacterRef], chooser: &dyn Chooser) -> Option<usize> {
+    if candidates.is_empty() {
+        return None;
+    }
+    let original = candidates.iter().position(|(pid, i)| is_replay_target(state, pid, *i));
+    if original.is_some() {
+        return original;
+    }
+    match chooser.choose(state, Choice::Target { valid_targets: (0..candidates.len()).collect() }) {
+        Choice::Target { valid_targets } => valid_targets.first().copied().filter(|&k| k < candidates.len()),
+        _ => None,
+    }
+}
+
+/// Le personnage est-il la cible d'origine du sort en cours de rejeu ?
+fn is_replay_target(state: &GameState, pid: &PlayerId, index: Option<usize>) -> bool {
+    match (&state.replay_target, index) {
+        (Some(Character::Hero(owner)), None) => owner == pid,
+        (Some(Character::Minion { owner, card_id }), Some(i)) => {
+            owner == pid && state.players[pid].zones.board.get(i).is_some_and(|c| c.card_id == *card_id)
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use rand::Rng;
+
+use crate::{
+    data::card_template::CardTemplate,
+    game::{
+        card::Card,
+        effects::{apply_effect, Effect},
+        engine::{choose::Chooser, draw::draw_card, zones::move_card},
+        enums::Zone,
+        event::GameEvent,
+        state::{GameState, PlayerId},
+        triggers::Trigger,
+    },
+};
+
+/// Coût d'un échange, quel que soit le coût de la carte.
+pub const TRADE_COST: u8 = 1;
+
+pub fn is_tradeable(card: &Card) -> bool {
+    card.effects.contains(&Effect::Tradeable)
+}
+
+/// Indices (dans la main de `pid`) des cartes Échangeables que le joueur peut payer.
+pub fn tradeable_cards(state: &GameState, pid: &PlayerId) -> Vec<usize> {
+    let player = &state.players[pid];
+    if player.stats.mana.current < TRADE_COST {
+        return Vec::new();
+    }
+    player
+        .zones
+        .hand
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| is_tradeable(c))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Échange la carte `hand_index` : 1 mana, mélange dans le deck, pioche d'une carte,
+/// puis ses effets « when_traded » et l'événement `CardTraded`.
+pub fn trade_card(
+    state: &mut GameState,
+    player_id: &PlayerId,
+    hand_index: usize,
+    chooser: &dyn Chooser,
+    card_templates: &HashMap<String, CardTemplate>,
+) -> bool {
+    if !tradeable_cards(state, player_id).contains(&hand_index) {
+        println!("⚠️ Échange impossible pour la carte en position {}", hand_index);
+        return false;
+    }
+
+    let card = state.players[player_id].zones.hand[hand_index].clone();
+    {
+        let player = state.players.get_mut(player_id).unwrap();
+        player.stats.mana.spend(TRADE_COST);
+        println!("🔁 {} échange {} (mélangée dans le deck)", player.id_string(), card.name);
+    }
+    // La carte quitte la main avant la pioche : une main pleine ne brûle pas la carte piochée.
+    // Elle n'est pas placée sur le dessus du deck (sauf deck vide) : elle ne revient pas aussitôt.
+    let deck_len = state.players[player_id].zones.deck.len();
+    let pos = if deck_len == 0 { 0 } else { rand::thread_rng().gen_range(0..deck_len) };
+    let _ = move_card(state, player_id, Zone::Hand, hand_index, Zone::Deck, Some(pos));
+    draw_card(state, player_id);
+
+    // La carte est dans le deck : ses effets d'échange sont résolus directement
+    for trig in card.triggers.iter().filter(|t| t.when == Trigger::WhenTraded) {
+        apply_effect(state, player_id, &trig.effect, chooser, card_templates);
+    }
+    state.event_queue.push_back(GameEvent::CardTraded {
+        card_id: card.card_id,
+        owner: *player_id,
+    });
+    crate::game::engine::events::dispatch_events(state, chooser);
+    true
+}
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+use crate::{
+    data::card_template::CardTemplate,
+    game::{
+        card::Card,
+        effects::apply_effect,
+        engine::{choose::Chooser, utils::IdString},
+        event::GameEvent,
+        state::{GameState, PlayerId},
+        triggers::Trigger,
+    },
+};
+
+/// Équipe `weapon` ; l'arme remplacée est détruite (Râle d'agonie compris).
+/// Le Cri de guerre d'une arme jouée passe par `CardPlayed`, pas par ici.
+pub fn equip_weapon(
+    state: &mut GameState,
+    player_id: &PlayerId,
+    weapon: Card,
+    chooser: &dyn Chooser,
+    card_templates: &HashMap<String, CardTemplate>,
+) {
+    if state.players[player_id].stats.weapon.is_some() {
+        println!("{} brise son ancienne arme", player_id.id_string());
+        destroy_weapon(state, player_id, chooser, card_templates);
+    }
+    println!(
+        "{} équipe l'arme {} (ATK {}, DUR {})",
+        player_id.id_string(),
+        weapon.name,
+        weapon.attack.unwrap_or(0),
+        weapon.health.unwrap_or(0)
+    );
+    state.players.get_mut(player_id).unwrap().stats.weapon = Some(weapon);
+}
+
+/// Retire `amount` de durabilité (stockée dans `health`). Renvoie `true` si l'arme tombe à 0 :
+/// l'appelant la détruit (`destroy_weapon`) une fois les déclencheurs de l'attaque résolus.
+pub fn lose_durability(state: &mut GameState, player_id: &PlayerId, amount: i32) -> bool {
+    let (card_id, remaining) = {
+        let Some(weapon) = state.players.get_mut(player_id).unwrap().stats.weapon.as_mut() else {
+            return false;
+        };
+        let remaining = (weapon.health.unwrap_or(0) - amount).max(0);
+        weapon.health = Some(remaining);
+        (weapon.card_id.clone(), remaining)
+    };
+    state.event_queue.push_back(GameEvent::WeaponDurabilityLost {
+        card_id,
+        owner: *player_id,
+        remaining,
+    });
+    remaining == 0
+}
+
+/// Détruit l'arme équipée : cimetière, événement, puis son Râle d'agonie.
+/// L'arme n'étant plus dans aucune zone, le Râle est résolu directement.
+pub fn destroy_weapon(
+    state: &mut GameState,
+    player_id: &PlayerId,
+    chooser: &dyn Chooser,
+    card_templates: &HashMap<String, CardTemplate>,
+) {
+    let Some(weapon) = state.players.get_mut(player_id).unwrap().stats.weapon.take() else {
+        return;
+    };
+    println!("🗡️ {} est détruite", weapon.name);
+
+    let deathrattles: Vec<_> = weapon
+        .triggers
+        .iter()
+        .filter(|t| t.when == Trigger::Deathrattle)
+        .map(|t| t.effect.clone())
+        .collect();
+    state.event_queue.push_back(GameEvent::WeaponDestroyed {
+        card_id: weapon.card_id.clone(),
+        owner: *player_id,
+    });
+    state.players.get_mut(player_id).unwrap().zones.graveyard.push(weapon);
+
+    for eff in deathrattles {
+        apply_effect(state, player_id, &eff, chooser, card_templates);
+    }
+}
@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+use crate::data::card_template::CardTemplate;
+use crate::game::{
+    card::Card,
+    engine::{graveyard::fresh_copy, utils::IdString},
+    enums::Zone,
+    event::GameEvent,
+    player::ZoneFull,
+    state::{GameState, PlayerId},
+};
+
+/// Déplace la carte `index` de la zone `from` vers `to` (capacités respectées).
+/// En cas de refus, la carte reste dans `from` et l'appelant reçoit la zone pleine.
+pub fn move_card(
+    state: &mut GameState,
+    player_id: &PlayerId,
+    from: Zone,
+    index: usize,
+    to: Zone,
+    position: Option<usize>,
+) -> Result<usize, ZoneFull> {
+    let player = state.players.get_mut(player_id).unwrap();
+    let card_id = player.zone(&from)[index].card_id.clone();
+    match player.move_card(&from, index, &to, position) {
+        Ok(pos) => {
+            state.event_queue.push_back(GameEvent::CardMoved { card_id, owner: *player_id, from, to });
+            Ok(pos)
+        }
+        Err(full) => {
+            println!("⚠️ {:?} plein : {} reste en {:?}", full, card_id, from);
+            Err(full)
+        }
+    }
+}
+
+/// Invoque `card` pour `player_id` ; board plein : l'invocation échoue.
+pub fn summon(state: &mut GameState, player_id: &PlayerId, card: Card) -> Option<usize> {
+    let (card_id, name) = (card.card_id.clone(), card.name.clone());
+    match state.players.get_mut(player_id).unwrap().summon_minion(card) {
+        Ok(pos) => {
+            state.event_queue.push_back(GameEvent::MinionSummoned { card_id, owner: *player_id });
+            Some(pos)
+        }
+        Err(_) => {
+            println!("⚠️ Board plein ({}) : {} n'est pas invoqué", player_id.id_string(), name);
+            state.event_queue.push_back(GameEvent::SummonFizzled { card_id, owner: *player_id });
+            None
+        }
+    }
+}
+
+/// Ajoute `card` à la main ; main pleine : la carte est brûlée (cimetière).
+pub fn add_to_hand(state: &mut GameState, player_id: &PlayerId, card: Card) -> bool {
+    let player = state.players.get_mut(player_id).unwrap();
+    if player.has_room(&Zone::Hand) {
+        return player.put_card(card, &Zone::Hand, None).is_ok();
+    }
+    println!("🔥 Main pleine ({}) : {} est brûlée", player.id_string(), card.name);
+    let card_id = card.card_id.clone();
+    player.zones.graveyard.push(card);
+    state.event_queue.push_back(GameEvent::CardBurned { card_id, owner: *player_id });
+    false
+}
+
+/// Renvoie en main le serviteur `index` du board, remis à son état imprimé (main pleine : brûlé).
+pub fn bounce(
+    state: &mut GameState,
+    player_id: &PlayerId,
+    index: usize,
+    card_templates: &HashMap<String, CardTemplate>,
+) -> bool {
+    let card = state.players.get_mut(player_id).unwrap().remove_from_board(index);
+    println!("↩️ {} retourne dans la main de {}", card.name, player_id.id_string());
+    state.event_queue.push_back(GameEvent::CardMoved {
+        card_id: card.card_id.clone(),
+        owner: *player_id,
+        from: Zone::Board,
+        to: Zone::Hand,
+    });
+    add_to_hand(state, player_id, fresh_copy(&card, card_templates))
+}
+
+/// Défausse la carte `index` de la main : elle rejoint le cimetière.
+pub fn discard(state: &mut GameState, player_id: &PlayerId, index: usize) {
+    let player = state.players.get_mut(player_id).unwrap();
+    let card = player.zones.hand.remove(index);
+    println!("🗑️ {} défausse {}", player_id.id_string(), card.name);
+    let card_id = card.card_id.clone();
+    player.zones.graveyard.push(card);
+    state.event_queue.push_back(GameEvent::CardDiscarded { card_id, owner: *player_id });
+}
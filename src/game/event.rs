@@ -1,9 +1,27 @@
-use crate::game::state::PlayerId;
-
-#[derive(Debug, Clone)]
-pub enum GameEvent {
-    CardPlayed   { card_id: String, owner: PlayerId },
-    MinionDied   { card_id: String, owner: PlayerId },
-    TurnStart    { player: PlayerId },
-    TurnEnd      { player: PlayerId },
-}
+use crate::game::enums::Zone;
+use crate::game::state::PlayerId;
+
+#[derive(Debug, Clone)]
+pub enum GameEvent {
+    CardPlayed   { card_id: String, owner: PlayerId },
+    MinionPlayed { card_id: String, owner: PlayerId },
+    ComboActivated   { card_id: String, owner: PlayerId },
+    OutcastActivated { card_id: String, owner: PlayerId },
+    CardTraded   { card_id: String, owner: PlayerId },
+    SpellCast    { card_id: String, owner: PlayerId },
+    AttackDeclared { attacker_id: String, owner: PlayerId, defender_id: Option<String> },
+    HeroDamaged  { player: PlayerId, amount: i32, dealer: PlayerId },
+    MinionDied   { card_id: String, owner: PlayerId, position: usize },
+    MinionSummoned { card_id: String, owner: PlayerId },
+    SummonFizzled  { card_id: String, owner: PlayerId },
+    CardBurned   { card_id: String, owner: PlayerId },
+    CardDiscarded { card_id: String, owner: PlayerId },
+    CardMoved    { card_id: String, owner: PlayerId, from: Zone, to: Zone },
+    /// Combat d'un serviteur terminé (`killed` : son défenseur est mort)
+    AttackResolved { attacker_id: String, owner: PlayerId, defender_id: Option<String>, killed: bool },
+    HeroAttacked { owner: PlayerId, defender_id: Option<String> },
+    WeaponDurabilityLost { card_id: String, owner: PlayerId, remaining: i32 },
+    WeaponDestroyed { card_id: String, owner: PlayerId },
+    TurnStart    { player: PlayerId },
+    TurnEnd      { player: PlayerId },
+}
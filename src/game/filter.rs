@@ -0,0 +1,281 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::data::card_template::CardTemplate;
+use crate::game::card::Card;
+use crate::game::effects::{mechanic_name, Effect};
+use crate::game::enums::{CardClass, CardType, Races, Rarity, SpellSchool};
+use crate::game::keywords::Keywords;
+use crate::game::state::{GameState, PlayerId};
+use crate::game::triggers::Trigger;
+
+/// Classe recherchée : une classe précise, ou relative au joueur qui filtre.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ClassFilter {
+    Is(CardClass),
+    /// « d'une autre classe » : ni la sienne, ni Neutre
+    Other,
+    /// « de la classe de votre adversaire »
+    Opponent,
+}
+
+/// Coût compris entre `min` et `max` (bornes incluses).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct CostRange {
+    pub min: Option<u8>,
+    pub max: Option<u8>,
+}
+
+impl CostRange {
+    pub fn contains(&self, cost: u8) -> bool {
+        self.min.is_none_or(|m| cost >= m) && self.max.is_none_or(|m| cost <= m)
+    }
+}
+
+/// Filtre de cartes typé, lu depuis les `filter`/`source_pool` du JSON.
+/// Chaque critère absent laisse tout passer ; les critères présents se cumulent.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CardFilter {
+    pub card_type: Option<CardType>,
+    pub class: Option<ClassFilter>,
+    /// Au moins une de ces races (`Races::All` : n'importe quelle race)
+    pub races: Vec<Races>,
+    pub spell_school: Option<SpellSchool>,
+    pub rarity: Option<Rarity>,
+    pub cost: Option<CostRange>,
+    /// Tous ces mots-clés
+    pub keywords: Keywords,
+    pub collectible: Option<bool>,
+    /// Foxy Fraud : « votre prochaine carte Combo »
+    pub combo: bool,
+    /// Chevalier de la mort : « une carte qui dépense des Cadavres »
+    pub spends_corpse: Option<bool>,
+    /// Runes minimales (`{"blood": {"min": 1}}`)
+    pub runes: HashMap<String, u8>,
+    /// Classes du joueur qui filtre (résout `Other` / `Opponent`), cf. `for_player`
+    pub own_class: Option<CardClass>,
+    pub opponent_class: Option<CardClass>,
+}
+
+impl CardFilter {
+    /// Lit un filtre JSON : objet de critères, ou raccourci texte
+    /// (`"spell"`, `"elemental"`, `"opponent_class"`). Clés inconnues ignorées.
+    pub fn from_value(value: &Value) -> Self {
+        let mut filter = CardFilter::default();
+        match value {
+            Value::String(s) => filter.apply_shorthand(s),
+            Value::Object(map) => {
+                for (key, v) in map {
+                    filter.apply(key, v);
+                }
+            }
+            _ => {}
+        }
+        filter
+    }
+
+    /// Même lecture depuis la forme `HashMap` des templates d'effets.
+    pub fn from_map(map: &HashMap<String, Value>) -> Self {
+        let mut filter = CardFilter::default();
+        for (key, v) in map {
+            filter.apply(key, v);
+        }
+        filter
+    }
+
+    /// Renseigne les classes du joueur `player_id` pour les critères relatifs.
+    pub fn for_player(mut self, state: &GameState, player_id: &PlayerId) -> Self {
+        self.own_class = Some(state.players[player_id].stats.class.clone());
+        self.opponent_class = Some(state.players[&player_id.opponent()].stats.class.clone());
+        self
+    }
+
+    fn apply_shorthand(&mut self, s: &str) {
+        match s {
+            "opponent_class" => self.class = Some(ClassFilter::Opponent),
+            "other" | "other_classes" => self.class = Some(ClassFilter::Other),
+            _ if CardType::from_str(s) != CardType::Unknown => self.card_type = Some(CardType::from_str(s)),
+            _ if Races::from_str(s) != Races::Unknown => self.races.push(Races::from_str(s)),
+            _ => {}
+        }
+    }
+
+    fn apply(&mut self, key: &str, v: &Value) {
+        match (key, v) {
+            ("card_type", Value::String(t)) => self.card_type = Some(CardType::from_str(t)),
+            ("type", Value::String(t)) if t == "combo" => self.combo = true,
+            ("type", Value::String(t)) => self.card_type = Some(CardType::from_str(t)),
+            ("card_class", Value::String(c)) => {
+                self.class = Some(match c.as_str() {
+                    "opponent_class" => ClassFilter::Opponent,
+                    "other" | "other_classes" => ClassFilter::Other,
+                    _ => ClassFilter::Is(CardClass::from_str(c)),
+                })
+            }
+            ("race" | "races", Value::String(r)) => self.races.push(Races::from_str(r)),
+            ("race" | "races", Value::Array(rs)) => {
+                self.races.extend(rs.iter().filter_map(|r| r.as_str()).map(Races::from_str))
+            }
+            ("spellschool" | "spell_school", Value::String(s)) => {
+                self.spell_school = Some(SpellSchool::from_str(s))
+            }
+            ("rarity", Value::String(r)) => self.rarity = Some(Rarity::from_str(r)),
+            ("cost", Value::Number(n)) => {
+                let c = n.as_u64().map(|c| c.min(u8::MAX as u64) as u8);
+                self.cost = Some(CostRange { min: c, max: c });
+            }
+            ("cost", Value::Object(range)) => {
+                let bound = |k: &str| range.get(k).and_then(|b| b.as_u64()).map(|b| b.min(u8::MAX as u64) as u8);
+                self.cost = Some(CostRange { min: bound("min"), max: bound("max") });
+            }
+            ("min_cost", Value::Number(n)) => {
+                self.cost.get_or_insert_with(CostRange::default).min = n.as_u64().map(|c| c as u8)
+            }
+            ("max_cost", Value::Number(n)) => {
+                self.cost.get_or_insert_with(CostRange::default).max = n.as_u64().map(|c| c as u8)
+            }
+            ("keywords" | "mechanics", Value::Array(ks)) => {
+                for k in ks.iter().filter_map(|k| k.as_str()) {
+                    self.keywords |= keyword(k);
+                }
+            }
+            ("keyword" | "mechanic", Value::String(k)) => self.keywords |= keyword(k),
+            ("has_taunt", Value::Bool(true)) => self.keywords |= Keywords::TAUNT,
+            ("collectible", Value::Bool(b)) => self.collectible = Some(*b),
+            ("spends_corpse", Value::Bool(b)) => self.spends_corpse = Some(*b),
+            ("rune_cost", Value::Object(runes)) => {
+                for (rune, bounds) in runes {
+                    let min = bounds.get("min").and_then(|m| m.as_u64()).unwrap_or(0) as u8;
+                    self.runes.insert(rune.clone(), min);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// La carte (en jeu, en main, au deck…) ou le template satisfait-il le filtre ?
+    pub fn matches(&self, card: &impl Filterable) -> bool {
+        self.card_type.as_ref().is_none_or(|t| card.card_type() == t)
+            && self.class.as_ref().is_none_or(|c| self.class_matches(c, card.card_class()))
+            && (self.races.is_empty() || races_match(&self.races, card.races()))
+            && self.spell_school.as_ref().is_none_or(|s| card.spell_school() == Some(s))
+            && self.rarity.as_ref().is_none_or(|r| card.rarity() == Some(r))
+            && self.cost.is_none_or(|range| range.contains(card.cost()))
+            && card.keywords().contains(self.keywords)
+            && self.collectible.is_none_or(|c| card.collectible() == c)
+            && (!self.combo || card.has_combo())
+            && self.spends_corpse.is_none_or(|s| card.spends_corpse() == s)
+            && self.runes.iter().all(|(rune, min)| card.runes(rune) >= *min)
+    }
+
+    fn class_matches(&self, filter: &ClassFilter, class: &CardClass) -> bool {
+        match filter {
+            ClassFilter::Is(c) => class == c,
+            // sans joueur de référence, le critère relatif ne s'évalue pas
+            ClassFilter::Other => {
+                *class != CardClass::Neutral && self.own_class.as_ref().is_none_or(|own| class != own)
+            }
+            ClassFilter::Opponent => self.opponent_class.as_ref().is_none_or(|opp| class == opp),
+        }
+    }
+}
+
+fn keyword(name: &str) -> Keywords {
+    let normalized = name.to_ascii_lowercase().replace(' ', "_");
+    Keywords::from_mechanics(&[mechanic_name(&normalized)])
+}
+
+/// `Races::All` (Amalgame…) compte pour toutes les races ; en filtre, il vaut « une race ».
+fn races_match(wanted: &[Races], races: &[Races]) -> bool {
+    races.iter().any(|r| *r == Races::All || wanted.contains(&Races::All) || wanted.contains(r))
+}
+
+/// Ce qu'un filtre sait lire d'une carte : implémenté par `Card` et `CardTemplate`.
+pub trait Filterable {
+    fn card_type(&self) -> &CardType;
+    fn card_class(&self) -> &CardClass;
+    fn races(&self) -> &[Races];
+    fn spell_school(&self) -> Option<&SpellSchool>;
+    fn rarity(&self) -> Option<&Rarity>;
+    fn cost(&self) -> u8;
+    fn keywords(&self) -> Keywords;
+    fn collectible(&self) -> bool;
+    fn runes(&self, rune: &str) -> u8;
+    fn has_combo(&self) -> bool;
+    fn spends_corpse(&self) -> bool;
+}
+
+impl Filterable for Card {
+    fn card_type(&self) -> &CardType {
+        &self.card_type
+    }
+    fn card_class(&self) -> &CardClass {
+        &self.card_class
+    }
+    fn races(&self) -> &[Races] {
+        self.races.as_deref().unwrap_or(&[])
+    }
+    fn spell_school(&self) -> Option<&SpellSchool> {
+        self.spell_school.as_ref()
+    }
+    fn rarity(&self) -> Option<&Rarity> {
+        self.rarity.as_ref()
+    }
+    fn cost(&self) -> u8 {
+        self.cost
+    }
+    fn keywords(&self) -> Keywords {
+        self.keywords
+    }
+    fn collectible(&self) -> bool {
+        self.collectible.unwrap_or(false)
+    }
+    fn runes(&self, rune: &str) -> u8 {
+        self.rune_cost.as_ref().and_then(|rc| rc.get(rune)).copied().unwrap_or(0)
+    }
+    fn has_combo(&self) -> bool {
+        self.triggers.iter().any(|t| t.when == Trigger::Combo)
+    }
+    fn spends_corpse(&self) -> bool {
+        self.effects.iter().any(|e| matches!(e, Effect::SpendCorpse { .. }))
+    }
+}
+
+impl Filterable for CardTemplate {
+    fn card_type(&self) -> &CardType {
+        &self.card_type
+    }
+    fn card_class(&self) -> &CardClass {
+        &self.card_class
+    }
+    fn races(&self) -> &[Races] {
+        self.races.as_deref().unwrap_or(&[])
+    }
+    fn spell_school(&self) -> Option<&SpellSchool> {
+        self.spell_school.as_ref()
+    }
+    fn rarity(&self) -> Option<&Rarity> {
+        self.rarity.as_ref()
+    }
+    fn cost(&self) -> u8 {
+        self.cost.unwrap_or(0)
+    }
+    fn keywords(&self) -> Keywords {
+        CardTemplate::keywords(self)
+    }
+    fn collectible(&self) -> bool {
+        self.collectible.unwrap_or(false)
+    }
+    fn runes(&self, rune: &str) -> u8 {
+        self.rune_cost.as_ref().and_then(|rc| rc.get(rune)).copied().unwrap_or(0)
+    }
+    fn has_combo(&self) -> bool {
+        self.effects.iter().flatten().any(|e| e.trigger == Some(Trigger::Combo))
+    }
+    fn spends_corpse(&self) -> bool {
+        self.effects.iter().flatten().any(|e| e.effect_type == "spend_corpse")
+    }
+}
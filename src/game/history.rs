@@ -0,0 +1,241 @@
+use serde::Serialize;
+
+use crate::game::card::Card;
+use crate::game::enums::{CardClass, CardType, Races, SpellSchool};
+use crate::game::state::PlayerId;
+
+/// Personnage touché ou ciblé : un héros, ou un serviteur identifié par son propriétaire.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum Character {
+    Hero(PlayerId),
+    Minion { owner: PlayerId, card_id: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CardPlayedRecord {
+    pub turn: u32,
+    pub player: PlayerId,
+    pub card_id: String,
+    pub card_type: CardType,
+    pub card_class: CardClass,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SpellCastRecord {
+    pub turn: u32,
+    pub player: PlayerId,
+    pub card_id: String,
+    pub spell_school: Option<SpellSchool>,
+    /// Mana réellement payé
+    pub cost: u8,
+    /// Première cible choisie pendant la résolution du sort
+    pub target: Option<Character>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MinionDiedRecord {
+    pub turn: u32,
+    pub owner: PlayerId,
+    pub card_id: String,
+    pub races: Vec<Races>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DamageRecord {
+    pub turn: u32,
+    pub target: Character,
+    /// Joueur qui inflige les dégâts (propriétaire de l'effet ou de l'attaquant)
+    pub dealer: PlayerId,
+    pub amount: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CardDrawnRecord {
+    pub turn: u32,
+    pub player: PlayerId,
+    pub card_id: String,
+}
+
+/// Historique de la partie, tour par tour (le tour `n` est le n-ième tour joué,
+/// tours supplémentaires compris). Lu par les effets, les conditions et l'analyse.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GameHistory {
+    /// Joueur actif de chaque tour : `turns[n - 1]` a joué le tour `n`
+    pub turns: Vec<PlayerId>,
+    pub cards_played: Vec<CardPlayedRecord>,
+    pub spells_cast: Vec<SpellCastRecord>,
+    /// Dans l'ordre des morts
+    pub minions_died: Vec<MinionDiedRecord>,
+    pub damage: Vec<DamageRecord>,
+    pub cards_drawn: Vec<CardDrawnRecord>,
+    /// Un sort est en cours de résolution : sa cible reste à noter
+    #[serde(skip)]
+    resolving_spell: bool,
+    /// Un pouvoir héroïque est en cours de résolution
+    #[serde(skip)]
+    resolving_hero_power: bool,
+    /// Des cartes sont rejouées : leurs cibles ne modifient pas les sorts enregistrés
+    #[serde(skip)]
+    replaying: bool,
+}
+
+impl GameHistory {
+    /// Numéro du tour en cours (0 avant le premier tour).
+    pub fn current_turn(&self) -> u32 {
+        self.turns.len() as u32
+    }
+
+    pub fn start_turn(&mut self, player: PlayerId) {
+        self.turns.push(player);
+    }
+
+    /// Dernier tour de `player` avant le tour en cours (« au tour précédent »).
+    pub fn last_turn_of(&self, player: &PlayerId) -> Option<u32> {
+        let current = self.turns.len().saturating_sub(1);
+        self.turns[..current]
+            .iter()
+            .rposition(|p| p == player)
+            .map(|i| i as u32 + 1)
+    }
+
+    // ---- Enregistrement ----
+
+    pub fn record_card_played(&mut self, player: &PlayerId, card: &Card) {
+        self.cards_played.push(CardPlayedRecord {
+            turn: self.current_turn(),
+            player: *player,
+            card_id: card.card_id.clone(),
+            card_type: card.card_type.clone(),
+            card_class: card.card_class.clone(),
+        });
+    }
+
+    pub fn record_spell_cast(&mut self, player: &PlayerId, card: &Card, cost: u8) {
+        self.spells_cast.push(SpellCastRecord {
+            turn: self.current_turn(),
+            player: *player,
+            card_id: card.card_id.clone(),
+            spell_school: card.spell_school.clone(),
+            cost,
+            target: None,
+        });
+    }
+
+    /// Encadre la résolution du texte du dernier sort : `note_target` n'agit qu'entre les deux.
+    pub fn begin_spell_resolution(&mut self) {
+        self.resolving_spell = true;
+    }
+
+    pub fn end_spell_resolution(&mut self) {
+        self.resolving_spell = false;
+    }
+
+    /// Un sort est-il en train de se résoudre ?
+    pub fn is_resolving_spell(&self) -> bool {
+        self.resolving_spell
+    }
+
+    /// Encadre la résolution d'un pouvoir héroïque.
+    pub fn begin_hero_power_resolution(&mut self) {
+        self.resolving_hero_power = true;
+    }
+
+    pub fn end_hero_power_resolution(&mut self) {
+        self.resolving_hero_power = false;
+    }
+
+    pub fn is_resolving_hero_power(&self) -> bool {
+        self.resolving_hero_power
+    }
+
+    /// Encadre le rejeu de cartes (Tess Greymane) : `note_target` n'y agit pas.
+    pub fn begin_replay(&mut self) {
+        self.replaying = true;
+    }
+
+    pub fn end_replay(&mut self) {
+        self.replaying = false;
+    }
+
+    /// Cible choisie par un effet ; retenue si elle est la première du sort en cours.
+    pub fn note_target(&mut self, target: Character) {
+        if !self.resolving_spell || self.replaying {
+            return;
+        }
+        if let Some(spell) = self.spells_cast.last_mut() {
+            spell.target.get_or_insert(target);
+        }
+    }
+
+    pub fn record_minion_died(&mut self, owner: &PlayerId, card: &Card) {
+        self.minions_died.push(MinionDiedRecord {
+            turn: self.current_turn(),
+            owner: *owner,
+            card_id: card.card_id.clone(),
+            races: card.races.clone().unwrap_or_default(),
+        });
+    }
+
+    pub fn record_damage(&mut self, target: Character, dealer: PlayerId, amount: i32) {
+        if amount > 0 {
+            self.damage.push(DamageRecord { turn: self.current_turn(), target, dealer, amount });
+        }
+    }
+
+    pub fn record_draw(&mut self, player: &PlayerId, card: &Card) {
+        self.cards_drawn.push(CardDrawnRecord {
+            turn: self.current_turn(),
+            player: *player,
+            card_id: card.card_id.clone(),
+        });
+    }
+
+    // ---- Requêtes ----
+
+    /// Cartes jouées par `player` ; `turn` restreint à un tour précis.
+    pub fn cards_played_by(&self, player: &PlayerId, turn: Option<u32>) -> Vec<&CardPlayedRecord> {
+        self.cards_played
+            .iter()
+            .filter(|r| r.player == *player && turn.is_none_or(|t| r.turn == t))
+            .collect()
+    }
+
+    pub fn spells_cast_by(&self, player: &PlayerId, turn: Option<u32>) -> Vec<&SpellCastRecord> {
+        self.spells_cast
+            .iter()
+            .filter(|r| r.player == *player && turn.is_none_or(|t| r.turn == t))
+            .collect()
+    }
+
+    /// « Si vous avez lancé un sort au tour précédent »
+    pub fn cast_spell_last_turn(&self, player: &PlayerId) -> bool {
+        self.last_turn_of(player)
+            .is_some_and(|t| !self.spells_cast_by(player, Some(t)).is_empty())
+    }
+
+    /// Serviteurs de `owner` morts (toute la partie, ou depuis le tour `since` inclus).
+    pub fn minions_died_of(&self, owner: &PlayerId, since: Option<u32>) -> Vec<&MinionDiedRecord> {
+        self.minions_died
+            .iter()
+            .filter(|r| r.owner == *owner && since.is_none_or(|t| r.turn >= t))
+            .collect()
+    }
+
+    /// Dégâts subis par `target` (toute la partie, ou pendant `turn`).
+    pub fn damage_taken_by(&self, target: &Character, turn: Option<u32>) -> i32 {
+        self.damage
+            .iter()
+            .filter(|r| r.target == *target && turn.is_none_or(|t| r.turn == t))
+            .map(|r| r.amount)
+            .sum()
+    }
+
+    /// Dégâts infligés par `dealer` (toute la partie, ou pendant `turn`).
+    pub fn damage_dealt_by(&self, dealer: &PlayerId, turn: Option<u32>) -> i32 {
+        self.damage
+            .iter()
+            .filter(|r| r.dealer == *dealer && turn.is_none_or(|t| r.turn == t))
+            .map(|r| r.amount)
+            .sum()
+    }
+}
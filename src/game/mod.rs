@@ -1,10 +1,13 @@
-pub mod card;
-pub mod enums;
-pub mod effects;
-pub mod engine;
-pub mod player;
-pub mod state;
-pub mod targets;
-pub mod triggers;
-pub mod keywords;
-pub mod event;
+pub mod card;
+pub mod enums;
+pub mod effects;
+pub mod engine;
+pub mod player;
+pub mod state;
+pub mod targets;
+pub mod triggers;
+pub mod keywords;
+pub mod event;
+pub mod condition;
+pub mod filter;
+pub mod history;
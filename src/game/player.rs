@@ -1,151 +1,521 @@
-use crate::game::card::Card;
-use crate::game::state::PlayerId;
-use crate::game::enums::CardClass;
-use crate::game::engine::utils::IdString;
-
-
-pub const MAX_HAND: usize = 10;
-pub const MAX_BOARD: usize = 7;
-pub const MAX_MANA: u8 = 10;
-pub const MAX_HEALTH: i32 = 30;
-
-#[derive(Debug)]
-pub struct Player {
-    pub id: PlayerId,
-    pub stats: PlayerStats,
-    pub zones: PlayerZones,
-    pub fatigue_counter: u32,
-    pub overload_pending: u8,
-    pub hero_has_attacked: bool,
-}
-
-#[derive(Debug)]
-pub struct PlayerStats {
-    pub health: i32,
-    pub max_health: i32,
-    pub armor: u32,
-    pub mana: Mana,
-    pub class: CardClass,
-    pub weapon: Option<Card>,
-    pub hero_power: Option<Card>,
-    pub extra_turns: i32,
-}
-
-#[derive(Debug)]
-pub struct Mana {
-    pub current: u8,
-    pub max: u8,
-}
-
-impl Mana {
-    pub fn new() -> Self { Self { current: 0, max: 0 } }
-    pub fn refill(&mut self) { self.current = self.max; }
-    pub fn spend(&mut self, amount: u8) -> bool {
-        if self.current >= amount { self.current -= amount; true } else { false }
-    }
-    pub fn gain_max(&mut self, amount: u8) {
-        self.max = (self.max + amount).min(MAX_MANA);
-    }
-}
-
-#[derive(Debug)]
-pub struct PlayerZones {
-    pub deck: Vec<Card>,
-    pub hand: Vec<Card>,
-    pub board: Vec<Card>,
-    pub graveyard: Vec<Card>,
-    pub secrets: Vec<Card>,
-    pub set_aside: Vec<Card>,
-    pub dormant: Vec<Card>,
-}
-
-impl Player {
-    pub fn id_string(&self) -> String {
-        self.id.id_string()
-    }
-
-    pub fn new(id: PlayerId, deck: Vec<Card>, class: CardClass) -> Self {
-        Self {
-            id,
-            stats: PlayerStats {
-                health: MAX_HEALTH,
-                max_health: MAX_HEALTH,
-                armor: 0,
-                mana: Mana::new(),
-                class,
-                weapon: None,
-                hero_power: None,
-                extra_turns: 0,
-            },
-            zones: PlayerZones {
-                deck,
-                hand: Vec::new(),
-                board: Vec::new(),
-                graveyard: Vec::new(),
-                secrets: Vec::new(),
-                set_aside: Vec::new(),
-                dormant: Vec::new(),
-            },
-            fatigue_counter: 0,
-            overload_pending: 0,
-            hero_has_attacked: false,
-        }
-    }
-
-    pub fn draw_card(&mut self) -> Option<Card> {
-        if let Some(card) = self.zones.deck.pop() {
-            if self.zones.hand.len() < MAX_HAND {
-                self.zones.hand.push(card.clone());
-            } else {
-                self.zones.graveyard.push(card.clone());
-            }
-            Some(card)
-        } else {
-            self.fatigue_counter += 1;
-            self.take_damage(self.fatigue_counter as i32);
-            None
-        }
-    }
-
-    pub fn start_turn(&mut self) {
-        if self.stats.mana.max < MAX_MANA {
-            self.stats.mana.gain_max(1);
-        }
-        self.stats.mana.refill();
-        self.hero_has_attacked = false;
-        for minion in self.zones.board.iter_mut() {
-        minion.status.attacks_this_turn = 0;
-        }
-    }
-
-    pub fn heal(&mut self, amount: i32) {
-        if self.stats.health >= self.stats.max_health {
-            return;                      // ← stoppe tout de suite
-        }
-
-        let before = self.stats.health;
-        self.stats.health = (self.stats.health + amount).min(self.stats.max_health);
-
-        println!(
-            "Le joueur {} est soigné de {} PV (PV avant : {}, après : {})",
-            self.id_string(),
-            amount,
-            before,
-            self.stats.health
-        );
-    }
-
-    pub fn take_damage(&mut self, amount: i32) {
-        let mut remaining = amount;
-        if self.stats.armor > 0 {
-            let absorbed = remaining.min(self.stats.armor as i32);
-            self.stats.armor -= absorbed as u32;
-            remaining -= absorbed;
-        }
-        self.stats.health -= remaining.max(0);
-    }
-
-    pub fn is_dead(&self) -> bool {
-        self.stats.health <= 0
-    }
-}
+use crate::game::card::Card;
+use crate::game::keywords::Keywords;
+use crate::game::effects::Effect;
+use crate::game::state::PlayerId;
+use crate::game::targets::Target;
+use crate::game::enums::{CardClass, Zone};
+use crate::game::engine::utils::IdString;
+use crate::game::engine::cost::{CostModifier, AURA_USED_TAG};
+use crate::game::engine::deck::STARTING_DECK_TAG;
+use crate::game::engine::location::LOCATION_COOLDOWN_TAG;
+
+
+pub const MAX_HAND: usize = 10;
+pub const MAX_BOARD: usize = 7;
+pub const MAX_MANA: u8 = 10;
+pub const MAX_HEALTH: i32 = 30;
+pub const MAX_SECRETS: usize = 5;
+
+#[derive(Debug)]
+pub struct Player {
+    pub id: PlayerId,
+    pub stats: PlayerStats,
+    pub zones: PlayerZones,
+    pub fatigue_counter: u32,
+    pub overload_pending: u8,
+    pub hero_attacks_this_turn: u8,
+    pub hero_frozen: bool,
+    pub cost_modifiers: Vec<CostModifier>,
+    pub cards_played_this_turn: u32,   // Combo
+    pub corpses: u32,                  // Cadavres disponibles (Chevalier de la mort)
+    pub corpses_spent_this_game: u32,
+    pub corpses_spent_last: u32,       // dépensés par le dernier « spend_corpse » résolu
+    pub source_position: Option<usize>, // serviteur dont les effets se résolvent (« self »)
+    pub summon_position: Option<usize>, // emplacement des invocations de l'effet en cours
+    pub deathrattle_source: Option<usize>, // carte (cimetière) dont le Râle d'agonie se résout
+}
+
+#[derive(Debug)]
+pub struct PlayerStats {
+    pub health: i32,
+    pub max_health: i32,
+    pub armor: u32,
+    pub mana: Mana,
+    pub class: CardClass,
+    pub weapon: Option<Card>,
+    pub hero_power: Option<Card>,
+    pub hero: Option<Card>,      // carte Héros jouée (remplace le héros de départ)
+    pub hero_attack: i32,        // attaque du héros pour ce tour, hors arme
+    pub hero_base_attack: i32,   // attaque du héros gagnée sans durée, hors arme
+    pub hero_keywords: Keywords, // Windfury, Immune (pendant ses attaques)… accordés au héros pour le tour
+    pub extra_turns: i32,
+}
+
+#[derive(Debug)]
+pub struct Mana {
+    pub current: u8,
+    pub max: u8,
+    pub locked: u8,      // cristaux verrouillés par la Surcharge ce tour-ci
+    pub temporary: u8,   // mana « ce tour-ci uniquement » encore disponible (The Coin…)
+}
+
+impl Mana {
+    pub fn new() -> Self { Self { current: 0, max: 0, locked: 0, temporary: 0 } }
+    pub fn refill(&mut self) { self.current = self.max.saturating_sub(self.locked); }
+    /// Paie `amount` ; la mana temporaire est dépensée en premier.
+    pub fn spend(&mut self, amount: u8) -> bool {
+        if self.current >= amount {
+            self.current -= amount;
+            self.temporary = self.temporary.saturating_sub(amount);
+            true
+        } else {
+            false
+        }
+    }
+    pub fn gain_max(&mut self, amount: u8) {
+        self.max = (self.max + amount).min(MAX_MANA);
+    }
+    /// Mana utilisable ce tour uniquement. Ne touche pas au maximum : fonctionne
+    /// donc aussi à 10 cristaux, dans la limite de `MAX_MANA` disponibles.
+    pub fn gain_temporary(&mut self, amount: u8) -> u8 {
+        let gained = amount.min(MAX_MANA.saturating_sub(self.current));
+        self.current += gained;
+        self.temporary += gained;
+        gained
+    }
+    /// Cristaux permanents, pleins ou vides. Renvoie le nombre réellement gagné (cap `MAX_MANA`).
+    pub fn gain_crystals(&mut self, amount: u8, empty: bool) -> u8 {
+        let before = self.max;
+        self.gain_max(amount);
+        let gained = self.max - before;
+        if !empty {
+            self.current = (self.current + gained).min(MAX_MANA);
+        }
+        gained
+    }
+    /// Détruit des cristaux : les pleins disparaissent avec eux si nécessaire.
+    pub fn destroy_crystals(&mut self, amount: u8) -> u8 {
+        let destroyed = amount.min(self.max);
+        self.max -= destroyed;
+        self.locked = self.locked.min(self.max);
+        self.current = self.current.min(self.max + self.temporary);
+        destroyed
+    }
+    /// Vide les cristaux (ils restent, mais sans mana disponible).
+    pub fn empty_crystals(&mut self, amount: u8) -> u8 {
+        let emptied = amount.min(self.current);
+        self.current -= emptied;
+        self.temporary = self.temporary.min(self.current);
+        emptied
+    }
+    /// Fin de tour : la mana temporaire non dépensée expire.
+    pub fn expire_temporary(&mut self) {
+        self.current = self.current.saturating_sub(self.temporary);
+        self.temporary = 0;
+    }
+    /// Verrouille `amount` cristaux (jamais plus que le maximum).
+    pub fn lock(&mut self, amount: u8) {
+        self.locked = amount.min(self.max);
+    }
+    /// Déverrouille les cristaux surchargés et les rend disponibles ; renvoie le nombre libéré.
+    pub fn unlock(&mut self) -> u8 {
+        let freed = self.locked;
+        self.locked = 0;
+        self.current = (self.current + freed).min(MAX_MANA);
+        freed
+    }
+}
+
+/// Transition refusée : la zone d'arrivée est pleine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZoneFull {
+    Board,
+    Hand,
+    Secrets,
+}
+
+impl ZoneFull {
+    fn of(zone: &Zone) -> Self {
+        match zone {
+            Zone::Board => ZoneFull::Board,
+            Zone::Hand => ZoneFull::Hand,
+            _ => ZoneFull::Secrets,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PlayerZones {
+    pub deck: Vec<Card>,
+    pub hand: Vec<Card>,
+    pub board: Vec<Card>,
+    pub graveyard: Vec<Card>,
+    pub secrets: Vec<Card>,
+    pub set_aside: Vec<Card>,
+    pub dormant: Vec<Card>,
+}
+
+impl Player {
+    pub fn id_string(&self) -> String {
+        self.id.id_string()
+    }
+
+    pub fn new(id: PlayerId, mut deck: Vec<Card>, class: CardClass) -> Self {
+        for card in deck.iter_mut() {
+            card.tags.insert(STARTING_DECK_TAG.to_string(), 1);
+        }
+        Self {
+            id,
+            stats: PlayerStats {
+                health: MAX_HEALTH,
+                max_health: MAX_HEALTH,
+                armor: 0,
+                mana: Mana::new(),
+                class,
+                weapon: None,
+                hero_power: None,
+                hero: None,
+                hero_attack: 0,
+                hero_base_attack: 0,
+                hero_keywords: Keywords::empty(),
+                extra_turns: 0,
+            },
+            zones: PlayerZones {
+                deck,
+                hand: Vec::new(),
+                board: Vec::new(),
+                graveyard: Vec::new(),
+                secrets: Vec::new(),
+                set_aside: Vec::new(),
+                dormant: Vec::new(),
+            },
+            fatigue_counter: 0,
+            overload_pending: 0,
+            hero_attacks_this_turn: 0,
+            hero_frozen: false,
+            cost_modifiers: Vec::new(),
+            cards_played_this_turn: 0,
+            corpses: 0,
+            corpses_spent_this_game: 0,
+            corpses_spent_last: 0,
+            source_position: None,
+            summon_position: None,
+            deathrattle_source: None,
+        }
+    }
+
+    pub fn draw_card(&mut self) -> Option<Card> {
+        if let Some(card) = self.zones.deck.pop() {
+            if self.put_card(card.clone(), &Zone::Hand, None).is_err() {
+                self.zones.graveyard.push(card.clone());
+            }
+            Some(card)
+        } else {
+            self.fatigue_counter += 1;
+            self.take_damage(self.fatigue_counter as i32);
+            None
+        }
+    }
+
+    pub fn start_turn(&mut self) {
+        if self.stats.mana.max < MAX_MANA {
+            self.stats.mana.gain_max(1);
+        }
+        // La Surcharge du tour précédent verrouille des cristaux pour ce tour
+        self.stats.mana.lock(self.overload_pending);
+        self.overload_pending = 0;
+        if self.stats.mana.locked > 0 {
+            println!("{} a {} cristal(aux) verrouillé(s) par la Surcharge", self.id_string(), self.stats.mana.locked);
+        }
+        self.stats.mana.refill();
+        self.hero_attacks_this_turn = 0;
+        self.cards_played_this_turn = 0;
+        for minion in self.zones.board.iter_mut() {
+        minion.status.attacks_this_turn = 0;
+        minion.tags.remove(AURA_USED_TAG);
+        minion.tags.remove(LOCATION_COOLDOWN_TAG);
+        }
+    }
+
+    pub fn end_turn(&mut self) {
+        if self.stats.mana.temporary > 0 {
+            println!("{} perd {} mana temporaire non dépensée", self.id_string(), self.stats.mana.temporary);
+        }
+        self.stats.mana.expire_temporary();
+        self.stats.hero_attack = 0;
+        self.stats.hero_keywords = Keywords::empty();
+
+        // Le Gel tombe à la fin du tour du contrôleur, si le personnage n'a pas attaqué
+        if self.hero_attacks_this_turn == 0 {
+            self.hero_frozen = false;
+        }
+        for minion in self.zones.board.iter_mut() {
+            if minion.status.attacks_this_turn == 0 {
+                minion.status.frozen = false;
+            }
+        }
+    }
+
+    /// Nom du héros affiché : la dernière carte Héros jouée, sinon la classe de départ.
+    pub fn hero_name(&self) -> String {
+        match &self.stats.hero {
+            Some(hero) => hero.name.clone(),
+            None => format!("{:?}", self.stats.class),
+        }
+    }
+
+    pub fn gain_armor(&mut self, amount: u32) {
+        self.stats.armor += amount;
+        println!("{} gagne {} Armure (total {})", self.id_string(), amount, self.stats.armor);
+    }
+
+    /// Ajoute de la Surcharge qui verrouillera des cristaux au prochain tour.
+    pub fn add_overload(&mut self, amount: u8) {
+        self.overload_pending = self.overload_pending.saturating_add(amount);
+        println!("{} est surchargé de {} (total en attente : {})", self.id_string(), amount, self.overload_pending);
+    }
+
+    /// Déverrouille la mana surchargée : cristaux verrouillés ce tour et Surcharge en attente.
+    pub fn unlock_overload(&mut self) {
+        let freed = self.stats.mana.unlock();
+        self.overload_pending = 0;
+        println!("{} déverrouille {} cristal(aux) surchargé(s)", self.id_string(), freed);
+    }
+
+    pub fn gain_corpses(&mut self, amount: u32) {
+        self.corpses += amount;
+        println!("{} gagne {} Cadavre(s) (total {})", self.id_string(), amount, self.corpses);
+    }
+
+    pub fn spend_corpses(&mut self, amount: u32) {
+        self.corpses -= amount;
+        self.corpses_spent_this_game += amount;
+        self.corpses_spent_last = amount;
+        println!("{} dépense {} Cadavre(s) (reste {})", self.id_string(), amount, self.corpses);
+    }
+
+    /// Multiplicateur de gain de Cadavres (auras « modify_corpse_gain » du board).
+    pub fn corpse_gain_multiplier(&self) -> u32 {
+        self.zones
+            .board
+            .iter()
+            .filter(|c| !c.status.silenced)
+            .flat_map(|c| c.effects.iter())
+            .filter_map(|e| match e {
+                Effect::Aura { effect, .. } => match Effect::from_value(effect) {
+                    Effect::ModifyCorpseGain { multiplier } => Some(multiplier.unwrap_or(1).max(1) as u32),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .max()
+            .unwrap_or(1)
+    }
+
+    /// Pose `card` sur le board à `position` (bornée), à droite par défaut ; renvoie son index.
+    pub fn place_on_board(&mut self, card: Card, position: Option<usize>) -> usize {
+        let index = position.unwrap_or(self.zones.board.len()).min(self.zones.board.len());
+        self.zones.board.insert(index, card);
+        if let Some(src) = self.source_position.as_mut().filter(|src| **src >= index) {
+            *src += 1;
+        }
+        self.refresh_adjacency_auras();
+        index
+    }
+
+    /// Invocation par un effet : à l'emplacement en cours (à droite de la source, ou
+    /// à la place du serviteur mort) ; les invocations suivantes se rangent à sa droite.
+    /// Board plein : l'invocation échoue.
+    pub fn summon_minion(&mut self, card: Card) -> Result<usize, ZoneFull> {
+        let index = self.put_card(card, &Zone::Board, self.summon_position)?;
+        if self.summon_position.is_some() {
+            self.summon_position = Some(index + 1);
+        }
+        Ok(index)
+    }
+
+    /// Capacité d'une zone (`None` = illimitée).
+    pub fn zone_capacity(zone: &Zone) -> Option<usize> {
+        match zone {
+            Zone::Board => Some(MAX_BOARD),
+            Zone::Hand => Some(MAX_HAND),
+            Zone::Secret => Some(MAX_SECRETS),
+            _ => None,
+        }
+    }
+
+    pub fn zone(&self, zone: &Zone) -> &Vec<Card> {
+        match zone {
+            Zone::Hand => &self.zones.hand,
+            Zone::Board => &self.zones.board,
+            Zone::Deck => &self.zones.deck,
+            Zone::Graveyard => &self.zones.graveyard,
+            Zone::Secret => &self.zones.secrets,
+            Zone::Dormant => &self.zones.dormant,
+            Zone::SetAside | Zone::Unknown => &self.zones.set_aside,
+        }
+    }
+
+    fn zone_mut(&mut self, zone: &Zone) -> &mut Vec<Card> {
+        match zone {
+            Zone::Hand => &mut self.zones.hand,
+            Zone::Board => &mut self.zones.board,
+            Zone::Deck => &mut self.zones.deck,
+            Zone::Graveyard => &mut self.zones.graveyard,
+            Zone::Secret => &mut self.zones.secrets,
+            Zone::Dormant => &mut self.zones.dormant,
+            Zone::SetAside | Zone::Unknown => &mut self.zones.set_aside,
+        }
+    }
+
+    /// Reste-t-il une place dans `zone` ?
+    pub fn has_room(&self, zone: &Zone) -> bool {
+        Self::zone_capacity(zone).is_none_or(|max| self.zone(zone).len() < max)
+    }
+
+    /// Ajoute `card` à `to` (à `position` si précisée, à la fin sinon) ; renvoie son index.
+    /// Zone pleine : la carte n'arrive nulle part (à l'appelant de la brûler s'il le faut).
+    pub fn put_card(&mut self, card: Card, to: &Zone, position: Option<usize>) -> Result<usize, ZoneFull> {
+        if !self.has_room(to) {
+            return Err(ZoneFull::of(to));
+        }
+        if *to == Zone::Board {
+            return Ok(self.place_on_board(card, position));
+        }
+        let zone = self.zone_mut(to);
+        let index = position.unwrap_or(zone.len()).min(zone.len());
+        zone.insert(index, card);
+        Ok(index)
+    }
+
+    /// Déplace la carte `index` de `from` vers `to`. Sans place à l'arrivée,
+    /// elle reste dans sa zone de départ.
+    pub fn move_card(&mut self, from: &Zone, index: usize, to: &Zone, position: Option<usize>) -> Result<usize, ZoneFull> {
+        if from != to && !self.has_room(to) {
+            return Err(ZoneFull::of(to));
+        }
+        let card = match from {
+            Zone::Board => self.remove_from_board(index),
+            _ => self.zone_mut(from).remove(index),
+        };
+        self.put_card(card, to, position)
+    }
+
+    /// Retire la carte en `index` du board en gardant la source et les auras à jour.
+    pub fn remove_from_board(&mut self, index: usize) -> Card {
+        let card = self.zones.board.remove(index);
+        self.source_position = match self.source_position {
+            Some(src) if src == index => None,
+            Some(src) if src > index => Some(src - 1),
+            other => other,
+        };
+        self.refresh_adjacency_auras();
+        card
+    }
+
+    /// Index du serviteur « self » : celui dont les effets se résolvent, sinon le dernier posé.
+    pub fn source_minion_index(&self) -> Option<usize> {
+        self.source_position
+            .filter(|&i| self.zones.board.get(i).is_some_and(|c| !c.is_location()))
+            .or_else(|| self.zones.board.iter().rposition(|c| !c.is_location()))
+    }
+
+    /// Serviteurs voisins de l'emplacement `index` (les lieux ne comptent pas).
+    pub fn adjacent_minions(&self, index: usize) -> Vec<usize> {
+        [index.checked_sub(1), Some(index + 1)]
+            .into_iter()
+            .flatten()
+            .filter(|&i| self.zones.board.get(i).is_some_and(|c| !c.is_location()))
+            .collect()
+    }
+
+    /// Recalcule les bonus d'attaque des auras « adjacent_friendly_minion » (Loup alpha…).
+    pub fn refresh_adjacency_auras(&mut self) {
+        let bonuses: Vec<i32> = (0..self.zones.board.len())
+            .map(|i| {
+                if self.zones.board[i].is_location() {
+                    return 0;
+                }
+                self.adjacent_minions(i)
+                    .into_iter()
+                    .map(|j| adjacent_aura_attack(&self.zones.board[j]))
+                    .sum()
+            })
+            .collect();
+        for (minion, bonus) in self.zones.board.iter_mut().zip(bonuses) {
+            minion.status.aura_attack = bonus;
+        }
+    }
+
+    /// Un serviteur allié vient de mourir : il laisse un Cadavre, sauf mention contraire.
+    pub fn on_friendly_minion_death(&mut self, dead: &Card) {
+        if dead.is_location() || dead.effects.contains(&Effect::NoCorpse) {
+            return;
+        }
+        let gained = self.corpse_gain_multiplier();
+        self.gain_corpses(gained);
+    }
+
+    pub fn heal(&mut self, amount: i32) {
+        if self.stats.health >= self.stats.max_health {
+            return;                      // ← stoppe tout de suite
+        }
+
+        let before = self.stats.health;
+        self.stats.health = (self.stats.health + amount).min(self.stats.max_health);
+
+        println!(
+            "Le joueur {} est soigné de {} PV (PV avant : {}, après : {})",
+            self.id_string(),
+            amount,
+            before,
+            self.stats.health
+        );
+    }
+
+    pub fn take_damage(&mut self, amount: i32) {
+        let mut remaining = amount;
+        if self.stats.armor > 0 {
+            let absorbed = remaining.min(self.stats.armor as i32);
+            self.stats.armor -= absorbed as u32;
+            remaining -= absorbed;
+        }
+        self.stats.health -= remaining.max(0);
+    }
+
+    /// Un Secret peut être posé : zone non pleine et pas de doublon.
+    pub fn can_add_secret(&self, card: &Card) -> bool {
+        self.zones.secrets.len() < MAX_SECRETS
+            && !self.zones.secrets.iter().any(|s| s.card_id == card.card_id)
+    }
+
+    /// Secrets tels que vus par `viewer` : l'adversaire n'en voit que le nombre.
+    pub fn secrets_view(&self, viewer: PlayerId) -> Vec<String> {
+        self.zones
+            .secrets
+            .iter()
+            .map(|s| if viewer == self.id { s.name.clone() } else { "Secret".to_string() })
+            .collect()
+    }
+
+    pub fn is_dead(&self) -> bool {
+        self.stats.health <= 0
+    }
+}
+
+/// Attaque donnée aux voisins par les auras d'un serviteur (non réduit au silence).
+fn adjacent_aura_attack(card: &Card) -> i32 {
+    if card.status.silenced {
+        return 0;
+    }
+    card.effects
+        .iter()
+        .filter_map(|e| match e {
+            Effect::Aura { effect, .. } => match Effect::from_value(effect) {
+                Effect::Buff { attack, target: Some(Target::AdjacentFriendlyMinion), .. } => attack,
+                _ => None,
+            },
+            _ => None,
+        })
+        .sum()
+}
@@ -1,3 +1,3 @@
-pub mod data;
-pub mod game;
-pub mod logger;
+pub mod data;
+pub mod game;
+pub mod logger;
@@ -0,0 +1,118 @@
+use hearthstone_sim::game::card::Card;
+use hearthstone_sim::game::effects::{apply_effect, remove_dead_minions};
+use hearthstone_sim::game::engine::attack::perform_attack_phase;
+use hearthstone_sim::game::engine::choose::RandomChooser;
+use hearthstone_sim::game::engine::events::dispatch_events;
+use hearthstone_sim::game::enums::CardClass;
+use hearthstone_sim::game::state::PlayerId;
+use serde_json::json;
+use std::collections::HashMap;
+
+mod common;
+use common::{board_ids, effect, new_state};
+
+fn minion(id: &str, attack: i32, health: i32, extra: serde_json::Value) -> Card {
+    let mut card = common::minion(id, attack, health, extra);
+    card.status.just_played = false;
+    card
+}
+
+#[test]
+fn cleave_also_damages_the_defenders_neighbours() {
+    let mut state = new_state(CardClass::Neutral);
+    let (p1, p2) = (PlayerId::Player1, PlayerId::Player2);
+    let gnomelia = minion("GNOMELIA", 4, 10, json!({"effects": [{"type": "cleave"}]}));
+    state.players.get_mut(&p1).unwrap().place_on_board(gnomelia, None);
+    let enemy = state.players.get_mut(&p2).unwrap();
+    enemy.place_on_board(minion("LEFT", 1, 2, json!({})), None);
+    enemy.place_on_board(minion("WALL", 1, 5, json!({"mechanics": ["Taunt"]})), None);
+    enemy.place_on_board(minion("RIGHT", 1, 2, json!({})), None);
+
+    perform_attack_phase(&mut state, &p1, &p2, &RandomChooser, &HashMap::new());
+
+    assert_eq!(board_ids(&state, &p2), ["WALL"]);
+    assert_eq!(state.players[&p2].zones.board[0].effective_health(), 1);
+    // pas de riposte des voisins
+    assert_eq!(state.players[&p1].zones.board[0].effective_health(), 9);
+}
+
+#[test]
+fn on_attack_trigger_resolves_first_and_can_redirect_the_attack() {
+    let mut state = new_state(CardClass::Neutral);
+    let (p1, p2) = (PlayerId::Player1, PlayerId::Player2);
+    let trickster = minion("TRICKSTER", 3, 3, json!({"effects": [
+        {"type": "gain_armor", "amount": 2, "trigger": "on_attack"},
+        {"type": "redirect_attack", "trigger": "on_attack"}
+    ]}));
+    state.players.get_mut(&p1).unwrap().place_on_board(trickster, None);
+    state.players.get_mut(&p2).unwrap().place_on_board(minion("WALL", 2, 5, json!({"mechanics": ["Taunt"]})), None);
+
+    perform_attack_phase(&mut state, &p1, &p2, &RandomChooser, &HashMap::new());
+
+    assert_eq!(state.players[&p1].stats.armor, 2);
+    assert_eq!(state.players[&p2].stats.health, 27);
+    assert_eq!(state.players[&p2].zones.board[0].effective_health(), 5);
+    assert_eq!(state.players[&p1].zones.board[0].effective_health(), 3);
+}
+
+#[test]
+fn after_attack_and_kill_triggers_fire_once_combat_is_over() {
+    let mut state = new_state(CardClass::Neutral);
+    let (p1, p2) = (PlayerId::Player1, PlayerId::Player2);
+    let finja = minion("FINJA", 3, 3, json!({"effects": [
+        {"type": "gain_armor", "amount": 1, "trigger": "after_self_attack"},
+        {"type": "gain_armor", "amount": 10, "trigger": "on_kill"}
+    ]}));
+    state.players.get_mut(&p1).unwrap().place_on_board(finja, None);
+    state.players.get_mut(&p2).unwrap().place_on_board(minion("WISP", 1, 1, json!({})), None);
+
+    perform_attack_phase(&mut state, &p1, &p2, &RandomChooser, &HashMap::new());
+
+    assert!(state.players[&p2].zones.board.is_empty());
+    assert_eq!(state.players[&p1].stats.armor, 11);
+}
+
+#[test]
+fn follow_up_and_forced_attacks_do_not_use_the_minions_attack() {
+    let mut state = new_state(CardClass::Neutral);
+    let (p1, p2) = (PlayerId::Player1, PlayerId::Player2);
+    let inquisitor = minion("INQUISITOR", 8, 8, json!({"effects": [{"type": "follow_up_attack", "trigger": "after_hero_attack"}]}));
+    let player = state.players.get_mut(&p1).unwrap();
+    player.place_on_board(inquisitor, None);
+    player.zones.board[0].status.attacks_this_turn = 1;
+    player.stats.hero_attack = 2;
+
+    perform_attack_phase(&mut state, &p1, &p2, &RandomChooser, &HashMap::new());
+    assert_eq!(state.players[&p2].stats.health, 20);
+    assert_eq!(state.players[&p1].zones.board[0].status.attacks_this_turn, 1);
+
+    let enemy = state.players.get_mut(&p2).unwrap();
+    enemy.place_on_board(minion("BIG", 1, 6, json!({})), None);
+    enemy.place_on_board(minion("SMALL", 1, 1, json!({})), None);
+    state.players.get_mut(&p1).unwrap().source_position = Some(0);
+    let muncher = effect(json!({"type": "attack_enemy", "target": "lowest_health_enemy", "trigger": "end_of_turn"}));
+    apply_effect(&mut state, &p1, &muncher, &RandomChooser, &HashMap::new());
+
+    assert_eq!(board_ids(&state, &p2), ["BIG"]);
+    assert_eq!(state.players[&p1].zones.board[0].effective_health(), 7);
+}
+
+#[test]
+fn transfer_attack_gives_the_dead_minions_attack() {
+    let mut state = new_state(CardClass::Neutral);
+    let pid = PlayerId::Player1;
+    let servant = minion("SERVANT", 2, 1, json!({"effects": [
+        {"type": "transfer_attack", "trigger": "deathrattle", "target": "friendly_minion", "random": true, "amount": "self_attack"}
+    ]}));
+    let player = state.players.get_mut(&pid).unwrap();
+    player.place_on_board(servant, None);
+    player.place_on_board(minion("WISP", 1, 1, json!({})), None);
+    player.zones.board[0].status.attack_modifiers = 1;
+
+    player.zones.board[0].status.current_health = Some(0);
+    remove_dead_minions(&mut state);
+    dispatch_events(&mut state, &RandomChooser);
+
+    assert_eq!(board_ids(&state, &pid), ["WISP"]);
+    assert_eq!(state.players[&pid].zones.board[0].effective_attack(), 4);
+}
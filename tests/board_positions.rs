@@ -0,0 +1,100 @@
+use hearthstone_sim::data::card_template::CardTemplate;
+use hearthstone_sim::game::card::Card;
+use hearthstone_sim::game::effects::remove_dead_minions;
+use hearthstone_sim::game::engine::choose::RandomChooser;
+use hearthstone_sim::game::engine::events::dispatch_events;
+use hearthstone_sim::game::engine::play_card::play_card_at_position;
+use hearthstone_sim::game::enums::CardClass;
+use hearthstone_sim::game::keywords::Keywords;
+use hearthstone_sim::game::state::{GameState, PlayerId};
+use serde_json::json;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+mod common;
+use common::{board_ids, new_state_with_mana};
+
+fn template(id: &str, effects: serde_json::Value) -> CardTemplate {
+    common::template(json!({"card_id": id, "health": 2, "effects": effects}))
+}
+
+fn minion(id: &str, effects: serde_json::Value) -> Card {
+    template(id, effects).to_card()
+}
+
+fn new_state(board: &[&str], hand: Card) -> GameState {
+    let mut state = new_state_with_mana(CardClass::Neutral);
+    let p1 = state.players.get_mut(&PlayerId::Player1).unwrap();
+    for id in board {
+        p1.place_on_board(minion(id, json!([])), None);
+    }
+    p1.zones.hand.push(hand);
+    state
+}
+
+#[test]
+fn played_minion_lands_at_chosen_position_and_buffs_neighbours() {
+    let wolf = minion("WOLF", json!([
+        {"type": "aura", "effect": {"type": "buff", "attack": 1, "target": "adjacent_friendly_minion"}}
+    ]));
+    let mut state = new_state(&["A", "B", "C"], wolf);
+    let pid = PlayerId::Player1;
+
+    assert!(play_card_at_position(&mut state, &pid, 0, Some(1), &RandomChooser, &HashMap::new()));
+    assert_eq!(board_ids(&state, &pid), ["A", "WOLF", "B", "C"]);
+
+    let attacks: Vec<i32> = state.players[&pid].zones.board.iter().map(|c| c.effective_attack()).collect();
+    assert_eq!(attacks, [2, 1, 2, 1]);
+    assert_eq!(state.players[&pid].adjacent_minions(1), vec![0, 2]);
+
+    // B meurt : C devient voisin du loup
+    state.players.get_mut(&pid).unwrap().zones.board[2].status.current_health = Some(0);
+    remove_dead_minions(&mut state);
+    let attacks: Vec<i32> = state.players[&pid].zones.board.iter().map(|c| c.effective_attack()).collect();
+    assert_eq!(attacks, [2, 1, 2]);
+}
+
+#[test]
+fn adjacent_battlecry_and_summons_next_to_source() {
+    let defender = minion("DEFENDER", json!([
+        {"type": "grant_mechanic", "mechanic": "taunt", "target": "adjacent_friendly_minion", "trigger": "battlecry"},
+        {"type": "summon", "card_id": "TOKEN", "amount": 2, "trigger": "battlecry"}
+    ]));
+    let mut state = new_state(&["A", "B", "C"], defender);
+    let pid = PlayerId::Player1;
+    // Les Cris de guerre passent par dispatch_events, qui lit les templates de l'état
+    state.card_templates = Rc::new(HashMap::from([("TOKEN".to_string(), template("TOKEN", json!([])))]));
+    let templates = Rc::clone(&state.card_templates);
+
+    assert!(play_card_at_position(&mut state, &pid, 0, Some(1), &RandomChooser, &templates));
+    let board = &state.players[&pid].zones.board;
+    let taunts: Vec<&str> = board
+        .iter()
+        .filter(|c| c.has_kw(Keywords::TAUNT))
+        .map(|c| c.card_id.as_str())
+        .collect();
+    assert_eq!(taunts, ["A", "B"]);
+    assert_eq!(board_ids(&state, &pid), ["A", "DEFENDER", "TOKEN", "TOKEN", "B", "C"]);
+}
+
+#[test]
+fn deathrattle_summons_take_the_dead_minion_place() {
+    let mut state = new_state(&["A"], minion("UNUSED", json!([])));
+    let pid = PlayerId::Player1;
+    state.card_templates = Rc::new(HashMap::from([("TOKEN".to_string(), template("TOKEN", json!([])))]));
+    {
+        let player = state.players.get_mut(&pid).unwrap();
+        player.place_on_board(
+            minion("NERUBIAN_EGG", json!([{"type": "summon", "card_id": "TOKEN", "trigger": "deathrattle"}])),
+            None,
+        );
+        player.place_on_board(minion("C", json!([])), None);
+        player.zones.board[1].status.current_health = Some(0);
+    }
+
+    remove_dead_minions(&mut state);
+    dispatch_events(&mut state, &RandomChooser);
+
+    assert_eq!(board_ids(&state, &pid), ["A", "TOKEN", "C"]);
+    assert_eq!(state.players[&pid].zones.graveyard.last().unwrap().card_id, "NERUBIAN_EGG");
+}
@@ -0,0 +1,86 @@
+use hearthstone_sim::data::card_template::load_card_templates;
+use hearthstone_sim::game::effects::apply_effect;
+use hearthstone_sim::game::engine::choose::{Choice, Chooser, RandomChooser};
+use hearthstone_sim::game::engine::play_card::play_card_at_index;
+use hearthstone_sim::game::enums::CardClass;
+use hearthstone_sim::game::keywords::Keywords;
+use hearthstone_sim::game::state::{GameState, PlayerId};
+use std::cell::RefCell;
+
+mod common;
+use common::{new_state_with_mana};
+
+/// Prend toujours la première option et compte les choix proposés.
+struct FirstOption {
+    offered: RefCell<Vec<usize>>,
+}
+
+impl Chooser for FirstOption {
+    fn choose(&self, _state: &GameState, choice: Choice) -> Choice {
+        match choice {
+            Choice::ChooseOne { options } => {
+                self.offered.borrow_mut().push(options.len());
+                Choice::ChooseOne { options: vec![options[0].clone()] }
+            }
+            other => other,
+        }
+    }
+}
+
+#[test]
+fn siamat_gains_two_chosen_keywords() {
+    let templates = load_card_templates("cards/CORE2025.json").unwrap();
+    let mut state = new_state_with_mana(CardClass::Druid);
+    let pid = PlayerId::Player1;
+    let siamat = templates["CORE_ULD_178"].to_card();
+    let battlecry = siamat.triggers[0].effect.clone();
+    state.players.get_mut(&pid).unwrap().zones.board.push(siamat);
+
+    let chooser = FirstOption { offered: RefCell::new(vec![]) };
+    apply_effect(&mut state, &pid, &battlecry, &chooser, &templates);
+
+    // 4 options, puis les 3 restantes
+    assert_eq!(*chooser.offered.borrow(), vec![4, 3]);
+    let m = &state.players[&pid].zones.board[0];
+    assert!(m.has_kw(Keywords::RUSH));
+    assert!(m.has_kw(Keywords::TAUNT));
+    assert!(!m.has_kw(Keywords::DIVINE_SHIELD));
+    assert!(!m.has_kw(Keywords::WINDFURY));
+}
+
+#[test]
+fn fandral_combines_every_option() {
+    let templates = load_card_templates("cards/CORE2025.json").unwrap();
+    let mut state = new_state_with_mana(CardClass::Druid);
+    let pid = PlayerId::Player1;
+    {
+        let p = state.players.get_mut(&pid).unwrap();
+        p.zones.board.push(templates["CORE_OG_044"].to_card());
+        p.zones.hand.push(templates["CORE_ULD_178"].to_card());
+    }
+
+    assert!(play_card_at_index(&mut state, &pid, 0, &RandomChooser, &templates));
+
+    let m = state.players[&pid].zones.board.last().unwrap();
+    assert_eq!(m.card_id, "CORE_ULD_178");
+    for kw in [Keywords::RUSH, Keywords::TAUNT, Keywords::DIVINE_SHIELD, Keywords::WINDFURY] {
+        assert!(m.has_kw(kw));
+    }
+}
+
+#[test]
+fn choose_one_battlecry_from_hand_asks_the_chooser() {
+    let templates = load_card_templates("cards/CORE2025.json").unwrap();
+    let mut state = new_state_with_mana(CardClass::Druid);
+    let pid = PlayerId::Player1;
+    state.players.get_mut(&pid).unwrap().zones.hand.push(templates["CORE_ULD_178"].to_card());
+
+    // le Cri de guerre joué depuis la main passe par la file d'événements
+    let chooser = FirstOption { offered: RefCell::new(vec![]) };
+    assert!(play_card_at_index(&mut state, &pid, 0, &chooser, &templates));
+
+    assert_eq!(*chooser.offered.borrow(), vec![4, 3]);
+    let m = &state.players[&pid].zones.board[0];
+    assert!(m.has_kw(Keywords::RUSH) && m.has_kw(Keywords::TAUNT));
+    assert!(!m.has_kw(Keywords::DIVINE_SHIELD) && !m.has_kw(Keywords::WINDFURY));
+}
@@ -0,0 +1,59 @@
+use hearthstone_sim::data::card_template::load_card_templates;
+use hearthstone_sim::game::card::Card;
+use hearthstone_sim::game::engine::choose::RandomChooser;
+use hearthstone_sim::game::engine::play_card::play_card_at_index;
+use hearthstone_sim::game::enums::CardClass;
+use hearthstone_sim::game::state::{GameState, PlayerId};
+use serde_json::json;
+use std::collections::HashMap;
+
+mod common;
+use common::{card, new_state_with_mana};
+
+fn filler(id: &str) -> Card {
+    card(json!({"card_id": id, "cost": 0}))
+}
+
+fn combo_minion(id: &str) -> Card {
+    card(json!({
+        "card_id": id, "card_class": "ROGUE", "cost": 0,
+        "effects": [{"type": "damage", "amount": 2, "target": "enemy_hero", "trigger": "combo"}]
+    }))
+}
+
+fn new_state(hand: Vec<Card>) -> GameState {
+    let mut state = new_state_with_mana(CardClass::Rogue);
+    state.players.get_mut(&PlayerId::Player1).unwrap().zones.hand = hand;
+    state
+}
+
+#[test]
+fn combo_needs_a_card_played_before() {
+    let mut state = new_state(vec![filler("A"), combo_minion("COMBO_1"), filler("B"), combo_minion("COMBO_2"), filler("C")]);
+    let (pid, opp) = (PlayerId::Player1, PlayerId::Player2);
+
+    assert!(play_card_at_index(&mut state, &pid, 1, &RandomChooser, &HashMap::new()));
+    assert_eq!(state.players[&opp].stats.health, 30);
+    assert_eq!(state.players[&pid].cards_played_this_turn, 1);
+
+    assert!(play_card_at_index(&mut state, &pid, 2, &RandomChooser, &HashMap::new()));
+    assert_eq!(state.players[&opp].stats.health, 28);
+
+    state.players.get_mut(&pid).unwrap().start_turn();
+    assert_eq!(state.players[&pid].cards_played_this_turn, 0);
+}
+
+#[test]
+fn outcast_fires_only_from_the_edges_of_the_hand() {
+    let templates = load_card_templates("cards/CORE2025.json").unwrap();
+    let glaivesmith = || templates["CS3_017"].to_card();
+    let pid = PlayerId::Player1;
+
+    let mut state = new_state(vec![filler("A"), glaivesmith(), filler("B")]);
+    assert!(play_card_at_index(&mut state, &pid, 1, &RandomChooser, &templates));
+    assert_eq!(state.players[&pid].stats.hero_attack, 0);
+
+    let mut state = new_state(vec![filler("A"), filler("B"), glaivesmith()]);
+    assert!(play_card_at_index(&mut state, &pid, 2, &RandomChooser, &templates));
+    assert_eq!(state.players[&pid].stats.hero_attack, 3);
+}
@@ -0,0 +1,74 @@
+//! Fixtures partagées par les tests d'intégration : cartes et effets construits
+//! depuis du JSON, parties vides prêtes à jouer.
+#![allow(dead_code)]
+
+use hearthstone_sim::data::card_template::{CardTemplate, EffectTemplate};
+use hearthstone_sim::game::card::Card;
+use hearthstone_sim::game::effects::Effect;
+use hearthstone_sim::game::enums::CardClass;
+use hearthstone_sim::game::player::Player;
+use hearthstone_sim::game::state::{GameState, PlayerId};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+/// Template d'un serviteur neutre à 1 mana, nommé d'après son `card_id`.
+/// Les champs de `value` remplacent ces valeurs ; un serviteur sans stats est un 1/1.
+pub fn template(value: Value) -> CardTemplate {
+    let mut base = json!({"card_class": "NEUTRAL", "card_type": "MINION", "cost": 1});
+    base.as_object_mut().unwrap().extend(value.as_object().unwrap().clone());
+    if base["card_name"].is_null() {
+        base["card_name"] = base["card_id"].clone();
+    }
+    if base["card_type"] == "MINION" {
+        for stat in ["attack", "health"] {
+            if base[stat].is_null() {
+                base[stat] = json!(1);
+            }
+        }
+    }
+    serde_json::from_value(base).unwrap()
+}
+
+pub fn card(value: Value) -> Card {
+    template(value).to_card()
+}
+
+/// Serviteur `id` en `attack`/`health`, complété par les champs de `extra`.
+pub fn minion(id: &str, attack: i32, health: i32, extra: Value) -> Card {
+    let mut value = json!({"card_id": id, "attack": attack, "health": health});
+    value.as_object_mut().unwrap().extend(extra.as_object().unwrap().clone());
+    card(value)
+}
+
+/// Indexe des templates par `card_id`, comme `GameState::card_templates`.
+pub fn templates(list: impl IntoIterator<Item = CardTemplate>) -> HashMap<String, CardTemplate> {
+    list.into_iter().map(|t| (t.card_id.clone(), t)).collect()
+}
+
+pub fn effect(value: Value) -> Effect {
+    Effect::from_template(&serde_json::from_value::<EffectTemplate>(value).unwrap())
+}
+
+/// Partie vide : Player1 joue `class` contre un Player2 neutre, sans deck ni mana.
+pub fn new_state(class: CardClass) -> GameState {
+    let p1 = Player::new(PlayerId::Player1, vec![], class);
+    let p2 = Player::new(PlayerId::Player2, vec![], CardClass::Neutral);
+    GameState::new(p1, p2)
+}
+
+/// Comme `new_state`, avec 10 cristaux de mana pleins pour Player1.
+pub fn new_state_with_mana(class: CardClass) -> GameState {
+    let mut state = new_state(class);
+    fill_mana(&mut state, &PlayerId::Player1);
+    state
+}
+
+pub fn fill_mana(state: &mut GameState, pid: &PlayerId) {
+    let mana = &mut state.players.get_mut(pid).unwrap().stats.mana;
+    mana.max = 10;
+    mana.current = 10;
+}
+
+pub fn board_ids(state: &GameState, pid: &PlayerId) -> Vec<String> {
+    state.players[pid].zones.board.iter().map(|c| c.card_id.clone()).collect()
+}
@@ -0,0 +1,155 @@
+use hearthstone_sim::game::card::Card;
+use hearthstone_sim::game::condition::{Bounds, EffectCondition};
+use hearthstone_sim::game::effects::apply_effect;
+use hearthstone_sim::game::engine::choose::RandomChooser;
+use hearthstone_sim::game::enums::CardClass;
+use hearthstone_sim::game::history::Character;
+use hearthstone_sim::game::state::PlayerId;
+use serde_json::json;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+mod common;
+use common::{effect, new_state, template};
+
+fn card(id: &str, extra: serde_json::Value) -> Card {
+    common::minion(id, 1, 1, extra)
+}
+
+#[test]
+fn chillmaw_deathrattle_needs_a_dragon_in_hand() {
+    let chillmaw = effect(json!({
+        "type": "damage", "amount": 3, "target": "all_enemy_character", "trigger": "deathrattle",
+        "condition": {"type": "holding", "zone": "hand", "races": "dragon"}
+    }));
+    let mut state = new_state(CardClass::Neutral);
+    let pid = PlayerId::Player1;
+    state.players.get_mut(&PlayerId::Player2).unwrap().zones.board.push(card("YETI", json!({"health": 5})));
+
+    apply_effect(&mut state, &pid, &chillmaw, &RandomChooser, &HashMap::new());
+    assert_eq!(state.players[&PlayerId::Player2].zones.board[0].effective_health(), 5);
+
+    state.players.get_mut(&pid).unwrap().zones.hand.push(card("WHELP", json!({"races": ["DRAGON"]})));
+    apply_effect(&mut state, &pid, &chillmaw, &RandomChooser, &HashMap::new());
+    assert_eq!(state.players[&PlayerId::Player2].zones.board[0].effective_health(), 2);
+}
+
+#[test]
+fn spell_cast_last_turn_rolls_over_at_turn_start() {
+    let mut state = new_state(CardClass::Neutral);
+    let pid = PlayerId::Player1;
+    let condition = EffectCondition::from_value(&json!({"type": "cast_spell_last_turn"}));
+    assert_eq!(condition, EffectCondition::CastSpellLastTurn);
+
+    state.history.start_turn(pid);
+    state.history.record_spell_cast(&pid, &card("BOLT", json!({"card_type": "SPELL"})), 1);
+    assert!(!condition.is_met(&state, &pid));
+    state.history.start_turn(PlayerId::Player2);
+    state.history.start_turn(pid);
+    assert!(condition.is_met(&state, &pid));
+    state.history.start_turn(PlayerId::Player2);
+    state.history.start_turn(pid);
+    assert!(!condition.is_met(&state, &pid));
+}
+
+#[test]
+fn target_properties_are_checked_per_target() {
+    let state = new_state(CardClass::Neutral);
+    let pid = PlayerId::Player1;
+    let big_game_hunter = EffectCondition::from_value(&json!({"attack": {"min": 7}}));
+    let black_knight = EffectCondition::from_value(&json!({"has_taunt": true}));
+
+    // vérifiées cible par cible, jamais au lancement
+    assert!(big_game_hunter.is_met(&state, &pid));
+    assert!(big_game_hunter.allows_target(&card("GIANT", json!({"attack": 8}))));
+    assert!(!big_game_hunter.allows_target(&card("YETI", json!({"attack": 4}))));
+    assert!(black_knight.allows_target(&card("WALL", json!({"mechanics": ["Taunt"]}))));
+    assert!(!black_knight.allows_target(&card("YETI", json!({}))));
+}
+
+#[test]
+fn combined_criteria_all_have_to_hold() {
+    let mut state = new_state(CardClass::Neutral);
+    let pid = PlayerId::Player1;
+    let condition = EffectCondition::from_value(&json!({
+        "weapon_equipped": true, "friendly_minions": {"min": 1}, "corpses": {"min": 2}
+    }));
+    let EffectCondition::All(parts) = &condition else { panic!("conjonction attendue") };
+    assert!(parts.contains(&EffectCondition::BoardCount { friendly: true, count: Bounds { min: Some(1), max: None } }));
+    assert!(!condition.is_met(&state, &pid));
+
+    let player = state.players.get_mut(&pid).unwrap();
+    player.stats.weapon = Some(card("AXE", json!({"card_type": "WEAPON"})));
+    player.zones.board.push(card("WISP", json!({})));
+    player.corpses = 1;
+    assert!(!condition.is_met(&state, &pid));
+    state.players.get_mut(&pid).unwrap().corpses = 2;
+    assert!(condition.is_met(&state, &pid));
+}
+
+#[test]
+fn buff_draw_and_corpse_effects_carry_their_condition() {
+    let mut state = new_state(CardClass::Neutral);
+    let pid = PlayerId::Player1;
+    let wailing_vapor = effect(json!({
+        "type": "buff", "health": 1, "target": "self", "trigger": "after_play",
+        "condition": {"card_race": "ELEMENTAL"}
+    }));
+    let enraged = effect(json!({"type": "buff", "health": 2, "target": "self", "condition": {"damaged": true}}));
+    let player = state.players.get_mut(&pid).unwrap();
+    player.zones.board.push(card("VAPOR", json!({"health": 3})));
+    player.source_position = Some(0);
+    let imp = template(json!({"card_id": "IMP", "races": ["ELEMENTAL"]}));
+    let imp_card = imp.to_card();
+    state.card_templates = Rc::new(HashMap::from([(imp.card_id.clone(), imp)]));
+
+    // « s'il est blessé » : vérifié sur la cible
+    apply_effect(&mut state, &pid, &enraged, &RandomChooser, &HashMap::new());
+    assert_eq!(state.players[&pid].zones.board[0].effective_health(), 3);
+    state.players.get_mut(&pid).unwrap().zones.board[0].status.current_health = Some(2);
+    apply_effect(&mut state, &pid, &enraged, &RandomChooser, &HashMap::new());
+    assert_eq!(state.players[&pid].zones.board[0].effective_health(), 4);
+
+    // « après avoir joué un Élémentaire »
+    state.history.record_card_played(&pid, &card("WISP", json!({})));
+    apply_effect(&mut state, &pid, &wailing_vapor, &RandomChooser, &HashMap::new());
+    assert_eq!(state.players[&pid].zones.board[0].effective_health(), 4);
+    state.history.record_card_played(&pid, &imp_card);
+    apply_effect(&mut state, &pid, &wailing_vapor, &RandomChooser, &HashMap::new());
+    assert_eq!(state.players[&pid].zones.board[0].effective_health(), 5);
+
+    let hematurge = effect(json!({"type": "spend_corpse", "amount": 2, "mode": "up_to", "condition": {"required": true}}));
+    assert_eq!(hematurge.condition(), Some(&EffectCondition::CorpsesAvailable(2)));
+    state.players.get_mut(&pid).unwrap().corpses = 1;
+    apply_effect(&mut state, &pid, &hematurge, &RandomChooser, &HashMap::new());
+    assert_eq!(state.players[&pid].corpses, 1);
+
+    let acolyte = effect(json!({"type": "draw", "amount": 1, "condition": {"damage_taken": true}}));
+    assert!(acolyte.condition().is_some());
+}
+
+#[test]
+fn damage_taken_and_undead_deaths_read_the_history() {
+    let mut state = new_state(CardClass::Neutral);
+    let (pid, opp) = (PlayerId::Player1, PlayerId::Player2);
+    let acolyte = EffectCondition::from_value(&json!({"damage_taken": true}));
+    let mortician = EffectCondition::from_value(&json!({"friendly_undead_died_since_opponent_turn": true}));
+    let player = state.players.get_mut(&pid).unwrap();
+    player.zones.board.push(card("ACOLYTE", json!({"health": 3})));
+    player.source_position = Some(0);
+
+    state.history.start_turn(pid);
+    assert!(!acolyte.is_met(&state, &pid));
+    state.history.record_damage(Character::Minion { owner: pid, card_id: "ACOLYTE".to_string() }, pid.opponent(), 1);
+    assert!(acolyte.is_met(&state, &pid));
+
+    // mort pendant le tour adverse : compte au tour suivant, plus après
+    state.history.start_turn(opp);
+    state.history.record_minion_died(&pid, &card("GHOUL", json!({"races": ["UNDEAD"]})));
+    state.history.start_turn(pid);
+    assert!(mortician.is_met(&state, &pid));
+    assert!(!acolyte.is_met(&state, &pid));
+    state.history.start_turn(opp);
+    state.history.start_turn(pid);
+    assert!(!mortician.is_met(&state, &pid));
+}
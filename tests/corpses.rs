@@ -0,0 +1,89 @@
+use hearthstone_sim::game::card::Card;
+use hearthstone_sim::game::effects::{apply_effect, remove_dead_minions, Effect};
+use hearthstone_sim::game::engine::choose::RandomChooser;
+use hearthstone_sim::game::engine::play_card::play_card_at_index;
+use hearthstone_sim::game::enums::CardClass;
+use hearthstone_sim::game::state::{GameState, PlayerId};
+use serde_json::json;
+use std::collections::HashMap;
+
+mod common;
+use common::{card, new_state_with_mana};
+
+fn minion(id: &str, effects: serde_json::Value) -> Card {
+    card(json!({"card_id": id, "card_class": "DEATHKNIGHT", "effects": effects}))
+}
+
+fn kill_all(state: &mut GameState) {
+    for p in state.players.values_mut() {
+        for m in p.zones.board.iter_mut() {
+            m.status.current_health = Some(0);
+        }
+    }
+    remove_dead_minions(state);
+}
+
+#[test]
+fn friendly_deaths_leave_corpses() {
+    let mut state = new_state_with_mana(CardClass::Deathknight);
+    let pid = PlayerId::Player1;
+    {
+        let board = &mut state.players.get_mut(&pid).unwrap().zones.board;
+        board.push(minion("A", json!([])));
+        board.push(minion("GHOUL", json!([{"type": "no_corpse"}])));
+    }
+    kill_all(&mut state);
+    assert_eq!(state.players[&pid].corpses, 1);
+
+    // Aura « deux fois plus de Cadavres » (le porteur survit)
+    {
+        let board = &mut state.players.get_mut(&pid).unwrap().zones.board;
+        board.push(minion("B", json!([])));
+        board.push(card(json!({
+            "card_id": "HEARTHSTONE", "card_name": "Aura", "card_class": "DEATHKNIGHT",
+            "card_type": "MINION", "cost": 1, "attack": 0, "health": 5,
+            "effects": [{"type": "aura", "effect": {"type": "modify_corpse_gain", "multiplier": 2}, "target": "friendly_hero"}]
+        })));
+        board[0].status.current_health = Some(0);
+    }
+    remove_dead_minions(&mut state);
+    assert_eq!(state.players[&pid].corpses, 3);
+    assert_eq!(state.players[&PlayerId::Player2].corpses, 0);
+}
+
+#[test]
+fn spend_corpse_if_you_can() {
+    let mut state = new_state_with_mana(CardClass::Deathknight);
+    let pid = PlayerId::Player1;
+    let hematurge = || minion("HEMA", json!([
+        {"type": "spend_corpse", "amount": 2, "condition": {"required": true}, "trigger": "battlecry"},
+        {"type": "damage", "amount": 3, "target": "enemy_hero"}
+    ]));
+
+    state.players.get_mut(&pid).unwrap().gain_corpses(1);
+    state.players.get_mut(&pid).unwrap().zones.hand.push(hematurge());
+    assert!(play_card_at_index(&mut state, &pid, 0, &RandomChooser, &HashMap::new()));
+    assert_eq!(state.players[&pid].corpses, 1);
+    assert_eq!(state.players[&PlayerId::Player2].stats.health, 30);
+
+    state.players.get_mut(&pid).unwrap().gain_corpses(1);
+    state.players.get_mut(&pid).unwrap().zones.hand.push(hematurge());
+    assert!(play_card_at_index(&mut state, &pid, 0, &RandomChooser, &HashMap::new()));
+    assert_eq!(state.players[&pid].corpses, 0);
+    assert_eq!(state.players[&PlayerId::Player2].stats.health, 27);
+}
+
+#[test]
+fn spend_up_to_uses_what_is_available() {
+    let mut state = new_state_with_mana(CardClass::Deathknight);
+    let pid = PlayerId::Player1;
+    state.players.get_mut(&pid).unwrap().gain_corpses(3);
+
+    let spend = Effect::SpendCorpse { amount: Some(5), up_to: Some(true), condition: None, then: vec![] };
+    apply_effect(&mut state, &pid, &spend, &RandomChooser, &HashMap::new());
+
+    let p = &state.players[&pid];
+    assert_eq!(p.corpses, 0);
+    assert_eq!(p.corpses_spent_last, 3);
+    assert_eq!(p.corpses_spent_this_game, 3);
+}
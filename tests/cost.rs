@@ -0,0 +1,107 @@
+use hearthstone_sim::game::card::Card;
+use hearthstone_sim::game::effects::apply_effect;
+use hearthstone_sim::game::engine::choose::RandomChooser;
+use hearthstone_sim::game::engine::cost::{consume_cost_modifiers, effective_cost, expire_cost_modifiers};
+use hearthstone_sim::game::enums::CardClass;
+use hearthstone_sim::game::state::PlayerId;
+use serde_json::json;
+use std::collections::HashMap;
+
+mod common;
+use common::{card, effect, new_state};
+
+fn demon(cost: u8) -> Card {
+    card(json!({
+        "card_id": "DEMON", "card_name": "Demon", "card_class": "NEUTRAL",
+        "card_type": "MINION", "cost": cost, "attack": 2, "health": 2, "races": ["DEMON"]
+    }))
+}
+
+#[test]
+fn next_demon_discount_is_consumed_and_floored() {
+    let mut state = new_state(CardClass::Neutral);
+    let pid = PlayerId::Player1;
+
+    let effect = effect(json!({
+        "type": "modify_cost", "mode": "reduce", "amount": 2, "target": "next_friendly_card",
+        "filter": {"races": "demon"}, "duration": "until_played", "trigger": "battlecry"
+    }));
+    apply_effect(&mut state, &pid, &effect, &RandomChooser, &HashMap::new());
+
+    let cheap = demon(1);
+    let big = demon(5);
+    assert_eq!(effective_cost(&state, &pid, &cheap), 0);
+    assert_eq!(effective_cost(&state, &pid, &big), 3);
+
+    consume_cost_modifiers(&mut state, &pid, &big);
+    assert_eq!(effective_cost(&state, &pid, &big), 5);
+}
+
+#[test]
+fn opponent_spell_tax_lasts_until_end_of_next_turn() {
+    let mut state = new_state(CardClass::Neutral);
+
+    let effect = effect(json!({
+        "type": "modify_cost", "mode": "increase", "value": 1, "target": "opponent_spells",
+        "zone": "hand", "duration": "until_end_of_next_turn", "trigger": "battlecry"
+    }));
+    apply_effect(&mut state, &PlayerId::Player1, &effect, &RandomChooser, &HashMap::new());
+
+    let spell = card(json!({
+        "card_id": "SPELL", "card_name": "Spell", "card_class": "MAGE",
+        "card_type": "SPELL", "cost": 2
+    }));
+    let opp = PlayerId::Player2;
+    assert_eq!(effective_cost(&state, &opp, &spell), 3);
+    expire_cost_modifiers(&mut state); // fin du tour du lanceur
+    assert_eq!(effective_cost(&state, &opp, &spell), 3);
+    expire_cost_modifiers(&mut state); // fin du tour adverse
+    assert_eq!(effective_cost(&state, &opp, &spell), 2);
+}
+
+#[test]
+fn self_reduction_follows_weapon_attack() {
+    let mut state = new_state(CardClass::Neutral);
+    let pid = PlayerId::Player1;
+
+    let corsair = card(json!({
+        "card_id": "CORE_NEW1_022", "card_name": "Dread Corsair", "card_class": "NEUTRAL",
+        "card_type": "MINION", "cost": 4, "attack": 3, "health": 3,
+        "effects": [{"type": "modify_cost", "mode": "reduce", "value": "weapon_attack", "zone": "hand"}]
+    }));
+    assert_eq!(effective_cost(&state, &pid, &corsair), 4);
+
+    state.players.get_mut(&pid).unwrap().stats.weapon = Some(card(json!({
+        "card_id": "AXE", "card_name": "Axe", "card_class": "WARRIOR",
+        "card_type": "WEAPON", "cost": 2, "attack": 3, "health": 2
+    })));
+    assert_eq!(effective_cost(&state, &pid, &corsair), 1);
+}
+
+#[test]
+fn stacked_discounts_from_one_source_are_all_consumed() {
+    let mut state = new_state(CardClass::Neutral);
+    let pid = PlayerId::Player1;
+    let felscreamer = card(json!({
+        "card_id": "FELSCREAMER", "card_name": "Felscreamer", "card_class": "NEUTRAL",
+        "card_type": "MINION", "cost": 2, "attack": 2, "health": 2
+    }));
+    let player = state.players.get_mut(&pid).unwrap();
+    player.zones.board.push(felscreamer);
+    player.zones.board.push(demon(1));
+    player.source_position = Some(0);
+
+    let effect = effect(json!({
+        "type": "modify_cost", "mode": "reduce", "amount": 1, "target": "next_friendly_card", "duration": "until_played"
+    }));
+    apply_effect(&mut state, &pid, &effect, &RandomChooser, &HashMap::new());
+    apply_effect(&mut state, &pid, &effect, &RandomChooser, &HashMap::new());
+    // la source est la carte qui résout l'effet, pas la dernière du board
+    assert!(state.players[&pid].cost_modifiers.iter().all(|m| m.source == "FELSCREAMER"));
+
+    let big = demon(5);
+    assert_eq!(effective_cost(&state, &pid, &big), 3);
+    consume_cost_modifiers(&mut state, &pid, &big);
+    assert!(state.players[&pid].cost_modifiers.is_empty());
+    assert_eq!(effective_cost(&state, &pid, &big), 5);
+}
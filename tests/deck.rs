@@ -0,0 +1,106 @@
+use hearthstone_sim::data::card_template::CardTemplate;
+use hearthstone_sim::game::card::Card;
+use hearthstone_sim::game::effects::apply_effect;
+use hearthstone_sim::game::engine::choose::RandomChooser;
+use hearthstone_sim::game::engine::deck::{not_starting_in_deck, peek_top, reveal_top, shuffle_into_deck, DeckPosition};
+use hearthstone_sim::game::engine::draw::draw_card;
+use hearthstone_sim::game::enums::{CardClass, Zone};
+use hearthstone_sim::game::event::GameEvent;
+use hearthstone_sim::game::player::{Player, MAX_BOARD};
+use hearthstone_sim::game::state::{GameState, PlayerId};
+use serde_json::json;
+use std::collections::HashMap;
+
+mod common;
+use common::{effect};
+
+fn template(id: &str, race: Option<&str>) -> CardTemplate {
+    let mut value = json!({"card_id": id});
+    if let Some(r) = race {
+        value["races"] = json!([r]);
+    }
+    common::template(value)
+}
+
+fn card(id: &str) -> Card {
+    template(id, None).to_card()
+}
+
+fn new_state(deck: Vec<Card>) -> GameState {
+    let p1 = Player::new(PlayerId::Player1, deck, CardClass::Neutral);
+    let p2 = Player::new(PlayerId::Player2, vec![], CardClass::Neutral);
+    GameState::new(p1, p2)
+}
+
+fn deck_ids(state: &GameState, pid: &PlayerId) -> Vec<String> {
+    state.players[pid].zones.deck.iter().map(|c| c.card_id.clone()).collect()
+}
+
+#[test]
+fn shuffled_cards_land_where_asked_and_top_is_drawn_next() {
+    let pid = PlayerId::Player1;
+    let mut state = new_state(vec![card("A"), card("B")]);
+
+    shuffle_into_deck(&mut state, &pid, card("TOP"), DeckPosition::Top);
+    assert!(matches!(
+        state.event_queue.back(),
+        Some(GameEvent::CardMoved { card_id, from: Zone::SetAside, to: Zone::Deck, .. }) if card_id == "TOP"
+    ));
+    shuffle_into_deck(&mut state, &pid, card("BOTTOM"), DeckPosition::Bottom);
+    assert_eq!(deck_ids(&state, &pid), ["BOTTOM", "A", "B", "TOP"].map(String::from));
+    let index = shuffle_into_deck(&mut state, &pid, card("ANY"), DeckPosition::Random);
+    assert_eq!(state.players[&pid].zones.deck[index].card_id, "ANY");
+
+    let expected_top = peek_top(&state.players[&pid]).unwrap().card_id.clone();
+    assert_eq!(reveal_top(&state, &pid).unwrap().card_id, expected_top);
+    apply_effect(&mut state, &pid, &effect(json!({"type": "reveal_top"})), &RandomChooser, &HashMap::new());
+    assert_eq!(state.players[&pid].zones.deck.len(), 5);
+    assert_eq!(draw_card(&mut state, &pid).unwrap().card_id, expected_top);
+}
+
+#[test]
+fn summon_from_deck_takes_matching_minions_until_the_board_is_full() {
+    let pid = PlayerId::Player1;
+    let murloc = template("MURLOC", Some("MURLOC"));
+    let mut state = new_state(vec![murloc.to_card(), card("WISP"), murloc.to_card(), murloc.to_card()]);
+    let finja = effect(json!({"type": "summon_from_deck", "amount": 2, "filter": {"races": "murloc"}, "zone": "friendly_board"}));
+
+    apply_effect(&mut state, &pid, &finja, &RandomChooser, &HashMap::new());
+    let board: Vec<_> = state.players[&pid].zones.board.iter().map(|c| c.card_id.as_str()).collect();
+    assert_eq!(board, ["MURLOC", "MURLOC"]);
+    let mut left = deck_ids(&state, &pid);
+    left.sort();
+    assert_eq!(left, ["MURLOC", "WISP"]);
+
+    for _ in 0..MAX_BOARD - 2 {
+        state.players.get_mut(&pid).unwrap().place_on_board(card("FILLER"), None);
+    }
+    apply_effect(&mut state, &pid, &finja, &RandomChooser, &HashMap::new());
+    assert_eq!(state.players[&pid].zones.deck.len(), 2);
+}
+
+#[test]
+fn steamcleaner_destroys_only_cards_added_to_the_decks() {
+    let pid = PlayerId::Player1;
+    let opp = PlayerId::Player2;
+    let mut state = new_state(vec![card("A"), card("B")]);
+    let squirrels = effect(json!({"type": "shuffle_into_deck", "amount": 2, "card_id": "ACORN"}));
+    let mut templates = HashMap::new();
+    templates.insert("ACORN".to_string(), template("ACORN", None));
+    apply_effect(&mut state, &pid, &squirrels, &RandomChooser, &templates);
+    shuffle_into_deck(&mut state, &opp, card("ACORN"), DeckPosition::Top);
+    assert_eq!(not_starting_in_deck(&state.players[&pid]).len(), 2);
+
+    let steamcleaner = effect(json!({"type": "destroy", "target": "deck_cards_not_starting_in_deck", "zone": ["both_decks"]}));
+    apply_effect(&mut state, &pid, &steamcleaner, &RandomChooser, &templates);
+
+    assert_eq!(deck_ids(&state, &pid), ["A", "B"].map(String::from));
+    assert!(state.players[&opp].zones.deck.is_empty());
+    assert_eq!(state.players[&pid].zones.graveyard.len(), 2);
+    let destroyed = state
+        .event_queue
+        .iter()
+        .filter(|e| matches!(e, GameEvent::CardMoved { from: Zone::Deck, to: Zone::Graveyard, .. }))
+        .count();
+    assert_eq!(destroyed, 3);
+}
@@ -0,0 +1,116 @@
+use hearthstone_sim::data::card_template::CardTemplate;
+use hearthstone_sim::game::effects::apply_effect;
+use hearthstone_sim::game::engine::choose::{Choice, Chooser};
+use hearthstone_sim::game::engine::discover::MYSTERY_CARD_ID;
+use hearthstone_sim::game::engine::play_card::play_card_at_index;
+use hearthstone_sim::game::enums::CardClass;
+use hearthstone_sim::game::state::{GameState, PlayerId};
+use serde_json::json;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+mod common;
+use common::{card, effect, new_state, new_state_with_mana, template};
+
+/// Choisit toujours la dernière option et mémorise ce qui a été proposé.
+struct LastOption {
+    seen: RefCell<Vec<Vec<String>>>,
+}
+
+impl Chooser for LastOption {
+    fn choose(&self, _state: &GameState, choice: Choice) -> Choice {
+        match choice {
+            Choice::Discover { options } => {
+                self.seen.borrow_mut().push(options.iter().map(|c| c.card_id.clone()).collect());
+                Choice::Discover { options: vec![options.last().unwrap().clone()] }
+            }
+            other => other,
+        }
+    }
+}
+
+fn templates() -> HashMap<String, CardTemplate> {
+    let specs = [
+        ("MAGE_1", "MAGE", "SPELL", true),
+        ("MAGE_2", "MAGE", "SPELL", true),
+        ("MAGE_TOKEN", "MAGE", "SPELL", false),
+        ("MAGE_MINION", "MAGE", "MINION", true),
+        ("NEUTRAL_1", "NEUTRAL", "SPELL", true),
+        ("WARRIOR_1", "WARRIOR", "SPELL", true),
+    ];
+    common::templates(specs.iter().map(|(id, class, ty, collectible)| {
+        template(json!({"card_id": id, "card_class": class, "card_type": ty, "collectible": collectible}))
+    }))
+}
+
+#[test]
+fn discover_offers_three_distinct_cards_from_the_pool() {
+    let mut state = new_state(CardClass::Mage);
+    let pid = PlayerId::Player1;
+    let chooser = LastOption { seen: RefCell::new(vec![]) };
+    let effect = effect(json!({"type": "discover", "amount": 1, "filter": {"card_type": "spell"}}));
+
+    apply_effect(&mut state, &pid, &effect, &chooser, &templates());
+
+    let seen = chooser.seen.borrow();
+    let mut options = seen[0].clone();
+    assert_eq!(options.len(), 3);
+    let picked = options.last().unwrap().clone();
+    options.sort();
+    assert_eq!(options, vec!["MAGE_1", "MAGE_2", "NEUTRAL_1"]);
+    let hand = &state.players[&pid].zones.hand;
+    assert_eq!(hand.len(), 1);
+    assert_eq!(hand[0].card_id, picked);
+}
+
+#[test]
+fn discover_from_another_class_and_mystery_choice() {
+    let mut state = new_state(CardClass::Mage);
+    let pid = PlayerId::Player1;
+    let chooser = LastOption { seen: RefCell::new(vec![]) };
+
+    let other = effect(json!({"type": "discover", "filter": {"card_type": "spell", "card_class": "other"}}));
+    apply_effect(&mut state, &pid, &other, &chooser, &templates());
+    assert_eq!(chooser.seen.borrow()[0], vec!["WARRIOR_1"]);
+
+    let mystery = effect(json!({"type": "discover_mystery", "filter": {"card_type": "spell"}}));
+    apply_effect(&mut state, &pid, &mystery, &chooser, &templates());
+    let seen = chooser.seen.borrow();
+    assert_eq!(seen[1].len(), 4);
+    assert_eq!(seen[1][3], MYSTERY_CARD_ID);
+    let got = &state.players[&pid].zones.hand[1];
+    assert!(["MAGE_1", "MAGE_2", "NEUTRAL_1"].contains(&got.card_id.as_str()));
+}
+
+#[test]
+fn discover_condition_must_hold() {
+    let mut state = new_state(CardClass::Mage);
+    let pid = PlayerId::Player1;
+    let chooser = LastOption { seen: RefCell::new(vec![]) };
+    let effect = effect(json!({
+        "type": "discover", "filter": {"card_type": "spell"},
+        "condition": {"type": "holding", "zone": "hand", "races": "dragon"}
+    }));
+
+    apply_effect(&mut state, &pid, &effect, &chooser, &templates());
+    assert!(chooser.seen.borrow().is_empty());
+    assert!(state.players[&pid].zones.hand.is_empty());
+}
+
+#[test]
+fn battlecry_discover_is_offered_to_the_chooser() {
+    let mut state = new_state_with_mana(CardClass::Mage);
+    state.card_templates = Rc::new(templates());
+    let pid = PlayerId::Player1;
+    state.players.get_mut(&pid).unwrap().zones.hand.push(card(json!({
+        "card_id": "SEEKER", "effects": [{"type": "discover", "filter": "spell", "trigger": "battlecry"}]
+    })));
+    let chooser = LastOption { seen: RefCell::new(vec![]) };
+
+    assert!(play_card_at_index(&mut state, &pid, 0, &chooser, &HashMap::new()));
+
+    let seen = chooser.seen.borrow();
+    assert_eq!(seen.len(), 1);
+    assert_eq!(state.players[&pid].zones.hand[0].card_id, *seen[0].last().unwrap());
+}
@@ -0,0 +1,75 @@
+use hearthstone_sim::game::card::Card;
+use hearthstone_sim::game::effects::{apply_effect, remove_dead_minions};
+use hearthstone_sim::game::engine::choose::RandomChooser;
+use hearthstone_sim::game::engine::events::dispatch_events;
+use hearthstone_sim::game::enums::CardClass;
+use hearthstone_sim::game::keywords::Keywords;
+use hearthstone_sim::game::state::{GameState, PlayerId};
+use serde_json::json;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+mod common;
+use common::{board_ids, card, effect, template, templates};
+
+fn minion(id: &str) -> Card {
+    card(json!({"card_id": id}))
+}
+
+fn new_state() -> GameState {
+    let mut state = common::new_state(CardClass::Neutral);
+    state.card_templates = Rc::new(templates([template(json!({"card_id": "TREANT"}))]));
+    state
+}
+
+fn kill(state: &mut GameState, pid: &PlayerId, index: usize) {
+    state.players.get_mut(pid).unwrap().zones.board[index].status.current_health = Some(0);
+    remove_dead_minions(state);
+    dispatch_events(state, &RandomChooser);
+}
+
+#[test]
+fn granted_keywords_go_to_the_other_minion_or_the_hand() {
+    let mut state = new_state();
+    let pid = PlayerId::Player1;
+    let player = state.players.get_mut(&pid).unwrap();
+    player.place_on_board(minion("WISP"), None);
+    player.place_on_board(minion("PROTECTOR"), None);
+    player.source_position = Some(1);
+    player.zones.hand.extend([minion("YETI"), card(json!({"card_id": "BOLT", "card_type": "SPELL"}))]);
+
+    let argent_protector = effect(json!({"type": "grant_mechanic", "mechanic": "divine_shield", "target": "friendly_minion"}));
+    apply_effect(&mut state, &pid, &argent_protector, &RandomChooser, &HashMap::new());
+    let in_hand = effect(json!({"type": "grant_mechanic", "mechanic": "taunt", "target": "hand_minion"}));
+    apply_effect(&mut state, &pid, &in_hand, &RandomChooser, &HashMap::new());
+
+    let player = &state.players[&pid];
+    assert!(player.zones.board[0].has_kw(Keywords::DIVINE_SHIELD));
+    assert!(!player.zones.board[1].has_kw(Keywords::DIVINE_SHIELD));
+    assert_eq!(player.zones.board[0].enchantments[0].source.as_deref(), Some("PROTECTOR"));
+    assert!(player.zones.hand[0].has_kw(Keywords::TAUNT));
+    assert!(!player.zones.hand[1].has_kw(Keywords::TAUNT));
+}
+
+#[test]
+fn granted_deathrattle_fires_and_silence_removes_it() {
+    let mut state = new_state();
+    let pid = PlayerId::Player1;
+    state.players.get_mut(&pid).unwrap().place_on_board(minion("WISP"), None);
+    let greybough = effect(json!({
+        "type": "grant_deathrattle", "target": "friendly_minion", "random": true,
+        "effect": {"type": "summon", "amount": 1, "card_id": "TREANT", "destination": "friendly_board"}
+    }));
+
+    apply_effect(&mut state, &pid, &greybough, &RandomChooser, &HashMap::new());
+    kill(&mut state, &pid, 0);
+    assert_eq!(board_ids(&state, &pid), ["TREANT"]);
+
+    apply_effect(&mut state, &pid, &greybough, &RandomChooser, &HashMap::new());
+    let royal_librarian = effect(json!({"type": "silence", "target": "minion"}));
+    apply_effect(&mut state, &pid, &royal_librarian, &RandomChooser, &HashMap::new());
+    let treant = &state.players[&pid].zones.board[0];
+    assert!(treant.status.silenced && treant.enchantments.is_empty() && treant.triggers.is_empty());
+    kill(&mut state, &pid, 0);
+    assert!(board_ids(&state, &pid).is_empty());
+}
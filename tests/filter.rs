@@ -0,0 +1,100 @@
+use hearthstone_sim::data::card_template::CardTemplate;
+use hearthstone_sim::game::effects::apply_effect;
+use hearthstone_sim::game::engine::choose::RandomChooser;
+use hearthstone_sim::game::enums::{CardClass, CardType, Races, Rarity};
+use hearthstone_sim::game::filter::{CardFilter, ClassFilter, CostRange};
+use hearthstone_sim::game::player::Player;
+use hearthstone_sim::game::state::{GameState, PlayerId};
+use serde_json::json;
+
+mod common;
+use common::{effect, template, templates};
+
+fn minion(id: &str, class: &str, race: Option<&str>, cost: u8) -> CardTemplate {
+    let mut value = json!({"card_id": id, "card_class": class, "cost": cost, "collectible": true});
+    if let Some(r) = race {
+        value["races"] = json!([r]);
+    }
+    template(value)
+}
+
+#[test]
+fn parses_objects_and_shorthands() {
+    let filter = CardFilter::from_value(&json!({
+        "card_type": "minion", "races": "murloc", "rarity": "legendary",
+        "cost": {"min": 2, "max": 4}, "card_class": "other_classes"
+    }));
+    assert_eq!(filter.card_type, Some(CardType::Minion));
+    assert_eq!(filter.races, vec![Races::Murloc]);
+    assert_eq!(filter.rarity, Some(Rarity::Legendary));
+    assert_eq!(filter.cost, Some(CostRange { min: Some(2), max: Some(4) }));
+    assert_eq!(filter.class, Some(ClassFilter::Other));
+
+    assert_eq!(CardFilter::from_value(&json!("spell")).card_type, Some(CardType::Spell));
+    assert_eq!(CardFilter::from_value(&json!("elemental")).races, vec![Races::Elemental]);
+    assert_eq!(CardFilter::from_value(&json!("opponent_class")).class, Some(ClassFilter::Opponent));
+}
+
+#[test]
+fn same_result_on_templates_and_live_cards() {
+    let filter = CardFilter::from_value(&json!({"races": ["BEAST"], "cost": {"max": 3}}));
+    let wolf = minion("WOLF", "NEUTRAL", Some("BEAST"), 2);
+    let amalgam = minion("AMALGAM", "NEUTRAL", Some("ALL"), 1);
+    let bear = minion("BEAR", "NEUTRAL", Some("BEAST"), 5);
+    let golem = minion("GOLEM", "NEUTRAL", None, 1);
+
+    for (t, expected) in [(&wolf, true), (&amalgam, true), (&bear, false), (&golem, false)] {
+        assert_eq!(filter.matches(t), expected, "{}", t.card_id);
+        assert_eq!(filter.matches(&t.to_card()), expected, "{}", t.card_id);
+    }
+    // « n'importe quelle race » : tout serviteur avec une race
+    let any_race = CardFilter { races: vec![Races::All], ..CardFilter::default() };
+    assert!(any_race.matches(&bear));
+    assert!(!any_race.matches(&golem));
+}
+
+#[test]
+fn relative_classes_resolve_for_the_filtering_player() {
+    let p1 = Player::new(PlayerId::Player1, vec![], CardClass::Rogue);
+    let p2 = Player::new(PlayerId::Player2, vec![], CardClass::Mage);
+    let state = GameState::new(p1, p2);
+
+    let other = CardFilter::from_value(&json!({"card_class": "other"})).for_player(&state, &PlayerId::Player1);
+    assert!(other.matches(&minion("M", "MAGE", None, 1)));
+    assert!(!other.matches(&minion("R", "ROGUE", None, 1)));
+    assert!(!other.matches(&minion("N", "NEUTRAL", None, 1)));
+
+    let opponent = CardFilter::from_value(&json!("opponent_class")).for_player(&state, &PlayerId::Player1);
+    assert!(opponent.matches(&minion("M", "MAGE", None, 1)));
+    assert!(!opponent.matches(&minion("R", "ROGUE", None, 1)));
+}
+
+#[test]
+fn summon_and_draw_use_the_filter() {
+    let templates = templates([
+        minion("TWO_A", "NEUTRAL", None, 2),
+        minion("THREE", "NEUTRAL", None, 3),
+        template(json!({"card_id": "SPELL_2", "card_type": "SPELL", "cost": 2})),
+    ]);
+
+    let deck = vec![
+        templates["THREE"].to_card(),
+        templates["SPELL_2"].to_card(),
+        templates["TWO_A"].to_card(),
+    ];
+    let p1 = Player::new(PlayerId::Player1, deck, CardClass::Neutral);
+    let p2 = Player::new(PlayerId::Player2, vec![], CardClass::Neutral);
+    let mut state = GameState::new(p1, p2);
+    let pid = PlayerId::Player1;
+
+    // Maze Guide : serviteur à 2 de coût (le sort à 2 est exclu)
+    let maze_guide = effect(json!({"type": "summon", "filter": {"card_type": "minion", "cost": 2}}));
+    apply_effect(&mut state, &pid, &maze_guide, &RandomChooser, &templates);
+    let board: Vec<&str> = state.players[&pid].zones.board.iter().map(|c| c.card_id.as_str()).collect();
+    assert_eq!(board, ["TWO_A"]);
+
+    let draw = effect(json!({"type": "draw", "amount": 1, "filter": {"card_type": "spell"}}));
+    apply_effect(&mut state, &pid, &draw, &RandomChooser, &templates);
+    let hand: Vec<&str> = state.players[&pid].zones.hand.iter().map(|c| c.card_id.as_str()).collect();
+    assert_eq!(hand, ["SPELL_2"]);
+}
@@ -0,0 +1,100 @@
+use hearthstone_sim::data::card_template::CardTemplate;
+use hearthstone_sim::game::card::Card;
+use hearthstone_sim::game::effects::apply_effect;
+use hearthstone_sim::game::engine::choose::RandomChooser;
+use hearthstone_sim::game::enums::CardClass;
+use hearthstone_sim::game::player::MAX_HAND;
+use hearthstone_sim::game::state::PlayerId;
+use serde_json::json;
+use std::collections::HashMap;
+
+mod common;
+use common::{effect, new_state, template};
+
+fn templates() -> HashMap<String, CardTemplate> {
+    common::templates([
+        template(json!({"card_id": "BANANA", "card_type": "SPELL", "collectible": false})),
+        template(json!({"card_id": "FROSTBOLT", "card_class": "MAGE", "card_type": "SPELL", "cost": 2, "collectible": true})),
+        template(json!({"card_id": "MAGE_TOKEN", "card_class": "MAGE", "card_type": "SPELL", "cost": 0, "collectible": false})),
+        template(json!({"card_id": "YETI", "cost": 4, "attack": 4, "health": 5, "collectible": true})),
+    ])
+}
+
+fn ids(cards: &[Card]) -> Vec<&str> {
+    cards.iter().map(|c| c.card_id.as_str()).collect()
+}
+
+#[test]
+fn card_id_goes_to_the_chosen_hand_and_overflow_burns() {
+    let mut state = new_state(CardClass::Mage);
+    let t = templates();
+    let opp = PlayerId::Player2;
+    for _ in 0..MAX_HAND - 1 {
+        state.players.get_mut(&opp).unwrap().zones.hand.push(t["YETI"].to_card());
+    }
+    let mukla = effect(json!({"type": "add_card_to_hand", "card_id": "BANANA", "amount": 2, "zone": "opponent_hand"}));
+
+    apply_effect(&mut state, &PlayerId::Player1, &mukla, &RandomChooser, &t);
+
+    let opponent = &state.players[&opp];
+    assert_eq!(opponent.zones.hand.len(), MAX_HAND);
+    assert_eq!(ids(&opponent.zones.graveyard), ["BANANA"]);
+    assert!(state.players[&PlayerId::Player1].zones.hand.is_empty());
+}
+
+#[test]
+fn random_cards_come_from_the_collectible_filtered_pool() {
+    let mut state = new_state(CardClass::Mage);
+    let t = templates();
+    let pid = PlayerId::Player1;
+    let bookcase = effect(json!({
+        "type": "add_card_to_hand", "amount": 2, "random": true,
+        "filter": {"card_class": "mage", "card_type": "spell"}
+    }));
+
+    apply_effect(&mut state, &pid, &bookcase, &RandomChooser, &t);
+
+    assert_eq!(ids(&state.players[&pid].zones.hand), ["FROSTBOLT", "FROSTBOLT"]);
+}
+
+#[test]
+fn copies_keep_the_source_card_and_apply_modifications() {
+    let mut state = new_state(CardClass::Mage);
+    let t = templates();
+    let pid = PlayerId::Player1;
+    let player = state.players.get_mut(&pid).unwrap();
+    let mut buffed = t["YETI"].to_card();
+    buffed.status.attack_modifiers = 2;
+    player.zones.hand.extend([buffed, t["BANANA"].to_card(), t["FROSTBOLT"].to_card()]);
+    state.players.get_mut(&PlayerId::Player2).unwrap().zones.deck.push(t["BANANA"].to_card());
+
+    let zai = effect(json!({
+        "type": "copy_card_to_hand", "from": {"zone": "hand", "position": ["leftmost", "rightmost"]},
+        "to": {"zone": "hand"}, "amount": 2, "modify": {"cost_change": -1}
+    }));
+    apply_effect(&mut state, &pid, &zai, &RandomChooser, &t);
+    let conjurer = effect(json!({"type": "add_card_to_hand", "amount": 1, "filter": {"zone": "opponent_deck"}, "mode": "copy"}));
+    apply_effect(&mut state, &pid, &conjurer, &RandomChooser, &t);
+
+    let hand = &state.players[&pid].zones.hand;
+    assert_eq!(ids(hand), ["YETI", "BANANA", "FROSTBOLT", "YETI", "FROSTBOLT", "BANANA"]);
+    assert_eq!(hand[3].effective_attack(), 6);
+    assert_eq!((hand[3].status.cost_modifier, hand[5].status.cost_modifier), (-1, 0));
+    // la copie ne retire rien du deck adverse
+    assert_eq!(state.players[&PlayerId::Player2].zones.deck.len(), 1);
+}
+
+#[test]
+fn lorewalker_cho_copies_the_last_spell_to_the_other_player() {
+    let mut state = new_state(CardClass::Mage);
+    let t = templates();
+    let opp = PlayerId::Player2;
+    state.history.start_turn(opp);
+    state.history.record_spell_cast(&opp, &t["FROSTBOLT"].to_card(), 2);
+
+    let cho = effect(json!({"type": "copy_card_to_hand", "card_type": "spell", "target": "other_player"}));
+    apply_effect(&mut state, &PlayerId::Player1, &cho, &RandomChooser, &t);
+
+    assert_eq!(ids(&state.players[&PlayerId::Player1].zones.hand), ["FROSTBOLT"]);
+    assert!(state.players[&opp].zones.hand.is_empty());
+}
@@ -0,0 +1,97 @@
+use hearthstone_sim::data::card_template::CardTemplate;
+use hearthstone_sim::game::card::Card;
+use hearthstone_sim::game::effects::{apply_effect, remove_dead_minions};
+use hearthstone_sim::game::engine::choose::RandomChooser;
+use hearthstone_sim::game::engine::graveyard::{dead_minions, graveyard_query};
+use hearthstone_sim::game::engine::play_card::play_card_at_index;
+use hearthstone_sim::game::engine::zones::add_to_hand;
+use hearthstone_sim::game::enums::{CardClass, CardType};
+use hearthstone_sim::game::filter::CardFilter;
+use hearthstone_sim::game::player::MAX_HAND;
+use hearthstone_sim::game::state::{GameState, PlayerId};
+use serde_json::json;
+use std::collections::HashMap;
+
+mod common;
+use common::{board_ids, card, effect, new_state_with_mana};
+
+fn template(id: &str, cost: u8, race: Option<&str>) -> CardTemplate {
+    let mut value = json!({"card_id": id, "cost": cost, "attack": cost, "health": cost});
+    if let Some(r) = race {
+        value["races"] = json!([r]);
+    }
+    common::template(value)
+}
+
+fn templates() -> HashMap<String, CardTemplate> {
+    common::templates([template("WISP", 0, None), template("RAPTOR", 2, Some("BEAST")), template("GIANT", 8, None), template("BEAR", 5, Some("BEAST"))])
+}
+
+/// Pose les serviteurs puis les tue tous, dans l'ordre du board.
+fn kill_all(state: &mut GameState, pid: &PlayerId, cards: Vec<Card>) {
+    let player = state.players.get_mut(pid).unwrap();
+    for c in cards {
+        player.place_on_board(c, None);
+    }
+    for m in player.zones.board.iter_mut() {
+        m.status.current_health = Some(0);
+    }
+    remove_dead_minions(state);
+}
+
+#[test]
+fn spells_deaths_and_burns_all_reach_the_graveyard() {
+    let mut state = new_state_with_mana(CardClass::Neutral);
+    let pid = PlayerId::Player1;
+    let t = templates();
+    state.players.get_mut(&pid).unwrap().zones.hand.push(card(json!({"card_id": "COIN", "card_type": "SPELL", "cost": 0})));
+    assert!(play_card_at_index(&mut state, &pid, 0, &RandomChooser, &t));
+    kill_all(&mut state, &pid, vec![t["RAPTOR"].to_card(), t["WISP"].to_card()]);
+    for _ in 0..MAX_HAND {
+        add_to_hand(&mut state, &pid, t["WISP"].to_card());
+    }
+    assert!(!add_to_hand(&mut state, &pid, t["GIANT"].to_card()));
+
+    let player = &state.players[&pid];
+    let ids: Vec<&str> = player.zones.graveyard.iter().map(|c| c.card_id.as_str()).collect();
+    assert_eq!(ids, ["COIN", "RAPTOR", "WISP", "GIANT"]);
+    // la carte brûlée n'est pas morte
+    let dead: Vec<&str> = dead_minions(player).iter().map(|c| c.card_id.as_str()).collect();
+    assert_eq!(dead, ["RAPTOR", "WISP"]);
+
+    let spells = CardFilter { card_type: Some(CardType::Spell), ..CardFilter::default() };
+    assert_eq!(graveyard_query(&state, &pid, &spells).len(), 1);
+}
+
+#[test]
+fn resurrect_highest_cost_summons_a_fresh_copy() {
+    let mut state = new_state_with_mana(CardClass::Neutral);
+    let pid = PlayerId::Player1;
+    let t = templates();
+    let mut buffed = t["GIANT"].to_card();
+    buffed.status.attack_modifiers = 4;
+    kill_all(&mut state, &pid, vec![t["RAPTOR"].to_card(), buffed]);
+
+    apply_effect(&mut state, &pid, &effect(json!({"type": "resurrect", "mode": "highest_cost"})), &RandomChooser, &t);
+
+    assert_eq!(board_ids(&state, &pid), ["GIANT"]);
+    let giant = &state.players[&pid].zones.board[0];
+    assert_eq!((giant.effective_attack(), giant.effective_health()), (8, 8));
+}
+
+#[test]
+fn resurrect_random_uses_each_death_once_and_respects_the_filter() {
+    let mut state = new_state_with_mana(CardClass::Neutral);
+    let pid = PlayerId::Player1;
+    let t = templates();
+    kill_all(&mut state, &pid, vec![t["RAPTOR"].to_card(), t["WISP"].to_card(), t["BEAR"].to_card()]);
+    // les morts adverses ne comptent pas
+    kill_all(&mut state, &PlayerId::Player2, vec![t["RAPTOR"].to_card()]);
+
+    let beasts = effect(json!({"type": "resurrect", "amount": 3, "filter": {"races": "beast"}}));
+    apply_effect(&mut state, &pid, &beasts, &RandomChooser, &t);
+
+    let mut ids = board_ids(&state, &pid);
+    ids.sort();
+    assert_eq!(ids, ["BEAR", "RAPTOR"]);
+}
@@ -0,0 +1,80 @@
+use hearthstone_sim::game::effects::{apply_effect, Effect};
+use hearthstone_sim::game::engine::attack::{hero_attack_value, perform_attack_phase};
+use hearthstone_sim::game::engine::choose::RandomChooser;
+use hearthstone_sim::game::enums::CardClass;
+use hearthstone_sim::game::keywords::Keywords;
+use hearthstone_sim::game::state::PlayerId;
+use serde_json::json;
+use std::collections::HashMap;
+
+mod common;
+use common::{card, effect, new_state};
+
+fn hero_buff(attack: i32) -> Effect {
+    effect(json!({"type": "buff", "attack": attack, "target": "friendly_hero", "duration": "this_turn"}))
+}
+
+fn hero_mechanic(mechanic: &str) -> Effect {
+    effect(json!({"type": "grant_mechanic", "mechanic": mechanic, "target": "friendly_hero"}))
+}
+
+#[test]
+fn hero_attacks_without_weapon_and_takes_retaliation() {
+    let mut state = new_state(CardClass::Demonhunter);
+    let (p1, p2) = (PlayerId::Player1, PlayerId::Player2);
+    apply_effect(&mut state, &p1, &hero_buff(2), &RandomChooser, &HashMap::new());
+    state.players.get_mut(&p2).unwrap().zones.board.push(card(json!({
+        "card_id": "WALL", "card_name": "Wall", "card_class": "NEUTRAL",
+        "card_type": "MINION", "cost": 2, "attack": 3, "health": 5, "mechanics": ["Taunt"]
+    })));
+
+    perform_attack_phase(&mut state, &p1, &p2, &RandomChooser, &HashMap::new());
+
+    let me = &state.players[&p1];
+    assert_eq!(me.hero_attacks_this_turn, 1);
+    assert_eq!(me.stats.health, 27);
+    assert_eq!(state.players[&p2].zones.board[0].effective_health(), 3);
+
+    // L'attaque du héros ne dure que le tour
+    state.players.get_mut(&p1).unwrap().end_turn();
+    assert_eq!(state.players[&p1].stats.hero_attack, 0);
+}
+
+#[test]
+fn hero_windfury_and_immune_while_attacking() {
+    let mut state = new_state(CardClass::Demonhunter);
+    let (p1, p2) = (PlayerId::Player1, PlayerId::Player2);
+    for mechanic in ["windfury", "immune"] {
+        apply_effect(&mut state, &p1, &hero_mechanic(mechanic), &RandomChooser, &HashMap::new());
+    }
+    apply_effect(&mut state, &p1, &hero_buff(1), &RandomChooser, &HashMap::new());
+    state.players.get_mut(&p2).unwrap().zones.board.push(card(json!({
+        "card_id": "OGRE", "card_name": "Ogre", "card_class": "NEUTRAL",
+        "card_type": "MINION", "cost": 6, "attack": 6, "health": 7
+    })));
+
+    perform_attack_phase(&mut state, &p1, &p2, &RandomChooser, &HashMap::new());
+
+    let me = &state.players[&p1];
+    assert_eq!(me.hero_attacks_this_turn, 2);
+    assert_eq!(me.stats.health, 30);
+    assert_eq!(state.players[&p2].zones.board[0].effective_health(), 5);
+}
+
+#[test]
+fn hero_immune_only_protects_attacks_and_ends_with_the_turn() {
+    let mut state = new_state(CardClass::Demonhunter);
+    let p1 = PlayerId::Player1;
+    apply_effect(&mut state, &p1, &hero_mechanic("immune"), &RandomChooser, &HashMap::new());
+    let permanent = effect(json!({"type": "buff", "attack": 1, "target": "friendly_hero"}));
+    apply_effect(&mut state, &p1, &permanent, &RandomChooser, &HashMap::new());
+
+    let me = state.players.get_mut(&p1).unwrap();
+    assert!(me.stats.hero_keywords.has(Keywords::IMMUNE));
+    me.take_damage(3);
+    assert_eq!(me.stats.health, 27);
+
+    me.end_turn();
+    assert!(me.stats.hero_keywords.is_empty());
+    assert_eq!(hero_attack_value(me), 1);
+}
@@ -0,0 +1,49 @@
+use hearthstone_sim::game::engine::choose::RandomChooser;
+use hearthstone_sim::game::engine::play_card::play_card_at_index;
+use hearthstone_sim::game::enums::CardClass;
+use hearthstone_sim::game::player::Player;
+use hearthstone_sim::game::state::{GameState, PlayerId};
+use serde_json::json;
+use std::collections::HashMap;
+
+mod common;
+use common::{template};
+
+#[test]
+fn hero_card_replaces_hero_and_hero_power() {
+    let mut templates = HashMap::new();
+    templates.insert(
+        "HP_PLAGUE".to_string(),
+        template(json!({
+            "card_id": "HP_PLAGUE", "card_name": "Plague Lord", "card_class": "DEATHKNIGHT",
+            "card_type": "HEROPOWER", "cost": 2
+        })),
+    );
+    let hero = template(json!({
+        "card_id": "HERO_DK", "card_name": "Frost Lich Jaina", "card_class": "DEATHKNIGHT",
+        "card_type": "HERO", "cost": 8, "armor": 5, "attack": 2, "hero_power": "HP_PLAGUE",
+        "effects": [{"type": "damage", "amount": 2, "target": "all_enemy_character", "trigger": "battlecry"}]
+    }));
+
+    let mut p1 = Player::new(PlayerId::Player1, vec![], CardClass::Deathknight);
+    p1.stats.mana.max = 8;
+    p1.stats.mana.current = 8;
+    p1.stats.health = 20;
+    p1.zones.hand.push(hero.to_card());
+    let p2 = Player::new(PlayerId::Player2, vec![], CardClass::Neutral);
+    let mut state = GameState::new(p1, p2);
+    let pid = PlayerId::Player1;
+
+    assert_eq!(state.players[&pid].hero_name(), "Deathknight");
+    assert!(play_card_at_index(&mut state, &pid, 0, &RandomChooser, &templates));
+
+    let p = &state.players[&pid];
+    assert_eq!(p.hero_name(), "Frost Lich Jaina");
+    assert_eq!(p.stats.health, 20);
+    assert_eq!(p.stats.armor, 5);
+    assert_eq!(p.stats.hero_attack, 2);
+    assert_eq!(p.stats.hero_power.as_ref().unwrap().card_id, "HP_PLAGUE");
+    assert!(p.zones.hand.is_empty());
+    // Cri de guerre résolu
+    assert_eq!(state.players[&PlayerId::Player2].stats.health, 28);
+}
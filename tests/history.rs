@@ -0,0 +1,90 @@
+use hearthstone_sim::game::effects::remove_dead_minions;
+use hearthstone_sim::game::engine::attack::perform_attack_phase;
+use hearthstone_sim::game::engine::choose::RandomChooser;
+use hearthstone_sim::game::engine::draw::draw_card;
+use hearthstone_sim::game::engine::play_card::play_card_at_index;
+use hearthstone_sim::game::enums::{CardClass, Races, SpellSchool};
+use hearthstone_sim::game::history::Character;
+use hearthstone_sim::game::state::PlayerId;
+use serde_json::json;
+use std::collections::HashMap;
+
+mod common;
+use common::{card, minion, new_state_with_mana};
+
+#[test]
+fn spells_are_recorded_with_school_and_first_target() {
+    let mut state = new_state_with_mana(CardClass::Mage);
+    let pid = PlayerId::Player1;
+    state.history.start_turn(pid);
+    state.players.get_mut(&pid).unwrap().zones.hand.push(card(json!({
+        "card_id": "FIREBOLT", "card_name": "Firebolt", "card_class": "MAGE",
+        "card_type": "SPELL", "spell_school": "FIRE", "cost": 1,
+        "effects": [{"type": "damage", "amount": 2, "target": "enemy_character"}]
+    })));
+
+    assert!(play_card_at_index(&mut state, &pid, 0, &RandomChooser, &HashMap::new()));
+
+    let history = &state.history;
+    assert_eq!(history.cards_played_by(&pid, Some(1)).len(), 1);
+    let spells = history.spells_cast_by(&pid, None);
+    assert_eq!(spells.len(), 1);
+    assert_eq!(spells[0].spell_school, Some(SpellSchool::Fire));
+    // seul personnage ennemi : le héros adverse
+    assert_eq!(spells[0].target, Some(Character::Hero(PlayerId::Player2)));
+    assert_eq!(history.damage_taken_by(&Character::Hero(PlayerId::Player2), Some(1)), 2);
+    assert_eq!(history.damage_dealt_by(&pid, Some(1)), 2);
+}
+
+#[test]
+fn combat_damage_is_recorded_for_each_dealer() {
+    let mut state = new_state_with_mana(CardClass::Mage);
+    let (pid, opp) = (PlayerId::Player1, PlayerId::Player2);
+    let mut yeti = minion("YETI", 4, 5, json!({}));
+    yeti.status.just_played = false;
+    state.players.get_mut(&pid).unwrap().place_on_board(yeti, None);
+    state.players.get_mut(&opp).unwrap().place_on_board(minion("RAPTOR", 3, 2, json!({})), None);
+
+    state.history.start_turn(pid);
+    perform_attack_phase(&mut state, &pid, &opp, &RandomChooser, &HashMap::new());
+
+    let history = &state.history;
+    assert_eq!(history.damage_dealt_by(&pid, Some(1)), 4);
+    assert_eq!(history.damage_dealt_by(&opp, Some(1)), 3);
+    assert_eq!(history.damage_dealt_by(&opp, Some(2)), 0);
+}
+
+#[test]
+fn deaths_and_draws_are_recorded_per_turn_and_player() {
+    let mut state = new_state_with_mana(CardClass::Mage);
+    let pid = PlayerId::Player1;
+    let opp = PlayerId::Player2;
+    state.players.get_mut(&pid).unwrap().zones.deck.push(card(json!({
+        "card_id": "WISP", "card_name": "Wisp", "card_class": "NEUTRAL",
+        "card_type": "MINION", "cost": 0, "attack": 1, "health": 1
+    })));
+    state.players.get_mut(&opp).unwrap().place_on_board(
+        card(json!({
+            "card_id": "RAPTOR", "card_name": "Raptor", "card_class": "NEUTRAL",
+            "card_type": "MINION", "cost": 2, "attack": 3, "health": 2, "races": ["BEAST"]
+        })),
+        None,
+    );
+
+    state.history.start_turn(pid);
+    draw_card(&mut state, &pid);
+    state.history.start_turn(opp);
+    state.players.get_mut(&opp).unwrap().zones.board[0].status.current_health = Some(0);
+    remove_dead_minions(&mut state);
+
+    let history = &state.history;
+    let drawn: Vec<_> = history.cards_drawn.iter().map(|r| (r.turn, r.player, r.card_id.as_str())).collect();
+    assert_eq!(drawn, [(1, pid, "WISP")]);
+    let died = history.minions_died_of(&opp, None);
+    assert_eq!(died.len(), 1);
+    assert_eq!((died[0].turn, died[0].card_id.as_str()), (2, "RAPTOR"));
+    assert_eq!(died[0].races, vec![Races::Beast]);
+    assert!(history.minions_died_of(&pid, None).is_empty());
+    assert_eq!(history.last_turn_of(&pid), Some(1));
+    assert_eq!(history.last_turn_of(&opp), None);
+}
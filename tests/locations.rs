@@ -0,0 +1,47 @@
+use hearthstone_sim::game::engine::choose::RandomChooser;
+use hearthstone_sim::game::engine::location::{activate_location, ready_locations};
+use hearthstone_sim::game::engine::play_card::play_card_at_index;
+use hearthstone_sim::game::enums::CardClass;
+use hearthstone_sim::game::player::Player;
+use hearthstone_sim::game::state::{GameState, PlayerId};
+use serde_json::json;
+use std::collections::HashMap;
+
+mod common;
+use common::{card};
+
+#[test]
+fn location_loses_durability_per_use_and_is_destroyed() {
+    let mut p1 = Player::new(PlayerId::Player1, vec![], CardClass::Neutral);
+    p1.stats.mana.max = 5;
+    p1.stats.mana.current = 5;
+    p1.zones.hand.push(card(json!({
+        "card_id": "LOC", "card_name": "Sunken Tower", "card_class": "NEUTRAL",
+        "card_type": "LOCATION", "cost": 2, "health": 2,
+        "effects": [{"type": "damage", "amount": 1, "target": "all_enemy_character"}]
+    })));
+    let p2 = Player::new(PlayerId::Player2, vec![], CardClass::Neutral);
+    let mut state = GameState::new(p1, p2);
+    let pid = PlayerId::Player1;
+    let templates = HashMap::new();
+
+    assert!(play_card_at_index(&mut state, &pid, 0, &RandomChooser, &templates));
+    assert_eq!(state.players[&pid].zones.board.len(), 1);
+    assert_eq!(ready_locations(&state, &pid), vec![0]);
+
+    assert!(activate_location(&mut state, &pid, 0, &RandomChooser, &templates));
+    assert_eq!(state.players[&PlayerId::Player2].stats.health, 29);
+    assert_eq!(state.players[&pid].zones.board[0].effective_health(), 1);
+
+    // En recharge jusqu'au prochain tour
+    assert!(ready_locations(&state, &pid).is_empty());
+    assert!(!activate_location(&mut state, &pid, 0, &RandomChooser, &templates));
+
+    state.players.get_mut(&pid).unwrap().start_turn();
+    assert!(activate_location(&mut state, &pid, 0, &RandomChooser, &templates));
+    assert_eq!(state.players[&PlayerId::Player2].stats.health, 28);
+
+    let player = &state.players[&pid];
+    assert!(player.zones.board.is_empty());
+    assert_eq!(player.zones.graveyard.last().unwrap().card_id, "LOC");
+}
@@ -0,0 +1,111 @@
+use hearthstone_sim::game::effects::{apply_effect, Effect};
+use hearthstone_sim::game::engine::choose::RandomChooser;
+use hearthstone_sim::game::engine::play_card::play_card_at_index;
+use hearthstone_sim::game::enums::CardClass;
+use hearthstone_sim::game::state::PlayerId;
+use serde_json::json;
+use std::collections::HashMap;
+
+mod common;
+use common::{card, new_state};
+
+#[test]
+fn overload_locks_crystals_next_turn() {
+    let mut state = new_state(CardClass::Shaman);
+    let pid = PlayerId::Player1;
+    {
+        let p = state.players.get_mut(&pid).unwrap();
+        p.stats.mana.max = 4;
+        p.stats.mana.current = 4;
+        p.zones.hand.push(card(json!({
+            "card_id": "CORE_EX1_250", "card_name": "Earth Elemental", "card_class": "SHAMAN",
+            "card_type": "MINION", "cost": 3, "attack": 7, "health": 8,
+            "effects": [{"type": "overload", "amount": 2}]
+        })));
+    }
+
+    assert!(play_card_at_index(&mut state, &pid, 0, &RandomChooser, &HashMap::new()));
+    let p = state.players.get_mut(&pid).unwrap();
+    assert_eq!(p.overload_pending, 2);
+    assert_eq!(p.stats.mana.current, 1);
+
+    p.start_turn();
+    assert_eq!(p.stats.mana.max, 5);
+    assert_eq!(p.stats.mana.locked, 2);
+    assert_eq!(p.stats.mana.current, 3);
+    assert_eq!(p.overload_pending, 0);
+
+    // Le tour suivant, les cristaux se déverrouillent
+    p.start_turn();
+    assert_eq!(p.stats.mana.locked, 0);
+    assert_eq!(p.stats.mana.current, 6);
+}
+
+#[test]
+fn unlock_overload_frees_locked_and_pending() {
+    let mut state = new_state(CardClass::Shaman);
+    let pid = PlayerId::Player1;
+    {
+        let p = state.players.get_mut(&pid).unwrap();
+        p.stats.mana.max = 5;
+        p.add_overload(3);
+        p.start_turn();
+        p.add_overload(1);
+        assert_eq!(p.stats.mana.current, 3);
+    }
+
+    apply_effect(&mut state, &pid, &Effect::UnlockOverload, &RandomChooser, &HashMap::new());
+    let p = &state.players[&pid];
+    assert_eq!(p.stats.mana.locked, 0);
+    assert_eq!(p.stats.mana.current, 6);
+    assert_eq!(p.overload_pending, 0);
+}
+
+#[test]
+fn the_coin_gives_temporary_mana_even_at_ten_crystals() {
+    let templates = hearthstone_sim::data::card_template::load_card_templates("cards/CORE2025.json").unwrap();
+    let mut state = new_state(CardClass::Shaman);
+    let pid = PlayerId::Player1;
+    {
+        let p = state.players.get_mut(&pid).unwrap();
+        p.stats.mana.max = 10;
+        p.stats.mana.current = 6;
+        p.zones.hand.push(templates["GAME_005"].to_card());
+    }
+
+    assert!(play_card_at_index(&mut state, &pid, 0, &RandomChooser, &templates));
+    let p = state.players.get_mut(&pid).unwrap();
+    assert_eq!(p.stats.mana.current, 7);
+    assert_eq!(p.stats.mana.max, 10);
+    assert_eq!(p.stats.mana.temporary, 1);
+
+    // la mana temporaire non dépensée disparaît en fin de tour
+    p.end_turn();
+    assert_eq!(p.stats.mana.current, 6);
+    assert_eq!(p.stats.mana.temporary, 0);
+}
+
+#[test]
+fn mana_crystals_respect_the_cap() {
+    let mut state = new_state(CardClass::Shaman);
+    let pid = PlayerId::Player1;
+    {
+        let p = state.players.get_mut(&pid).unwrap();
+        p.stats.mana.max = 9;
+        p.stats.mana.current = 2;
+    }
+
+    let gain = Effect::GainManaCrystal { amount: Some(2), empty: Some(true), target: None };
+    apply_effect(&mut state, &pid, &gain, &RandomChooser, &HashMap::new());
+    assert_eq!(state.players[&pid].stats.mana.max, 10);
+    assert_eq!(state.players[&pid].stats.mana.current, 2);
+
+    let destroy = Effect::DestroyManaCrystal { amount: Some(9), target: None };
+    apply_effect(&mut state, &pid, &destroy, &RandomChooser, &HashMap::new());
+    assert_eq!(state.players[&pid].stats.mana.max, 1);
+    assert_eq!(state.players[&pid].stats.mana.current, 1);
+
+    let empty = Effect::EmptyManaCrystal { amount: Some(1), target: None };
+    apply_effect(&mut state, &pid, &empty, &RandomChooser, &HashMap::new());
+    assert_eq!(state.players[&pid].stats.mana.current, 0);
+}
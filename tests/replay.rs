@@ -0,0 +1,121 @@
+use hearthstone_sim::data::card_template::CardTemplate;
+use hearthstone_sim::game::effects::apply_effect;
+use hearthstone_sim::game::engine::choose::RandomChooser;
+use hearthstone_sim::game::engine::replay::replay_cards;
+use hearthstone_sim::game::enums::CardClass;
+use hearthstone_sim::game::filter::CardFilter;
+use hearthstone_sim::game::history::Character;
+use hearthstone_sim::game::state::PlayerId;
+use hearthstone_sim::game::triggers::Trigger;
+use serde_json::json;
+use std::collections::HashMap;
+
+mod common;
+use common::{board_ids, effect, new_state, template};
+
+fn templates() -> HashMap<String, CardTemplate> {
+    common::templates([
+        template(json!({"card_id": "TREANT"})),
+        template(json!({"card_id": "HAUNTED", "effects": [
+            {"type": "summon", "amount": 1, "card_id": "TREANT", "destination": "friendly_board", "trigger": "deathrattle"}
+        ]})),
+        template(json!({"card_id": "FIREBALL", "card_class": "MAGE", "card_type": "SPELL", "effects": [
+            {"type": "damage", "amount": 6, "target": "any_character"}
+        ]})),
+        template(json!({"card_id": "FROSTBOLT", "card_class": "MAGE", "card_type": "SPELL", "effects": [
+            {"type": "damage", "amount": 3, "target": "enemy_character"}
+        ]})),
+        template(json!({"card_id": "APPRENTICE", "card_class": "MAGE", "effects": [
+            {"type": "gain_armor", "amount": 2, "trigger": "battlecry"}
+        ]})),
+        template(json!({"card_id": "SHIV", "card_class": "ROGUE", "card_type": "SPELL", "effects": [
+            {"type": "gain_armor", "amount": 5}
+        ]})),
+    ])
+}
+
+#[test]
+fn trigger_deathrattle_runs_it_without_killing_the_minion() {
+    let mut state = new_state(CardClass::Rogue);
+    let t = templates();
+    let pid = PlayerId::Player1;
+    let player = state.players.get_mut(&pid).unwrap();
+    player.place_on_board(t["TREANT"].to_card(), None);
+    player.place_on_board(t["HAUNTED"].to_card(), None);
+    player.place_on_board(template(json!({"card_id": "STALKER"})).to_card(), None);
+    player.source_position = Some(2);
+
+    let stalker = effect(json!({"type": "trigger_deathrattle", "target": "friendly_minion", "trigger": "battlecry"}));
+    apply_effect(&mut state, &pid, &stalker, &RandomChooser, &t);
+
+    assert_eq!(board_ids(&state, &pid), ["TREANT", "HAUNTED", "TREANT", "STALKER"]);
+    assert_eq!(state.players[&pid].source_position, Some(3));
+}
+
+#[test]
+fn repeat_uses_spent_corpses_or_stops_on_its_condition() {
+    let mut state = new_state(CardClass::Rogue);
+    let t = templates();
+    let pid = PlayerId::Player1;
+    let marrow = template(json!({"card_id": "MARROW", "effects": [
+        {"type": "spend_corpse", "amount": 5, "mode": "up_to", "trigger": "battlecry"},
+        {"type": "repeat", "times": "corpses_spent", "effect": {"type": "damage", "amount": 2, "target": "enemy_character", "random": true}}
+    ]}))
+    .to_card();
+    let battlecry = marrow.triggers.iter().find(|tr| tr.when == Trigger::Battlecry).unwrap().effect.clone();
+    state.players.get_mut(&pid).unwrap().corpses = 3;
+
+    apply_effect(&mut state, &pid, &battlecry, &RandomChooser, &t);
+    assert_eq!(state.players[&PlayerId::Player2].stats.health, 24);
+    assert_eq!(state.players[&pid].corpses, 0);
+
+    let fill = effect(json!({
+        "type": "repeat", "until": {"friendly_minions": {"min": 3}},
+        "effect": {"type": "summon", "amount": 1, "card_id": "TREANT", "destination": "friendly_board"}
+    }));
+    apply_effect(&mut state, &pid, &fill, &RandomChooser, &t);
+    assert_eq!(board_ids(&state, &pid), ["TREANT", "TREANT", "TREANT"]);
+}
+
+#[test]
+fn replayed_cards_keep_their_original_targets() {
+    let mut state = new_state(CardClass::Rogue);
+    let t = templates();
+    let (pid, opp) = (PlayerId::Player1, PlayerId::Player2);
+    let enemy = state.players.get_mut(&opp).unwrap();
+    enemy.place_on_board(template(json!({"card_id": "SMALL"})).to_card(), None);
+    enemy.place_on_board(template(json!({"card_id": "BIG", "health": 6})).to_card(), None);
+
+    state.history.start_turn(pid);
+    let originals = [
+        ("FIREBALL", Some(Character::Minion { owner: opp, card_id: "BIG".to_string() })),
+        ("FROSTBOLT", Some(Character::Hero(opp))),
+        ("APPRENTICE", None),
+        ("SHIV", None),
+    ];
+    for (id, target) in originals {
+        let card = t[id].to_card();
+        state.history.record_card_played(&pid, &card);
+        if id != "APPRENTICE" {
+            state.history.record_spell_cast(&pid, &card, card.cost);
+        }
+        if let Some(target) = target {
+            state.history.begin_spell_resolution();
+            state.history.note_target(target);
+            state.history.end_spell_resolution();
+        }
+    }
+
+    let tess = CardFilter::from_value(&json!({"card_class": "other_classes", "played_this_game": true}));
+    let replayed = replay_cards(&mut state, &pid, &tess, false, &RandomChooser, &t);
+
+    assert_eq!(replayed, 3);
+    assert_eq!(board_ids(&state, &opp), ["SMALL"]);
+    assert_eq!(state.players[&opp].stats.health, 27);
+    assert_eq!(state.players[&pid].stats.health, 30);
+    assert_eq!(board_ids(&state, &pid), ["APPRENTICE"]);
+    assert_eq!(state.players[&pid].stats.armor, 2);
+    assert!(state.replay_target.is_none());
+    // le rejeu ne réécrit pas les cibles enregistrées
+    assert_eq!(state.history.spells_cast.last().unwrap().target, None);
+}
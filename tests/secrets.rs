@@ -0,0 +1,69 @@
+use hearthstone_sim::game::card::Card;
+use hearthstone_sim::game::engine::choose::RandomChooser;
+use hearthstone_sim::game::engine::play_card::{can_play_card, play_card_at_index};
+use hearthstone_sim::game::enums::CardClass;
+use hearthstone_sim::game::state::{GameState, PlayerId};
+use serde_json::json;
+use std::collections::HashMap;
+
+mod common;
+use common::{card, fill_mana, new_state_with_mana};
+
+fn snipe() -> Card {
+    card(json!({
+        "card_id": "SNIPE", "card_name": "Snipe", "card_class": "HUNTER", "card_type": "SPELL", "cost": 2, "mechanics": ["Secret"],
+        "effects": [{"type": "damage", "amount": 4, "target": "all_enemy_character", "trigger": "on_enemy_minion_played"}]
+    }))
+}
+
+fn yeti() -> Card {
+    card(json!({"card_id": "YETI", "cost": 4, "attack": 4, "health": 5}))
+}
+
+fn new_state() -> GameState {
+    let mut state = new_state_with_mana(CardClass::Hunter);
+    fill_mana(&mut state, &PlayerId::Player2);
+    state
+}
+
+#[test]
+fn secret_is_hidden_and_unique() {
+    let mut state = new_state();
+    let pid = PlayerId::Player1;
+    state.players.get_mut(&pid).unwrap().zones.hand.push(snipe());
+
+    assert!(play_card_at_index(&mut state, &pid, 0, &RandomChooser, &HashMap::new()));
+    let p1 = &state.players[&pid];
+    assert_eq!(p1.zones.secrets.len(), 1);
+    assert_eq!(p1.secrets_view(PlayerId::Player1), vec!["Snipe".to_string()]);
+    assert_eq!(p1.secrets_view(PlayerId::Player2), vec!["Secret".to_string()]);
+
+    // Pas de doublon
+    assert!(!can_play_card(&state, &pid, &snipe()));
+}
+
+#[test]
+fn secret_triggers_only_on_opponent_turn() {
+    let mut state = new_state();
+    let p1 = PlayerId::Player1;
+    let p2 = PlayerId::Player2;
+    state.players.get_mut(&p1).unwrap().zones.hand.push(snipe());
+    assert!(play_card_at_index(&mut state, &p1, 0, &RandomChooser, &HashMap::new()));
+
+    // Le propriétaire joue un serviteur : rien ne se passe
+    state.players.get_mut(&p1).unwrap().zones.hand.push(yeti());
+    assert!(play_card_at_index(&mut state, &p1, 0, &RandomChooser, &HashMap::new()));
+    assert_eq!(state.players[&p1].zones.secrets.len(), 1);
+    assert_eq!(state.players[&p1].zones.board[0].effective_health(), 5);
+
+    // Tour adverse : le Secret se révèle et inflige ses dégâts
+    state.current_player = p2;
+    state.players.get_mut(&p2).unwrap().zones.hand.push(yeti());
+    assert!(play_card_at_index(&mut state, &p2, 0, &RandomChooser, &HashMap::new()));
+
+    let owner = &state.players[&p1];
+    assert!(owner.zones.secrets.is_empty());
+    assert_eq!(owner.zones.graveyard.last().unwrap().name, "Snipe");
+    assert_eq!(state.players[&p2].zones.board[0].effective_health(), 1);
+    assert_eq!(state.players[&p2].stats.health, 26);
+}
@@ -0,0 +1,189 @@
+use hearthstone_sim::data::card_template::CardTemplate;
+use hearthstone_sim::game::card::Card;
+use hearthstone_sim::game::effects::{apply_effect, remove_dead_minions};
+use hearthstone_sim::game::engine::attack::perform_attack_phase;
+use hearthstone_sim::game::engine::choose::RandomChooser;
+use hearthstone_sim::game::engine::events::dispatch_events;
+use hearthstone_sim::game::engine::play_card::play_card_at_index;
+use hearthstone_sim::game::enums::CardClass;
+use hearthstone_sim::game::state::PlayerId;
+use serde_json::json;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+mod common;
+use common::{card, effect, new_state_with_mana};
+
+fn template(id: &str, attack: i32, health: i32, extra: serde_json::Value) -> CardTemplate {
+    let mut value = json!({"card_id": id, "cost": 3, "attack": attack, "health": health});
+    value.as_object_mut().unwrap().extend(extra.as_object().unwrap().clone());
+    common::template(value)
+}
+
+fn minion(id: &str, attack: i32, health: i32, extra: serde_json::Value) -> Card {
+    template(id, attack, health, extra).to_card()
+}
+
+fn stats(card: &Card) -> (i32, i32) {
+    (card.effective_attack(), card.effective_health())
+}
+
+#[test]
+fn destroy_respects_the_target_condition() {
+    let mut state = new_state_with_mana(CardClass::Neutral);
+    let pid = PlayerId::Player1;
+    let opp = PlayerId::Player2;
+    for card in [minion("YETI", 4, 5, json!({})), minion("GIANT", 8, 8, json!({}))] {
+        state.players.get_mut(&opp).unwrap().place_on_board(card, None);
+    }
+    let big_game_hunter = effect(json!({
+        "type": "destroy", "target": "enemy_minion", "condition": {"attack": {"min": 7}}
+    }));
+
+    apply_effect(&mut state, &pid, &big_game_hunter, &RandomChooser, &HashMap::new());
+
+    let opponent = &state.players[&opp];
+    assert_eq!(opponent.zones.board.iter().map(|c| c.card_id.as_str()).collect::<Vec<_>>(), ["YETI"]);
+    assert_eq!(opponent.zones.graveyard[0].card_id, "GIANT");
+}
+
+#[test]
+fn swap_and_set_health_rewrite_the_stats() {
+    let mut state = new_state_with_mana(CardClass::Neutral);
+    let pid = PlayerId::Player1;
+    let opp = PlayerId::Player2;
+    let mut buffed = minion("YETI", 4, 5, json!({}));
+    buffed.status.attack_modifiers = 2;
+    state.players.get_mut(&opp).unwrap().place_on_board(buffed, None);
+
+    apply_effect(&mut state, &pid, &effect(json!({"type": "swap_stats", "target": "any_minion"})), &RandomChooser, &HashMap::new());
+    assert_eq!(stats(&state.players[&opp].zones.board[0]), (5, 6));
+
+    state.players.get_mut(&opp).unwrap().place_on_board(minion("GIANT", 8, 8, json!({})), None);
+    let veranus = effect(json!({"type": "set_health", "value": 1, "target": "all_enemy_minion"}));
+    apply_effect(&mut state, &pid, &veranus, &RandomChooser, &HashMap::new());
+    let healths: Vec<_> = state.players[&opp].zones.board.iter().map(|c| (c.effective_health(), c.max_health)).collect();
+    assert_eq!(healths, [(1, Some(1)), (1, Some(1))]);
+}
+
+#[test]
+fn bounced_minions_return_as_printed() {
+    let mut state = new_state_with_mana(CardClass::Neutral);
+    let pid = PlayerId::Player1;
+    let yeti = template("YETI", 4, 5, json!({}));
+    let saurfang = template("SAURFANG", 6, 8, json!({
+        "effects": [{"type": "return_to_hand", "trigger": "deathrattle", "target": "self"}]
+    }));
+    let templates: HashMap<_, _> = [yeti.clone(), saurfang.clone()].into_iter().map(|t| (t.card_id.clone(), t)).collect();
+    state.card_templates = Rc::new(templates.clone());
+
+    let mut hurt = yeti.to_card();
+    hurt.status.attack_modifiers = 3;
+    hurt.status.current_health = Some(1);
+    state.players.get_mut(&pid).unwrap().place_on_board(hurt, None);
+    let brewmaster = effect(json!({"type": "return_to_hand", "target": "friendly_minion", "zone": "board"}));
+    apply_effect(&mut state, &pid, &brewmaster, &RandomChooser, &templates);
+
+    let player = &state.players[&pid];
+    assert!(player.zones.board.is_empty());
+    assert_eq!(stats(&player.zones.hand[0]), (4, 5));
+
+    // Râle d'agonie « self » : la carte morte revient en main
+    let mut dying = saurfang.to_card();
+    dying.status.current_health = Some(0);
+    state.players.get_mut(&pid).unwrap().place_on_board(dying, None);
+    remove_dead_minions(&mut state);
+    dispatch_events(&mut state, &RandomChooser);
+
+    let hand: Vec<_> = state.players[&pid].zones.hand.iter().map(|c| (c.card_id.as_str(), c.effective_health())).collect();
+    assert_eq!(hand, [("YETI", 5), ("SAURFANG", 8)]);
+}
+
+#[test]
+fn doomguard_discards_random_cards_to_the_graveyard() {
+    let mut state = new_state_with_mana(CardClass::Neutral);
+    let pid = PlayerId::Player1;
+    for id in ["A", "B", "C"] {
+        state.players.get_mut(&pid).unwrap().zones.hand.push(minion(id, 1, 1, json!({})));
+    }
+
+    apply_effect(&mut state, &pid, &effect(json!({"type": "discard", "amount": 2, "random": true})), &RandomChooser, &HashMap::new());
+
+    let player = &state.players[&pid];
+    assert_eq!((player.zones.hand.len(), player.zones.graveyard.len()), (1, 2));
+}
+
+#[test]
+fn armor_and_static_abilities() {
+    let mut state = new_state_with_mana(CardClass::Neutral);
+    let pid = PlayerId::Player1;
+    let opp = PlayerId::Player2;
+    apply_effect(&mut state, &pid, &effect(json!({"type": "gain_armor", "amount": 5})), &RandomChooser, &HashMap::new());
+    assert_eq!(state.players[&pid].stats.armor, 5);
+
+    // Ragnaros ne peut pas attaquer
+    let mut ragnaros = minion("RAGNAROS", 8, 8, json!({"effects": [{"type": "cant_attack"}]}));
+    ragnaros.status.just_played = false;
+    state.players.get_mut(&pid).unwrap().place_on_board(ragnaros, None);
+    perform_attack_phase(&mut state, &pid, &opp, &RandomChooser, &HashMap::new());
+    assert_eq!(state.players[&opp].stats.health, 30);
+
+    // un sort ciblé ignore les serviteurs Insaisissables
+    for card in [minion("FAERIE", 3, 2, json!({"effects": [{"type": "elusive"}]})), minion("WISP", 1, 3, json!({}))] {
+        state.players.get_mut(&opp).unwrap().place_on_board(card, None);
+    }
+    state.players.get_mut(&pid).unwrap().zones.hand.push(card(json!({
+        "card_id": "BOLT", "card_type": "SPELL", "effects": [{"type": "damage", "amount": 1, "target": "enemy_minion"}]
+    })));
+    assert!(play_card_at_index(&mut state, &pid, 0, &RandomChooser, &HashMap::new()));
+    let healths: Vec<_> = state.players[&opp].zones.board.iter().map(Card::effective_health).collect();
+    assert_eq!(healths, [2, 2]);
+}
+
+#[test]
+fn spell_cost_armor_uses_the_mana_paid_even_for_secrets() {
+    let mut state = new_state_with_mana(CardClass::Neutral);
+    let pid = PlayerId::Player1;
+    let mut secret = template("TRAP", 0, 0, json!({"card_type": "SPELL", "mechanics": ["Secret"]})).to_card();
+    secret.status.cost_modifier = -1;
+    state.players.get_mut(&pid).unwrap().zones.hand.push(secret);
+    assert!(play_card_at_index(&mut state, &pid, 0, &RandomChooser, &HashMap::new()));
+
+    let artisan = effect(json!({"type": "gain_armor", "amount": {"value": "spell_cost"}}));
+    apply_effect(&mut state, &pid, &artisan, &RandomChooser, &HashMap::new());
+    assert_eq!(state.players[&pid].stats.armor, 2);
+}
+
+#[test]
+fn hero_powers_cannot_target_elusive_minions() {
+    let mut state = new_state_with_mana(CardClass::Neutral);
+    let (pid, opp) = (PlayerId::Player1, PlayerId::Player2);
+    for card in [minion("FAERIE", 3, 2, json!({"effects": [{"type": "elusive"}]})), minion("WISP", 1, 3, json!({}))] {
+        state.players.get_mut(&opp).unwrap().place_on_board(card, None);
+    }
+    let ping = effect(json!({"type": "damage", "amount": 1, "target": "enemy_minion"}));
+    state.history.begin_hero_power_resolution();
+    apply_effect(&mut state, &pid, &ping, &RandomChooser, &HashMap::new());
+    state.history.end_hero_power_resolution();
+
+    let healths: Vec<_> = state.players[&opp].zones.board.iter().map(Card::effective_health).collect();
+    assert_eq!(healths, [2, 2]);
+}
+
+#[test]
+fn spell_damage_to_a_hero_is_absorbed_by_armor() {
+    let mut state = new_state_with_mana(CardClass::Neutral);
+    let (pid, opp) = (PlayerId::Player1, PlayerId::Player2);
+    let armor = |amount: i32| effect(json!({"type": "gain_armor", "amount": amount}));
+    apply_effect(&mut state, &opp, &armor(3), &RandomChooser, &HashMap::new());
+
+    // seul personnage ennemi : le héros adverse
+    let bolt = effect(json!({"type": "damage", "amount": 5, "target": "enemy_character"}));
+    apply_effect(&mut state, &pid, &bolt, &RandomChooser, &HashMap::new());
+    assert_eq!((state.players[&opp].stats.armor, state.players[&opp].stats.health), (0, 28));
+
+    apply_effect(&mut state, &opp, &armor(4), &RandomChooser, &HashMap::new());
+    let nova = effect(json!({"type": "damage", "amount": 2, "target": "all_enemy_character"}));
+    apply_effect(&mut state, &pid, &nova, &RandomChooser, &HashMap::new());
+    assert_eq!((state.players[&opp].stats.armor, state.players[&opp].stats.health), (2, 28));
+}
@@ -0,0 +1,94 @@
+use hearthstone_sim::game::card::Card;
+use hearthstone_sim::game::engine::choose::RandomChooser;
+use hearthstone_sim::game::engine::trade::{trade_card, tradeable_cards};
+use hearthstone_sim::game::enums::CardClass;
+use hearthstone_sim::game::player::{Player, MAX_HAND};
+use hearthstone_sim::game::state::{GameState, PlayerId};
+use serde_json::json;
+use std::collections::HashMap;
+
+mod common;
+use common::{card};
+
+fn filler(id: &str) -> Card {
+    card(json!({"card_id": id}))
+}
+
+#[test]
+fn trading_shuffles_card_into_deck_and_draws() {
+    let mut p1 = Player::new(PlayerId::Player1, vec![filler("A"), filler("B")], CardClass::Neutral);
+    p1.stats.mana.max = 2;
+    p1.stats.mana.current = 2;
+    p1.zones.hand.push(card(json!({
+        "card_id": "TRADE", "card_name": "Merchant", "card_class": "NEUTRAL",
+        "card_type": "MINION", "cost": 8, "attack": 4, "health": 4,
+        "effects": [{"type": "tradeable"}]
+    })));
+    p1.zones.hand.push(filler("C"));
+    let p2 = Player::new(PlayerId::Player2, vec![], CardClass::Neutral);
+    let mut state = GameState::new(p1, p2);
+    let pid = PlayerId::Player1;
+
+    // Seule la carte Échangeable est proposée, même si elle coûte 8
+    assert_eq!(tradeable_cards(&state, &pid), vec![0]);
+    assert!(!trade_card(&mut state, &pid, 1, &RandomChooser, &HashMap::new()));
+    assert!(trade_card(&mut state, &pid, 0, &RandomChooser, &HashMap::new()));
+
+    let player = &state.players[&pid];
+    assert_eq!(player.stats.mana.current, 1);
+    assert_eq!(player.zones.hand.len(), 2);
+    assert!(player.zones.hand.iter().all(|c| c.card_id != "TRADE"));
+    assert_eq!(player.zones.deck.len(), 2);
+    assert!(player.zones.deck.iter().any(|c| c.card_id == "TRADE"));
+}
+
+#[test]
+fn when_traded_trigger_fires_and_needs_mana() {
+    let mut p1 = Player::new(PlayerId::Player1, vec![filler("A")], CardClass::Neutral);
+    p1.stats.mana.max = 1;
+    p1.stats.mana.current = 1;
+    p1.zones.hand.push(card(json!({
+        "card_id": "LIBRARIAN", "card_name": "Librarian", "card_class": "NEUTRAL",
+        "card_type": "MINION", "cost": 3, "attack": 3, "health": 3,
+        "effects": [
+            {"type": "tradeable"},
+            {"type": "damage", "amount": 2, "target": "enemy_hero", "trigger": "when_traded"}
+        ]
+    })));
+    let p2 = Player::new(PlayerId::Player2, vec![], CardClass::Neutral);
+    let mut state = GameState::new(p1, p2);
+    let pid = PlayerId::Player1;
+
+    assert!(trade_card(&mut state, &pid, 0, &RandomChooser, &HashMap::new()));
+    assert_eq!(state.players[&PlayerId::Player2].stats.health, 28);
+
+    // Plus de mana : plus d'échange possible
+    let deck_card = state.players.get_mut(&pid).unwrap().zones.deck.pop().unwrap();
+    state.players.get_mut(&pid).unwrap().zones.hand.push(deck_card);
+    assert!(tradeable_cards(&state, &pid).is_empty());
+}
+
+#[test]
+fn trading_from_a_full_hand_burns_nothing() {
+    let mut p1 = Player::new(PlayerId::Player1, vec![filler("A")], CardClass::Neutral);
+    p1.stats.mana.current = 1;
+    p1.zones.hand.push(card(json!({
+        "card_id": "TRADE", "card_name": "Merchant", "card_class": "NEUTRAL",
+        "card_type": "MINION", "cost": 8, "attack": 4, "health": 4,
+        "effects": [{"type": "tradeable"}]
+    })));
+    while p1.zones.hand.len() < MAX_HAND {
+        p1.zones.hand.push(filler("C"));
+    }
+    let p2 = Player::new(PlayerId::Player2, vec![], CardClass::Neutral);
+    let mut state = GameState::new(p1, p2);
+    let pid = PlayerId::Player1;
+
+    assert!(trade_card(&mut state, &pid, 0, &RandomChooser, &HashMap::new()));
+
+    let player = &state.players[&pid];
+    assert_eq!(player.zones.hand.len(), MAX_HAND);
+    assert!(player.zones.hand.iter().any(|c| c.card_id == "A"));
+    assert_eq!(player.zones.deck.len(), 1);
+    assert!(player.zones.graveyard.is_empty());
+}
@@ -0,0 +1,111 @@
+use hearthstone_sim::game::card::Card;
+use hearthstone_sim::game::engine::attack::perform_attack_phase;
+use hearthstone_sim::game::engine::choose::RandomChooser;
+use hearthstone_sim::game::engine::play_card::play_card_at_index;
+use hearthstone_sim::game::enums::CardClass;
+use hearthstone_sim::game::state::PlayerId;
+use serde_json::json;
+use std::collections::HashMap;
+
+mod common;
+use common::{card, new_state_with_mana};
+
+fn minion(id: &str, attack: i32, health: i32, mechanics: serde_json::Value) -> Card {
+    common::minion(id, attack, health, json!({"mechanics": mechanics}))
+}
+
+#[test]
+fn replacing_a_weapon_fires_deathrattle_and_battlecry() {
+    let mut state = new_state_with_mana(CardClass::Warrior);
+    let pid = PlayerId::Player1;
+    {
+        let p = state.players.get_mut(&pid).unwrap();
+        p.stats.weapon = Some(card(json!({
+            "card_id": "OLD", "card_name": "Old Axe", "card_class": "WARRIOR",
+            "card_type": "WEAPON", "cost": 1, "attack": 1, "health": 2,
+            "effects": [{"type": "damage", "amount": 2, "target": "enemy_hero", "trigger": "deathrattle"}]
+        })));
+        p.zones.hand.push(card(json!({
+            "card_id": "NEW", "card_name": "New Axe", "card_class": "WARRIOR",
+            "card_type": "WEAPON", "cost": 2, "attack": 3, "health": 2,
+            "effects": [{"type": "damage", "amount": 1, "target": "enemy_hero", "trigger": "battlecry"}]
+        })));
+    }
+
+    assert!(play_card_at_index(&mut state, &pid, 0, &RandomChooser, &HashMap::new()));
+    let p = &state.players[&pid];
+    assert_eq!(p.stats.weapon.as_ref().unwrap().card_id, "NEW");
+    assert_eq!(p.zones.graveyard.last().unwrap().card_id, "OLD");
+    assert_eq!(state.players[&PlayerId::Player2].stats.health, 27);
+}
+
+#[test]
+fn weapon_attack_respects_taunt_and_keywords() {
+    let mut state = new_state_with_mana(CardClass::Warrior);
+    let (p1, p2) = (PlayerId::Player1, PlayerId::Player2);
+    {
+        let p = state.players.get_mut(&p1).unwrap();
+        p.stats.health = 20;
+        p.stats.weapon = Some(card(json!({
+            "card_id": "AXE", "card_name": "Axe", "card_class": "WARRIOR",
+            "card_type": "WEAPON", "cost": 2, "attack": 2, "health": 3,
+            "mechanics": ["Windfury", "Lifesteal", "Freeze"],
+            "effects": [{"type": "damage", "amount": 1, "target": "enemy_hero", "trigger": "after_hero_attack"}]
+        })));
+        let o = state.players.get_mut(&p2).unwrap();
+        o.zones.board.push(minion("PLAIN", 1, 1, json!([])));
+        o.zones.board.push(minion("WALL", 1, 6, json!(["Taunt"])));
+    }
+
+    perform_attack_phase(&mut state, &p1, &p2, &RandomChooser, &HashMap::new());
+
+    let me = &state.players[&p1];
+    let wall = state.players[&p2].zones.board.iter().find(|m| m.card_id == "WALL").unwrap();
+    // Windfury : deux attaques sur le Taunt, gelé par l'arme
+    assert_eq!(me.hero_attacks_this_turn, 2);
+    assert_eq!(wall.effective_health(), 2);
+    assert!(wall.status.frozen);
+    assert_eq!(state.players[&p2].zones.board.len(), 2);
+    // 2 ripostes de 1, 2 × 2 de Lifesteal
+    assert_eq!(me.stats.health, 22);
+    assert_eq!(me.stats.weapon.as_ref().unwrap().health, Some(1));
+    // AfterHeroAttack à chaque attaque
+    assert_eq!(state.players[&p2].stats.health, 28);
+}
+
+#[test]
+fn poisonous_weapon_breaks_after_last_durability() {
+    let mut state = new_state_with_mana(CardClass::Warrior);
+    let (p1, p2) = (PlayerId::Player1, PlayerId::Player2);
+    state.players.get_mut(&p1).unwrap().stats.weapon = Some(card(json!({
+        "card_id": "DAGGER", "card_name": "Dagger", "card_class": "ROGUE",
+        "card_type": "WEAPON", "cost": 1, "attack": 1, "health": 1, "mechanics": ["Poisonous"]
+    })));
+    state.players.get_mut(&p2).unwrap().zones.board.push(minion("BIG", 0, 8, json!([])));
+
+    perform_attack_phase(&mut state, &p1, &p2, &RandomChooser, &HashMap::new());
+
+    let me = &state.players[&p1];
+    assert!(me.stats.weapon.is_none());
+    assert_eq!(me.zones.graveyard.last().unwrap().card_id, "DAGGER");
+    assert!(state.players[&p2].zones.board.is_empty());
+}
+
+#[test]
+fn last_durability_swing_still_fires_after_attack_triggers() {
+    let mut state = new_state_with_mana(CardClass::Warrior);
+    let (p1, p2) = (PlayerId::Player1, PlayerId::Player2);
+    state.players.get_mut(&p1).unwrap().stats.weapon = Some(card(json!({
+        "card_id": "AXE", "card_name": "Axe", "card_class": "WARRIOR",
+        "card_type": "WEAPON", "cost": 1, "attack": 2, "health": 1,
+        "effects": [{"type": "gain_armor", "amount": 3, "trigger": "after_hero_attack"}]
+    })));
+
+    perform_attack_phase(&mut state, &p1, &p2, &RandomChooser, &HashMap::new());
+
+    let me = &state.players[&p1];
+    assert_eq!(me.stats.armor, 3);
+    assert!(me.stats.weapon.is_none());
+    assert_eq!(me.zones.graveyard.last().unwrap().card_id, "AXE");
+    assert_eq!(state.players[&p2].stats.health, 28);
+}
@@ -0,0 +1,72 @@
+use hearthstone_sim::game::card::Card;
+use hearthstone_sim::game::engine::choose::RandomChooser;
+use hearthstone_sim::game::engine::draw::draw_card;
+use hearthstone_sim::game::engine::play_card::{can_play_card, play_card_at_index};
+use hearthstone_sim::game::engine::zones::{move_card, summon};
+use hearthstone_sim::game::enums::{CardClass, Zone};
+use hearthstone_sim::game::event::GameEvent;
+use hearthstone_sim::game::player::{Player, ZoneFull, MAX_BOARD, MAX_HAND};
+use hearthstone_sim::game::state::{GameState, PlayerId};
+use serde_json::json;
+use std::collections::HashMap;
+
+mod common;
+use common::{card, new_state_with_mana};
+
+fn minion(id: &str) -> Card {
+    card(json!({"card_id": id}))
+}
+
+fn full_board_state() -> GameState {
+    let mut state = new_state_with_mana(CardClass::Neutral);
+    let p1 = state.players.get_mut(&PlayerId::Player1).unwrap();
+    for i in 0..MAX_BOARD {
+        p1.place_on_board(minion(&format!("M{i}")), None);
+    }
+    p1.zones.hand.push(minion("IN_HAND"));
+    state
+}
+
+#[test]
+fn full_board_blocks_plays_and_summons() {
+    let mut state = full_board_state();
+    let pid = PlayerId::Player1;
+
+    let in_hand = state.players[&pid].zones.hand[0].clone();
+    assert!(!can_play_card(&state, &pid, &in_hand));
+    assert!(!play_card_at_index(&mut state, &pid, 0, &RandomChooser, &HashMap::new()));
+    assert_eq!(state.players[&pid].zones.hand.len(), 1);
+    assert_eq!(state.players[&pid].stats.mana.current, 10);
+
+    assert_eq!(summon(&mut state, &pid, minion("TOKEN")), None);
+    assert!(matches!(state.event_queue.back(), Some(GameEvent::SummonFizzled { .. })));
+    assert_eq!(
+        move_card(&mut state, &pid, Zone::Hand, 0, Zone::Board, None),
+        Err(ZoneFull::Board)
+    );
+    let player = &state.players[&pid];
+    assert_eq!(player.zones.board.len(), MAX_BOARD);
+    assert_eq!(player.zones.hand[0].card_id, "IN_HAND");
+}
+
+#[test]
+fn drawing_into_full_hand_burns_the_card() {
+    let deck = vec![minion("BURNED")];
+    let mut p1 = Player::new(PlayerId::Player1, deck, CardClass::Neutral);
+    for i in 0..MAX_HAND {
+        p1.zones.hand.push(minion(&format!("H{i}")));
+    }
+    let p2 = Player::new(PlayerId::Player2, vec![], CardClass::Neutral);
+    let mut state = GameState::new(p1, p2);
+    let pid = PlayerId::Player1;
+
+    assert!(draw_card(&mut state, &pid).is_none());
+    let player = &state.players[&pid];
+    assert_eq!(player.zones.hand.len(), MAX_HAND);
+    assert!(player.zones.deck.is_empty());
+    assert_eq!(player.zones.graveyard.last().unwrap().card_id, "BURNED");
+    assert!(matches!(
+        state.event_queue.back(),
+        Some(GameEvent::CardBurned { card_id, .. }) if card_id == "BURNED"
+    ));
+}